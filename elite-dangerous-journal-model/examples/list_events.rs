@@ -30,7 +30,7 @@ fn main() {
             unknown_count
         );
     }
-    if unknown_signal_types.len() > 0 {
+    if !unknown_signal_types.is_empty() {
         warn!(
             "Unknown signals found in Journal, count: {}",
             unknown_signal_types.len()
@@ -44,10 +44,8 @@ fn process_path(unknown_count: &mut i32, unknown_signal_types: &mut HashSet<Stri
     let metadata = metadata(path).unwrap();
 
     if metadata.is_dir() {
-        for entry in std::fs::read_dir(path).unwrap() {
-            if let Ok(entry) = entry {
-                process_path(unknown_count, unknown_signal_types, &entry.path());
-            }
+        for entry in std::fs::read_dir(path).unwrap().flatten() {
+            process_path(unknown_count, unknown_signal_types, &entry.path());
         }
     } else {
 
@@ -76,17 +74,16 @@ fn process_path(unknown_count: &mut i32, unknown_signal_types: &mut HashSet<Stri
                             debug!("Event: {}, event: {:?}", event_num + 1, event);
 
                             match event {
-                                JournalEvent::Unknown => {
+                                JournalEvent::Unknown(unknown) => {
                                     *unknown_count += 1;
-                                    warn!("Unknown event {}", event_num + 1);
+                                    warn!("Unknown event {} ({})", event_num + 1, unknown.event);
                                     warn!("\t\t=>{:?}", line);
                                 }
-                                JournalEvent::FSSSignalDiscovered(event) => match event.signal_type {
-                                    FssSignalType::Unknown(signal_type) => {
+                                JournalEvent::FSSSignalDiscovered(event) => {
+                                    if let FssSignalType::Unknown(signal_type) = event.signal_type {
                                         unknown_signal_types.insert(signal_type);
                                     }
-                                    _ => {}
-                                },
+                                }
                                 _ => {}
                             }
                         }
@@ -7,28 +7,91 @@
 //! The events try to follow the structure defined in [Elite Dangerous Player Journal](https://elite-journal.readthedocs.io/en/latest/) docs
 //! however the locations may be tweaked if considered more sensible (or they are not structured in the docs)
 
-use serde::{Deserialize, Serialize};
-use crate::events::common::EmptyEvent;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::Error;
+use crate::events::combat::bonds::{CapShipBondEvent, FactionKillBondEvent};
+use crate::events::combat::bounty::BountyEvent;
+use crate::events::combat::damage::{HullDamageEvent, ShieldStateEvent, UnderAttackEvent};
+use crate::events::combat::destroyed::{FighterDestroyedEvent, SRVDestroyedEvent};
+use crate::events::combat::died::DiedEvent;
+use crate::events::combat::interdiction::{EscapeInterdictionEvent, InterdictedEvent, InterdictionEvent};
+use crate::events::combat::pvp_kill::PVPKillEvent;
+use crate::events::combat::ship_targeted::ShipTargetedEvent;
+use crate::events::common::{EmptyEvent, ExtraFields, UnknownEvent};
+use crate::events::exploration::body_signals::{FSSBodySignalsEvent, SAASignalsFoundEvent};
+use crate::events::exploration::codex_entry::CodexEntryEvent;
+use crate::events::exploration::discovery_scan::DiscoveryScanEvent;
+use crate::events::exploration::fss_all_bodies_found::FSSAllBodiesFoundEvent;
+use crate::events::exploration::fss_discovery_scan::FSSDiscoveryScanEvent;
 use crate::events::exploration::fss_signal_discovered::FSSSignalDiscoveredEvent;
-use crate::events::exploration::material_collected::MaterialCollectedEvent;
+use crate::events::exploration::material_collected::{MaterialCollectedEvent, MaterialDiscardedEvent};
+use crate::events::exploration::nav_beacon_scan::NavBeaconScanEvent;
+use crate::events::exploration::saa_scan_complete::SAAScanCompleteEvent;
+use crate::events::exploration::scan_bary_centre::ScanBaryCentreEvent;
+use crate::events::exploration::sell_exploration_data::{MultiSellExplorationDataEvent, SellExplorationDataEvent};
 use crate::events::exploration::scan::event::ScanEvent;
+use crate::events::fleet_carriers::finance::{CarrierBankTransferEvent, CarrierDepositFuelEvent, CarrierFinanceEvent, CarrierTradeOrderEvent};
+use crate::events::fleet_carriers::jump::{CarrierJumpCancelledEvent, CarrierJumpRequestEvent};
+use crate::events::fleet_carriers::ownership::{CarrierBuyEvent, CarrierDecommissionEvent, CarrierNameChangedEvent};
+use crate::events::fleet_carriers::services::{CarrierCrewServicesEvent, CarrierDockingPermissionEvent, CarrierPackEvent};
+use crate::events::fleet_carriers::stats::CarrierStatsEvent;
+use crate::events::journal_event_ext::{EventCategory, JournalEventExt};
+use crate::events::odyssey::backpack::{BackpackChangeEvent, BackpackEvent};
+use crate::events::odyssey::micro_resources::{BuyMicroResourcesEvent, SellMicroResourcesEvent, TradeMicroResourcesEvent};
+use crate::events::odyssey::organic::{ScanOrganicEvent, SellOrganicDataEvent};
 use crate::events::odyssey::ship_locker::ShipLockerEvent;
+use crate::events::odyssey::suits::{BuySuitEvent, BuyWeaponEvent, SuitLoadoutEvent, UpgradeSuitEvent, UpgradeWeaponEvent};
 use crate::events::other::drone::LaunchDroneEvent;
 use crate::events::other::fuel_scoop::FuelScoopEvent;
+use crate::events::other::module_info::ModuleInfoEvent;
 use crate::events::other::music::MusicEvent;
 use crate::events::other::receive_text::ReceiveTextEvent;
+use crate::events::other::reservoir_replenished::ReservoirReplenishedEvent;
+use crate::events::other::synthesis::SynthesisEvent;
 use crate::events::startup::cargo::CargoEvent;
 use crate::events::startup::commander::{CommanderEvent, CommanderProgressEvent, CommanderRankEvent, CommanderReputationEvent};
 use crate::events::startup::game::{FileHeaderEvent, LoadGameEvent};
+use crate::events::startup::loadout::LoadoutEvent;
 use crate::events::startup::material::MaterialsEvent;
+use crate::events::station_services::crew::{CrewAssignEvent, CrewFireEvent, CrewHireEvent};
 use crate::events::station_services::engineer::EngineerProgressEvent;
+use crate::events::station_services::engineer_craft::{EngineerContributionEvent, EngineerCraftEvent};
+use crate::events::station_services::market::MarketEvent;
+use crate::events::station_services::material_trade::MaterialTradeEvent;
+use crate::events::station_services::missions::mission_abandoned::MissionAbandonedEvent;
 use crate::events::station_services::missions::mission_accepted::MissionAcceptedEvent;
 use crate::events::station_services::missions::mission_completed::MissionCompletedEvent;
+use crate::events::station_services::missions::mission_failed::MissionFailedEvent;
+use crate::events::station_services::missions::mission_list::MissionsEvent;
 use crate::events::station_services::missions::mission_redirected::MissionRedirectedEvent;
-use crate::events::trade::mining::MiningRefinedEvent;
+use crate::events::station_services::modules::{FetchRemoteModuleEvent, MassModuleStoreEvent, ModuleBuyEvent, ModuleRetrieveEvent, ModuleSellEvent, ModuleStoreEvent, ModuleSwapEvent, StoredModulesEvent};
+use crate::events::station_services::outfitting::OutfittingEvent;
+use crate::events::station_services::payments::{PayBountiesEvent, PayFinesEvent, RedeemVoucherEvent};
+use crate::events::station_services::restock::{BuyAmmoEvent, BuyDronesEvent, RefuelAllEvent, RepairAllEvent, RepairEvent, RestockVehicleEvent, SellDronesEvent};
+use crate::events::station_services::ships::{ShipyardBuyEvent, ShipyardSellEvent, ShipyardSwapEvent, ShipyardTransferEvent, StoredShipsEvent};
+use crate::events::station_services::shipyard::ShipyardEvent;
+use crate::events::station_services::technology_broker::TechnologyBrokerEvent;
+use crate::events::status::status_change::StatusChange;
+use crate::events::status::status_file::StatusFile;
+use crate::events::trade::buy_trade_data::BuyTradeDataEvent;
+use crate::events::trade::cargo::{CargoTransferEvent, CollectCargoEvent, EjectCargoEvent};
+use crate::events::trade::market::{MarketBuyEvent, MarketSellEvent};
+use crate::events::trade::mining::{AsteroidCrackedEvent, MiningRefinedEvent, ProspectedAsteroidEvent};
+use crate::events::travel::approach_settlement::ApproachSettlementEvent;
+use crate::events::travel::body::BodyEvent;
+use crate::events::travel::book_taxi::BookTaxiEvent;
+use crate::events::travel::docking::{DockedEvent, DockingDeniedEvent, DockingEvent, DockingGrantedEvent, DockingRequestedEvent, UndockedEvent};
+use crate::events::travel::embark::EmbarkEvent;
 use crate::events::travel::fsd_jump::fsd_jump_event::FSDJumpEvent;
 use crate::events::travel::fsd_target::FSDTargetEvent;
+use crate::events::travel::jet_cone_boost::JetConeBoostEvent;
+use crate::events::travel::location::LocationEvent;
+use crate::events::travel::nav_route::NavRouteEvent;
 use crate::events::travel::start_jump::StartJumpEvent;
+use crate::events::travel::supercruise::{SupercruiseEntryEvent, SupercruiseExitEvent};
+use crate::events::travel::surface::SurfaceEvent;
+use crate::events::travel::uss_drop::USSDropEvent;
 
 /// A module for common structures for example the serde processing of timestamps
 pub mod common;
@@ -69,6 +132,15 @@ pub mod other;
 /// As added in E::D 3 there is a status file that is updated regularly - https://elite-journal.readthedocs.io/en/latest/Status%20File/
 pub mod status;
 
+/// The envelope that wraps every emitted event with its provenance
+pub mod envelope;
+
+/// Parsing of journal lines with an opt-in strict mode that validates the events against the documented schema
+pub mod validation;
+
+/// Uniform accessors (timestamp, name and category) that every journal event has
+pub mod journal_event_ext;
+
 /// The EliteDangerousEvent is the event that consumers will receive, it consist of variants based on the 
 /// source of the event
 #[derive(Debug)]
@@ -82,50 +154,275 @@ pub enum EliteDangerousEvent {
     /// Journal.2025-01-24T172047.01.log
     /// ```
     JournalEvent(JournalEvent),
+    /// Event has come from a journal log that was written before the watcher started, these are read from the
+    /// existing journal files in chronological order at start up so consumers can build up their history
+    BackfilledJournalEvent(JournalEvent),
+    /// All the existing journal files have been read, any [JournalEvent](EliteDangerousEvent::JournalEvent) after
+    /// this is from live play
+    BackfillComplete,
+    /// Event has come from the Status.json file, this is rewritten every time the status of the ship/commander changes
+    StatusFile(StatusFile),
+    /// Event has come from the NavRoute.json file, this is rewritten when a route is plotted or cleared
+    NavRouteFile(NavRouteEvent),
+    /// Event has come from the Cargo.json file, this is rewritten when the cargo hold changes
+    CargoFile(CargoEvent),
+    /// Event has come from the Market.json file, this is rewritten when the commodity market is accessed
+    MarketFile(MarketEvent),
+    /// Event has come from the Outfitting.json file, this is rewritten when outfitting is accessed
+    OutfittingFile(OutfittingEvent),
+    /// Event has come from the Shipyard.json file, this is rewritten when the shipyard is accessed
+    ShipyardFile(ShipyardEvent),
+    /// Event has come from the ModulesInfo.json file, this is rewritten when the modules panel is viewed
+    ModulesInfoFile(ModuleInfoEvent),
+    /// A semantic change derived by comparing the latest Status.json snapshot against the previous one, these
+    /// are emitted after the [StatusFile](EliteDangerousEvent::StatusFile) event they were derived from
+    StatusChange(StatusChange),
+}
+
+
+/// Removes the ```event``` tag from the json of a modelled event, the tag is not part of the event struct so would
+/// otherwise be captured in its extra fields
+fn without_event_tag(mut raw: serde_json::Value) -> serde_json::Value {
+    if let Some(fields) = raw.as_object_mut() {
+        fields.remove("event");
+    }
+    raw
 }
 
+/// Declares the [JournalEvent] enum along with the accessors that have to cover every variant, so a new event only
+/// needs adding in one place and can't be missed by the accessors
+///
+/// Each entry is the name of the event in the journal, the variant with the struct it deserializes into and the
+/// [EventCategory] of the event
+macro_rules! journal_events {
+    ($($name:literal => $variant:ident($event:ty), $category:ident;)*) => {
+        /// The journal event enum allows the deserialisation of the events from the elite dangerous journal log
+        /// It uses the ```event``` json field to determine what enum variant to load and then completes the data
+        /// for the relevant struct
+        #[allow(missing_docs)]
+        #[derive(Serialize, Debug, Clone)]
+        #[serde(tag = "event")]
+        pub enum JournalEvent {
+            $(
+                #[serde(rename = $name)]
+                $variant($event),
+            )*
+            /// This is a catch all for any other event in the file to allow iterative development and also should frontier add an event in the future
+            #[serde(untagged)]
+            Unknown(UnknownEvent),
+        }
+
+        /// The ```event``` field is read first so that a modelled event that fails to deserialize reports the real
+        /// error for that event, only events that are not modelled become [Unknown](JournalEvent::Unknown)
+        impl<'de> Deserialize<'de> for JournalEvent {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let raw = serde_json::Value::deserialize(deserializer)?;
+                let name = raw.get("event")
+                    .ok_or_else(|| D::Error::missing_field("event"))?
+                    .as_str()
+                    .ok_or_else(|| D::Error::custom("The event field is not a string"))?
+                    .to_string();
+
+                let event = match name.as_str() {
+                    $($name => <$event>::deserialize(without_event_tag(raw)).map(JournalEvent::$variant),)*
+                    _ => return UnknownEvent::try_from(raw).map(JournalEvent::Unknown).map_err(D::Error::custom),
+                };
+                event.map_err(|e| D::Error::custom(format!("Invalid {} event: {}", name, e)))
+            }
+        }
+
+        impl JournalEventExt for JournalEvent {
+            fn timestamp(&self) -> NaiveDateTime {
+                match self {
+                    $(JournalEvent::$variant(event) => event.event_meta.timestamp,)*
+                    JournalEvent::Unknown(event) => event.event_meta.timestamp,
+                }
+            }
+
+            fn event_name(&self) -> &str {
+                match self {
+                    $(JournalEvent::$variant(_) => $name,)*
+                    JournalEvent::Unknown(event) => &event.event,
+                }
+            }
+
+            fn category(&self) -> EventCategory {
+                match self {
+                    $(JournalEvent::$variant(_) => EventCategory::$category,)*
+                    JournalEvent::Unknown(_) => EventCategory::Other,
+                }
+            }
+        }
+
+        impl JournalEvent {
+            /// The names of the events that are modelled, as written in the ```event``` field of the journal
+            pub const SUPPORTED_EVENTS: &'static [&'static str] = &[$($name,)*];
+
+            /// The fields of the event that are not modelled, [None] if the event itself is not modelled
+            pub fn extra(&self) -> Option<&ExtraFields> {
+                match self {
+                    $(JournalEvent::$variant(event) => Some(&event.extra),)*
+                    JournalEvent::Unknown(_) => None,
+                }
+            }
+        }
+    };
+}
 
-/// The journal event enum allows the deserialisation of the events from the elite dangerous journal log
-/// It uses the ```event``` json field to determine what enum variant to load and then completes the data
-/// for the relevant struct
-#[allow(missing_docs)]
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[serde(tag = "event")]
-pub enum JournalEvent {
-    Cargo(CargoEvent),
-    Commander(CommanderEvent),
-    EngineerProgress(EngineerProgressEvent),
-    #[serde(rename = "Fileheader")]
-    FileHeader(FileHeaderEvent),
-    FSDJump(FSDJumpEvent),
-    FSDTarget(FSDTargetEvent),
-    FSSSignalDiscovered(FSSSignalDiscoveredEvent),
-    FuelScoop(FuelScoopEvent),
-    LaunchDrone(LaunchDroneEvent),
-    LoadGame(LoadGameEvent),
-    MaterialCollected(MaterialCollectedEvent),
-    Materials(MaterialsEvent),
-    MiningRefined(MiningRefinedEvent),
-    MissionAbandoned(MissionAcceptedEvent),
-    MissionAccepted(MissionAcceptedEvent),
-    MissionCompleted(MissionCompletedEvent),
-    MissionFailed(MissionCompletedEvent),
-    MissionRedirected(MissionRedirectedEvent),
-    Music(MusicEvent),
-    NavRoute(EmptyEvent),
-    NavRouteClear(EmptyEvent),
-    Progress(CommanderProgressEvent),
-    Rank(CommanderRankEvent),
-    ReceiveText(ReceiveTextEvent),
-    Reputation(CommanderReputationEvent),
-    Scan(ScanEvent),
-    ShipLocker(ShipLockerEvent),
-    StartJump(StartJumpEvent),
-    #[serde(rename= "Shutdown")]
-    ShutDown(EmptyEvent),
-    /// This is a catch all for any other event in the file to allow iterative development and also should frontier add an event in the future
-    #[serde(other)]
-    Unknown,
+journal_events! {
+    "ApproachBody" => ApproachBody(BodyEvent), Travel;
+    "ApproachSettlement" => ApproachSettlement(ApproachSettlementEvent), Travel;
+    "AsteroidCracked" => AsteroidCracked(AsteroidCrackedEvent), Trade;
+    "Backpack" => Backpack(BackpackEvent), Odyssey;
+    "BackpackChange" => BackpackChange(BackpackChangeEvent), Odyssey;
+    "BookTaxi" => BookTaxi(BookTaxiEvent), Travel;
+    "Bounty" => Bounty(BountyEvent), Combat;
+    "BuyAmmo" => BuyAmmo(BuyAmmoEvent), StationServices;
+    "BuyDrones" => BuyDrones(BuyDronesEvent), StationServices;
+    "BuyMicroResources" => BuyMicroResources(BuyMicroResourcesEvent), Odyssey;
+    "BuySuit" => BuySuit(BuySuitEvent), Odyssey;
+    "BuyTradeData" => BuyTradeData(BuyTradeDataEvent), Trade;
+    "BuyWeapon" => BuyWeapon(BuyWeaponEvent), Odyssey;
+    "CapShipBond" => CapShipBond(CapShipBondEvent), Combat;
+    "Cargo" => Cargo(CargoEvent), Startup;
+    "CargoTransfer" => CargoTransfer(CargoTransferEvent), Trade;
+    "CarrierBankTransfer" => CarrierBankTransfer(CarrierBankTransferEvent), FleetCarriers;
+    "CarrierBuy" => CarrierBuy(CarrierBuyEvent), FleetCarriers;
+    "CarrierCrewServices" => CarrierCrewServices(CarrierCrewServicesEvent), FleetCarriers;
+    "CarrierDecommission" => CarrierDecommission(CarrierDecommissionEvent), FleetCarriers;
+    "CarrierDepositFuel" => CarrierDepositFuel(CarrierDepositFuelEvent), FleetCarriers;
+    "CarrierDockingPermission" => CarrierDockingPermission(CarrierDockingPermissionEvent), FleetCarriers;
+    "CarrierFinance" => CarrierFinance(CarrierFinanceEvent), FleetCarriers;
+    "CarrierJump" => CarrierJump(LocationEvent), Travel;
+    "CarrierJumpCancelled" => CarrierJumpCancelled(CarrierJumpCancelledEvent), FleetCarriers;
+    "CarrierJumpRequest" => CarrierJumpRequest(CarrierJumpRequestEvent), FleetCarriers;
+    "CarrierModulePack" => CarrierModulePack(CarrierPackEvent), FleetCarriers;
+    "CarrierNameChanged" => CarrierNameChanged(CarrierNameChangedEvent), FleetCarriers;
+    "CarrierShipPack" => CarrierShipPack(CarrierPackEvent), FleetCarriers;
+    "CarrierStats" => CarrierStats(CarrierStatsEvent), FleetCarriers;
+    "CarrierTradeOrder" => CarrierTradeOrder(CarrierTradeOrderEvent), FleetCarriers;
+    "CodexEntry" => CodexEntry(CodexEntryEvent), Exploration;
+    "CollectCargo" => CollectCargo(CollectCargoEvent), Trade;
+    "Commander" => Commander(CommanderEvent), Startup;
+    "CreateSuitLoadout" => CreateSuitLoadout(SuitLoadoutEvent), Odyssey;
+    "CrewAssign" => CrewAssign(CrewAssignEvent), StationServices;
+    "CrewFire" => CrewFire(CrewFireEvent), StationServices;
+    "CrewHire" => CrewHire(CrewHireEvent), StationServices;
+    "Died" => Died(DiedEvent), Combat;
+    "DiscoveryScan" => DiscoveryScan(DiscoveryScanEvent), Exploration;
+    "Disembark" => Disembark(EmbarkEvent), Travel;
+    "Docked" => Docked(DockedEvent), Travel;
+    "DockingCancelled" => DockingCancelled(DockingEvent), Travel;
+    "DockingDenied" => DockingDenied(DockingDeniedEvent), Travel;
+    "DockingGranted" => DockingGranted(DockingGrantedEvent), Travel;
+    "DockingRequested" => DockingRequested(DockingRequestedEvent), Travel;
+    "DockingTimeout" => DockingTimeout(DockingEvent), Travel;
+    "EjectCargo" => EjectCargo(EjectCargoEvent), Trade;
+    "Embark" => Embark(EmbarkEvent), Travel;
+    "EngineerContribution" => EngineerContribution(EngineerContributionEvent), StationServices;
+    "EngineerCraft" => EngineerCraft(EngineerCraftEvent), StationServices;
+    "EngineerProgress" => EngineerProgress(EngineerProgressEvent), StationServices;
+    "EscapeInterdiction" => EscapeInterdiction(EscapeInterdictionEvent), Combat;
+    "FactionKillBond" => FactionKillBond(FactionKillBondEvent), Combat;
+    "FetchRemoteModule" => FetchRemoteModule(FetchRemoteModuleEvent), StationServices;
+    "FighterDestroyed" => FighterDestroyed(FighterDestroyedEvent), Combat;
+    "Fileheader" => FileHeader(FileHeaderEvent), Startup;
+    "FSDJump" => FSDJump(FSDJumpEvent), Travel;
+    "FSDTarget" => FSDTarget(FSDTargetEvent), Travel;
+    "FSSAllBodiesFound" => FSSAllBodiesFound(FSSAllBodiesFoundEvent), Exploration;
+    "FSSBodySignals" => FSSBodySignals(FSSBodySignalsEvent), Exploration;
+    "FSSDiscoveryScan" => FSSDiscoveryScan(FSSDiscoveryScanEvent), Exploration;
+    "FSSSignalDiscovered" => FSSSignalDiscovered(FSSSignalDiscoveredEvent), Exploration;
+    "FuelScoop" => FuelScoop(FuelScoopEvent), Other;
+    "HeatDamage" => HeatDamage(EmptyEvent), Combat;
+    "HeatWarning" => HeatWarning(EmptyEvent), Combat;
+    "HullDamage" => HullDamage(HullDamageEvent), Combat;
+    "Interdicted" => Interdicted(InterdictedEvent), Combat;
+    "Interdiction" => Interdiction(InterdictionEvent), Combat;
+    "JetConeBoost" => JetConeBoost(JetConeBoostEvent), Travel;
+    "LaunchDrone" => LaunchDrone(LaunchDroneEvent), Other;
+    "LeaveBody" => LeaveBody(BodyEvent), Travel;
+    "Liftoff" => Liftoff(SurfaceEvent), Travel;
+    "LoadGame" => LoadGame(LoadGameEvent), Startup;
+    "Loadout" => Loadout(LoadoutEvent), Startup;
+    "Location" => Location(LocationEvent), Travel;
+    "Market" => Market(MarketEvent), StationServices;
+    "MarketBuy" => MarketBuy(MarketBuyEvent), Trade;
+    "MarketSell" => MarketSell(MarketSellEvent), Trade;
+    "MassModuleStore" => MassModuleStore(MassModuleStoreEvent), StationServices;
+    "MaterialCollected" => MaterialCollected(MaterialCollectedEvent), Exploration;
+    "MaterialDiscarded" => MaterialDiscarded(MaterialDiscardedEvent), Exploration;
+    "Materials" => Materials(MaterialsEvent), Startup;
+    "MaterialTrade" => MaterialTrade(MaterialTradeEvent), StationServices;
+    "MiningRefined" => MiningRefined(MiningRefinedEvent), Trade;
+    "MissionAbandoned" => MissionAbandoned(MissionAbandonedEvent), StationServices;
+    "MissionAccepted" => MissionAccepted(MissionAcceptedEvent), StationServices;
+    "MissionCompleted" => MissionCompleted(MissionCompletedEvent), StationServices;
+    "MissionFailed" => MissionFailed(MissionFailedEvent), StationServices;
+    "MissionRedirected" => MissionRedirected(MissionRedirectedEvent), StationServices;
+    "Missions" => Missions(MissionsEvent), Startup;
+    "ModuleBuy" => ModuleBuy(ModuleBuyEvent), StationServices;
+    "ModuleRetrieve" => ModuleRetrieve(ModuleRetrieveEvent), StationServices;
+    "ModuleSell" => ModuleSell(ModuleSellEvent), StationServices;
+    "ModuleStore" => ModuleStore(ModuleStoreEvent), StationServices;
+    "ModuleSwap" => ModuleSwap(ModuleSwapEvent), StationServices;
+    "MultiSellExplorationData" => MultiSellExplorationData(MultiSellExplorationDataEvent), Exploration;
+    "Music" => Music(MusicEvent), Other;
+    "NavBeaconScan" => NavBeaconScan(NavBeaconScanEvent), Exploration;
+    "NavRoute" => NavRoute(EmptyEvent), Travel;
+    "NavRouteClear" => NavRouteClear(EmptyEvent), Travel;
+    "Outfitting" => Outfitting(OutfittingEvent), StationServices;
+    "PayBounties" => PayBounties(PayBountiesEvent), StationServices;
+    "PayFines" => PayFines(PayFinesEvent), StationServices;
+    "Progress" => Progress(CommanderProgressEvent), Startup;
+    "ProspectedAsteroid" => ProspectedAsteroid(ProspectedAsteroidEvent), Trade;
+    "PVPKill" => PVPKill(PVPKillEvent), Combat;
+    "Rank" => Rank(CommanderRankEvent), Startup;
+    "ReceiveText" => ReceiveText(ReceiveTextEvent), Other;
+    "RedeemVoucher" => RedeemVoucher(RedeemVoucherEvent), StationServices;
+    "RefuelAll" => RefuelAll(RefuelAllEvent), StationServices;
+    "Repair" => Repair(RepairEvent), StationServices;
+    "RepairAll" => RepairAll(RepairAllEvent), StationServices;
+    "Reputation" => Reputation(CommanderReputationEvent), Startup;
+    "ReservoirReplenished" => ReservoirReplenished(ReservoirReplenishedEvent), Other;
+    "RestockVehicle" => RestockVehicle(RestockVehicleEvent), StationServices;
+    "SAAScanComplete" => SAAScanComplete(SAAScanCompleteEvent), Exploration;
+    "SAASignalsFound" => SAASignalsFound(SAASignalsFoundEvent), Exploration;
+    "Scan" => Scan(ScanEvent), Exploration;
+    "ScanBaryCentre" => ScanBaryCentre(ScanBaryCentreEvent), Exploration;
+    "ScanOrganic" => ScanOrganic(ScanOrganicEvent), Odyssey;
+    "SellDrones" => SellDrones(SellDronesEvent), StationServices;
+    "SellExplorationData" => SellExplorationData(SellExplorationDataEvent), Exploration;
+    "SellMicroResources" => SellMicroResources(SellMicroResourcesEvent), Odyssey;
+    "SellOrganicData" => SellOrganicData(SellOrganicDataEvent), Odyssey;
+    "ShieldState" => ShieldState(ShieldStateEvent), Combat;
+    "ShipLocker" => ShipLocker(ShipLockerEvent), Odyssey;
+    "ShipTargeted" => ShipTargeted(ShipTargetedEvent), Combat;
+    "Shipyard" => Shipyard(ShipyardEvent), StationServices;
+    "ShipyardBuy" => ShipyardBuy(ShipyardBuyEvent), StationServices;
+    "ShipyardSell" => ShipyardSell(ShipyardSellEvent), StationServices;
+    "ShipyardSwap" => ShipyardSwap(ShipyardSwapEvent), StationServices;
+    "ShipyardTransfer" => ShipyardTransfer(ShipyardTransferEvent), StationServices;
+    "Shutdown" => ShutDown(EmptyEvent), Other;
+    "SRVDestroyed" => SRVDestroyed(SRVDestroyedEvent), Combat;
+    "StartJump" => StartJump(StartJumpEvent), Travel;
+    "StoredModules" => StoredModules(StoredModulesEvent), StationServices;
+    "StoredShips" => StoredShips(StoredShipsEvent), StationServices;
+    "SuitLoadout" => SuitLoadout(SuitLoadoutEvent), Odyssey;
+    "SupercruiseEntry" => SupercruiseEntry(SupercruiseEntryEvent), Travel;
+    "SupercruiseExit" => SupercruiseExit(SupercruiseExitEvent), Travel;
+    "Synthesis" => Synthesis(SynthesisEvent), Other;
+    "TechnologyBroker" => TechnologyBroker(TechnologyBrokerEvent), StationServices;
+    "Touchdown" => Touchdown(SurfaceEvent), Travel;
+    "TradeMicroResources" => TradeMicroResources(TradeMicroResourcesEvent), Odyssey;
+    "UnderAttack" => UnderAttack(UnderAttackEvent), Combat;
+    "Undocked" => Undocked(UndockedEvent), Travel;
+    "UpgradeSuit" => UpgradeSuit(UpgradeSuitEvent), Odyssey;
+    "UpgradeWeapon" => UpgradeWeapon(UpgradeWeaponEvent), Odyssey;
+    "USSDrop" => USSDrop(USSDropEvent), Travel;
 }
 
 
@@ -192,9 +489,116 @@ mod tests {
         assert!(serialized.contains("\"timestamp\":\"2025-01-13T18:05:28Z\""));
     }
 
+    #[test]
+    fn test_deserialize_serialize_journal_event_bounty() {
 
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "event":"Bounty", "Rewards":[ { "Faction":"Ngalinn Jet Natural Incorporated", "Reward":248290 } ], "PilotName":"$npc_name_decorate:#name=Kumiko Kalaka;", "PilotName_Localised":"Kumiko Kalaka", "Target":"krait_mkii", "Target_Localised":"Krait Mk II", "TotalReward":248290, "VictimFaction":"Society of Ngalinn" }"#;
 
+        let event: JournalEvent = serde_json::from_str(json).expect("Failed to deserialize BountyEvent");
 
-}
+        match event.clone() {
+            JournalEvent::Bounty(bounty_event) => {
+                assert_eq!(bounty_event.total_reward, Some(248290));
+                assert_eq!(bounty_event.victim_faction.value, "Society of Ngalinn");
+            },
+            _ => panic!("Expected BountyEvent but got a different event")
+        }
+
+        let serialized: serde_json::Value = serde_json::to_value(&event).expect("Failed to serialize BountyEvent");
+        let expected: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn test_deserialize_journal_event_heat_warning() {
+        let json = r#"{ "timestamp":"2025-01-04T19:31:02Z", "event":"HeatWarning" }"#;
+
+        let event: JournalEvent = serde_json::from_str(json).expect("Failed to deserialize HeatWarning");
 
+        match event {
+            JournalEvent::HeatWarning(empty_event) => {
+                assert_eq!(empty_event.event_meta.timestamp, create_timestamp("2025-01-04T19:31:02Z"));
+            },
+            _ => panic!("Expected HeatWarning but got a different event")
+        }
+    }
+
+    #[test]
+    fn test_deserialize_serialize_journal_event_unknown() {
+
+        let json = r#"{"timestamp":"2025-01-04T19:30:12Z","event":"WibbleFish","Wibble":"Fish","Count":3}"#;
+
+        let event: JournalEvent = serde_json::from_str(json).expect("Failed to deserialize unknown event");
+
+        match event.clone() {
+            JournalEvent::Unknown(unknown) => {
+                assert_eq!(unknown.event, "WibbleFish");
+                assert_eq!(unknown.event_meta.timestamp, create_timestamp("2025-01-04T19:30:12Z"));
+                assert_eq!(unknown.raw["Wibble"], "Fish");
+                assert_eq!(unknown.raw["Count"], 3);
+            }
+            _ => panic!("Expected Unknown but got a different event")
+        }
+
+        // The raw json is written back out unchanged
+        let serialized = serde_json::to_value(&event).expect("Failed to serialize unknown event");
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_serialize_journal_event_extra() {
+
+        let json = r#"{"timestamp":"2025-01-04T19:45:12Z","event":"FSDTarget","Name":"Col 285 Sector CC-K a38-2","SystemAddress":5068732573105,"StarClass":"M","RemainingJumpsInRoute":2,"Wibble":{"Fish":[1,2,3]}}"#;
+
+        let event: JournalEvent = serde_json::from_str(json).expect("Failed to deserialize FSDTargetEvent");
+
+        match &event {
+            JournalEvent::FSDTarget(fsd_target) => {
+                assert_eq!(fsd_target.extra.len(), 1);
+                assert_eq!(fsd_target.extra["Wibble"]["Fish"][2], 3);
+            }
+            _ => panic!("Expected FSDTarget but got a different event")
+        }
+
+        // The fields that are not modelled are written back out
+        let serialized = serde_json::to_value(&event).expect("Failed to serialize FSDTargetEvent");
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_journal_event_ext() {
+        let file_header: JournalEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-04T19:27:12Z", "event":"Fileheader", "part":1, "language":"English/UK", "Odyssey":true, "gameversion":"4.0.0.1904", "build":"r308767/r0 " }"#).unwrap();
+        assert_eq!(file_header.timestamp(), create_timestamp("2025-01-04T19:27:12Z"));
+        assert_eq!(file_header.event_name(), "Fileheader");
+        assert_eq!(file_header.category(), EventCategory::Startup);
+
+        let heat_damage: JournalEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-04T19:45:12Z", "event":"HeatDamage" }"#).unwrap();
+        assert_eq!(heat_damage.timestamp(), create_timestamp("2025-01-04T19:45:12Z"));
+        assert_eq!(heat_damage.event_name(), "HeatDamage");
+        assert_eq!(heat_damage.category(), EventCategory::Combat);
+
+        let unknown: JournalEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-04T19:45:12Z", "event":"WibbleFish" }"#).unwrap();
+        assert_eq!(unknown.timestamp(), create_timestamp("2025-01-04T19:45:12Z"));
+        assert_eq!(unknown.event_name(), "WibbleFish");
+        assert_eq!(unknown.category(), EventCategory::Other);
+    }
+
+    #[test]
+    fn test_deserialize_journal_event_invalid_known_event() {
+        let mistyped = r#"{ "timestamp":"2025-01-04T19:45:12Z", "event":"Bounty", "Rewards":[ { "Faction":"Canonn", "Reward":1000 } ], "Target":123, "TotalReward":1000, "VictimFaction":"Pirates" }"#;
+        let missing_fields = r#"{ "timestamp":"2025-01-04T19:45:12Z", "event":"MissionAbandoned" }"#;
+        let missing_timestamp = r#"{ "event":"HeatWarning" }"#;
+
+        let error = serde_json::from_str::<JournalEvent>(mistyped).expect_err("Mistyped Bounty should not deserialize");
+        assert!(error.to_string().starts_with("Invalid Bounty event: invalid type: integer `123`"), "{}", error);
+
+        let error = serde_json::from_str::<JournalEvent>(missing_fields).expect_err("Empty MissionAbandoned should not deserialize");
+        assert!(error.to_string().starts_with("Invalid MissionAbandoned event: missing field"), "{}", error);
+
+        let error = serde_json::from_str::<JournalEvent>(missing_timestamp).expect_err("HeatWarning without a timestamp should not deserialize");
+        assert!(error.to_string().starts_with("Invalid HeatWarning event: missing field `timestamp`"), "{}", error);
+
+        assert!(serde_json::from_str::<JournalEvent>(r#"{ "timestamp":"2025-01-04T19:45:12Z" }"#).is_err());
+    }
+}
 
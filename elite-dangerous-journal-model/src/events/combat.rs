@@ -0,0 +1,16 @@
+/// Bounty event
+pub mod bounty;
+/// Combat and capital ship bond events
+pub mod bonds;
+/// Died event
+pub mod died;
+/// Interdiction events
+pub mod interdiction;
+/// Fighter and SRV destroyed events
+pub mod destroyed;
+/// Hull, shield and under attack events
+pub mod damage;
+/// PVP Kill event
+pub mod pvp_kill;
+/// Ship targeted event
+pub mod ship_targeted;
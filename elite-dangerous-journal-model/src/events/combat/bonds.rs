@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Emitted when the player is rewarded for destroying a ship in a combat zone
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:42:19Z",
+///   "event": "FactionKillBond",
+///   "Reward": 80000,
+///   "AwardingFaction": "$faction_Federation;",
+///   "AwardingFaction_Localised": "Federation",
+///   "VictimFaction": "$faction_Empire;",
+///   "VictimFaction_Localised": "Empire"
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#factionkillbond
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "FactionKillBondEventSchema", into = "FactionKillBondEventSchema")]
+pub struct FactionKillBondEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The reward for the kill
+    pub reward: u64,
+
+    /// The faction paying the reward
+    pub awarding_faction: LocalisedValue,
+
+    /// The faction of the victim
+    pub victim_faction: LocalisedValue,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<FactionKillBondEventSchema> for FactionKillBondEvent {
+    /// Convert from FactionKillBondEventSchema
+    fn from(value: FactionKillBondEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            reward: value.reward,
+            awarding_faction: LocalisedValue::new(value.awarding_faction, value.awarding_faction_localised),
+            victim_faction: LocalisedValue::new(value.victim_faction, value.victim_faction_localised),
+        }
+    }
+}
+
+/// As the faction kill bond event contains localised values we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FactionKillBondEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The reward for the kill
+    pub reward: u64,
+
+    /// The faction paying the reward
+    pub awarding_faction: String,
+
+    /// The localised faction paying the reward
+    #[serde(rename = "AwardingFaction_Localised", skip_serializing_if = "Option::is_none")]
+    pub awarding_faction_localised: Option<String>,
+
+    /// The faction of the victim
+    pub victim_faction: String,
+
+    /// The localised faction of the victim
+    #[serde(rename = "VictimFaction_Localised", skip_serializing_if = "Option::is_none")]
+    pub victim_faction_localised: Option<String>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<FactionKillBondEvent> for FactionKillBondEventSchema {
+    /// Convert from FactionKillBondEvent
+    fn from(value: FactionKillBondEvent) -> Self {
+        let (awarding_faction, awarding_faction_localised) = deconstruct_localised_value(value.awarding_faction);
+        let (victim_faction, victim_faction_localised) = deconstruct_localised_value(value.victim_faction);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            reward: value.reward,
+            awarding_faction,
+            awarding_faction_localised,
+            victim_faction,
+            victim_faction_localised,
+        }
+    }
+}
+
+/// Emitted when the player is rewarded for taking part in a capital ship combat
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:48:03Z",
+///   "event": "CapShipBond",
+///   "Reward": 200000,
+///   "AwardingFaction": "Federation",
+///   "VictimFaction": "Empire"
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#capshipbond
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CapShipBondEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The reward for the combat
+    pub reward: u64,
+
+    /// The faction paying the reward
+    pub awarding_faction: String,
+
+    /// The faction of the victim
+    pub victim_faction: String,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_faction_kill_bond_event_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "Reward":500, "AwardingFaction":"$faction_Federation;", "AwardingFaction_Localised":"Federation", "VictimFaction":"Lencali Freedom Party" }"#;
+
+        let event: FactionKillBondEvent = serde_json::from_str(json).expect("Failed to deserialize FactionKillBondEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T15:10:58Z"));
+        assert_eq!(event.reward, 500);
+        assert_eq!(event.awarding_faction, LocalisedValue::new("$faction_Federation;".to_string(), Some("Federation".to_string())));
+        assert_eq!(event.victim_faction, LocalisedValue::new("Lencali Freedom Party".to_string(), None));
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize FactionKillBondEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn test_cap_ship_bond_event() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "event":"CapShipBond", "Reward":200000, "AwardingFaction":"Federation", "VictimFaction":"Empire" }"#;
+
+        let event: CapShipBondEvent = serde_json::from_str(json).expect("Failed to deserialize CapShipBondEvent");
+
+        assert_eq!(event.reward, 200000);
+        assert_eq!(event.awarding_faction, "Federation");
+        assert_eq!(event.victim_faction, "Empire");
+    }
+}
@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// A reward paid by a faction as part of a bounty
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BountyReward {
+    /// The faction paying the reward
+    pub faction: String,
+    /// The reward amount
+    pub reward: u64,
+}
+
+/// Emitted when the player is awarded a bounty for a kill
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:10:58Z",
+///   "event": "Bounty",
+///   "Rewards": [
+///     { "Faction": "Ngalinn Jet Natural Incorporated", "Reward": 248290 }
+///   ],
+///   "PilotName": "$npc_name_decorate:#name=Kumiko Kalaka;",
+///   "PilotName_Localised": "Kumiko Kalaka",
+///   "Target": "krait_mkii",
+///   "Target_Localised": "Krait Mk II",
+///   "TotalReward": 248290,
+///   "VictimFaction": "Society of Ngalinn"
+/// }
+/// ```
+///
+/// **Note**: Bounties for skimmers use a single ```Faction``` and ```Reward``` rather than the ```Rewards``` list
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#bounty
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "BountyEventSchema", into = "BountyEventSchema")]
+pub struct BountyEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The rewards from each faction for the target
+    pub rewards: Option<Vec<BountyReward>>,
+
+    /// The name of the pilot destroyed
+    pub pilot_name: Option<LocalisedValue>,
+
+    /// The type of ship destroyed
+    pub target: LocalisedValue,
+
+    /// The total reward across all the factions
+    pub total_reward: Option<u64>,
+
+    /// The faction of the victim
+    pub victim_faction: LocalisedValue,
+
+    /// If the bounty was shared with other players the number of players it was shared with
+    pub shared_with_others: Option<u32>,
+
+    /// The faction paying the reward (skimmer bounties only)
+    pub faction: Option<String>,
+
+    /// The reward (skimmer bounties only)
+    pub reward: Option<u64>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<BountyEventSchema> for BountyEvent {
+    /// Convert from BountyEventSchema
+    fn from(value: BountyEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            rewards: value.rewards,
+            pilot_name: LocalisedValue::new_optional(value.pilot_name, value.pilot_name_localised),
+            target: LocalisedValue::new(value.target, value.target_localised),
+            total_reward: value.total_reward,
+            victim_faction: LocalisedValue::new(value.victim_faction, value.victim_faction_localised),
+            shared_with_others: value.shared_with_others,
+            faction: value.faction,
+            reward: value.reward,
+        }
+    }
+}
+
+/// As the bounty event contains localised values we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BountyEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The rewards from each faction for the target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewards: Option<Vec<BountyReward>>,
+
+    /// The name of the pilot destroyed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pilot_name: Option<String>,
+
+    /// The localised name of the pilot destroyed
+    #[serde(rename = "PilotName_Localised", skip_serializing_if = "Option::is_none")]
+    pub pilot_name_localised: Option<String>,
+
+    /// The type of ship destroyed
+    pub target: String,
+
+    /// The localised type of ship destroyed
+    #[serde(rename = "Target_Localised", skip_serializing_if = "Option::is_none")]
+    pub target_localised: Option<String>,
+
+    /// The total reward across all the factions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_reward: Option<u64>,
+
+    /// The faction of the victim
+    pub victim_faction: String,
+
+    /// The localised faction of the victim
+    #[serde(rename = "VictimFaction_Localised", skip_serializing_if = "Option::is_none")]
+    pub victim_faction_localised: Option<String>,
+
+    /// If the bounty was shared with other players the number of players it was shared with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_with_others: Option<u32>,
+
+    /// The faction paying the reward (skimmer bounties only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+
+    /// The reward (skimmer bounties only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reward: Option<u64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<BountyEvent> for BountyEventSchema {
+    /// Convert from BountyEvent
+    fn from(value: BountyEvent) -> Self {
+        let (pilot_name, pilot_name_localised) = deconstruct_optional_localised_value(value.pilot_name);
+        let (target, target_localised) = deconstruct_localised_value(value.target);
+        let (victim_faction, victim_faction_localised) = deconstruct_localised_value(value.victim_faction);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            rewards: value.rewards,
+            pilot_name,
+            pilot_name_localised,
+            target,
+            target_localised,
+            total_reward: value.total_reward,
+            victim_faction,
+            victim_faction_localised,
+            shared_with_others: value.shared_with_others,
+            faction: value.faction,
+            reward: value.reward,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_bounty_event_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "Rewards":[ { "Faction":"Ngalinn Jet Natural Incorporated", "Reward":248290 } ], "PilotName":"$npc_name_decorate:#name=Kumiko Kalaka;", "PilotName_Localised":"Kumiko Kalaka", "Target":"krait_mkii", "Target_Localised":"Krait Mk II", "TotalReward":248290, "VictimFaction":"Society of Ngalinn" }"#;
+
+        let event: BountyEvent = serde_json::from_str(json).expect("Failed to deserialize BountyEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T15:10:58Z"));
+        assert_eq!(event.rewards, Some(vec![BountyReward { faction: "Ngalinn Jet Natural Incorporated".to_string(), reward: 248290 }]));
+        assert_eq!(event.pilot_name, Some(LocalisedValue::new("$npc_name_decorate:#name=Kumiko Kalaka;".to_string(), Some("Kumiko Kalaka".to_string()))));
+        assert_eq!(event.target, LocalisedValue::new("krait_mkii".to_string(), Some("Krait Mk II".to_string())));
+        assert_eq!(event.total_reward, Some(248290));
+        assert_eq!(event.victim_faction, LocalisedValue::new("Society of Ngalinn".to_string(), None));
+        assert_eq!(event.faction, None);
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize BountyEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn test_bounty_event_skimmer() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "Target":"Skimmer", "Faction":"Society of Ngalinn", "Reward":1000, "VictimFaction":"Society of Ngalinn" }"#;
+
+        let event: BountyEvent = serde_json::from_str(json).expect("Failed to deserialize BountyEvent");
+
+        assert_eq!(event.rewards, None);
+        assert_eq!(event.faction, Some("Society of Ngalinn".to_string()));
+        assert_eq!(event.reward, Some(1000));
+        assert_eq!(event.target.value, "Skimmer");
+    }
+}
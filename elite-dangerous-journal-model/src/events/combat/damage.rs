@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Emitted when hull health drops below a threshold (20% steps)
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:10:04Z",
+///   "event": "HullDamage",
+///   "Health": 0.798496,
+///   "PlayerPilot": true,
+///   "Fighter": false
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#hulldamage
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct HullDamageEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The hull health (0.0 - 1.0)
+    pub health: f64,
+
+    /// Is the player piloting the damaged vessel
+    pub player_pilot: bool,
+
+    /// Is the damaged vessel a fighter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fighter: Option<bool>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Emitted when the shields are lost or restored
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:09:52Z",
+///   "event": "ShieldState",
+///   "ShieldsUp": false
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#shieldstate
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShieldStateEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// Are the shields up
+    pub shields_up: bool,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// What is under attack
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum UnderAttackTarget {
+    /// The players fighter is under attack
+    Fighter,
+    /// The players ship is under attack while they fly a fighter
+    Mothership,
+    /// The player is under attack
+    You,
+    /// Catch all for any other target
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Emitted when under fire (no more than once every 10 seconds)
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:09:41Z",
+///   "event": "UnderAttack",
+///   "Target": "You"
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#underattack
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UnderAttackEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// What is under attack
+    pub target: UnderAttackTarget,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_hull_damage_event() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "event":"HullDamage", "Health":0.798496, "PlayerPilot":true, "Fighter":false }"#;
+
+        let event: HullDamageEvent = serde_json::from_str(json).expect("Failed to deserialize HullDamageEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T15:10:58Z"));
+        assert_eq!(event.health, 0.798496);
+        assert!(event.player_pilot);
+        assert_eq!(event.fighter, Some(false));
+    }
+
+    #[test]
+    fn test_shield_state_event() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "event":"ShieldState", "ShieldsUp":false }"#;
+
+        let event: ShieldStateEvent = serde_json::from_str(json).expect("Failed to deserialize ShieldStateEvent");
+
+        assert!(!event.shields_up);
+    }
+
+    #[test]
+    fn test_under_attack_event() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "event":"UnderAttack", "Target":"You" }"#;
+        let event: UnderAttackEvent = serde_json::from_str(json).expect("Failed to deserialize UnderAttackEvent");
+        assert_eq!(event.target, UnderAttackTarget::You);
+
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "event":"UnderAttack", "Target":"Wingman" }"#;
+        let event: UnderAttackEvent = serde_json::from_str(json).expect("Failed to deserialize UnderAttackEvent");
+        assert_eq!(event.target, UnderAttackTarget::Other("Wingman".to_string()));
+    }
+}
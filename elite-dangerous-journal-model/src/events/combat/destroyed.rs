@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Emitted when a ship launched fighter is destroyed
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:17:26Z",
+///   "event": "FighterDestroyed",
+///   "ID": 13
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#fighterdestroyed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FighterDestroyedEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the fighter
+    #[serde(rename = "ID")]
+    pub id: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Emitted when the players SRV is destroyed
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T16:50:02Z",
+///   "event": "SRVDestroyed",
+///   "ID": 53,
+///   "SRVType": "testbuggy",
+///   "SRVType_Localised": "SRV Scarab"
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#srvdestroyed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "SRVDestroyedEventSchema", into = "SRVDestroyedEventSchema")]
+pub struct SRVDestroyedEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the SRV
+    pub id: u64,
+
+    /// The type of SRV
+    pub srv_type: LocalisedValue,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<SRVDestroyedEventSchema> for SRVDestroyedEvent {
+    /// Convert from SRVDestroyedEventSchema
+    fn from(value: SRVDestroyedEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            id: value.id,
+            srv_type: LocalisedValue::new(value.srv_type, value.srv_type_localised),
+        }
+    }
+}
+
+/// As the SRV destroyed event contains a localised value we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SRVDestroyedEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the SRV
+    #[serde(rename = "ID")]
+    pub id: u64,
+
+    /// The type of SRV
+    #[serde(rename = "SRVType")]
+    pub srv_type: String,
+
+    /// The localised type of SRV
+    #[serde(rename = "SRVType_Localised", skip_serializing_if = "Option::is_none")]
+    pub srv_type_localised: Option<String>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<SRVDestroyedEvent> for SRVDestroyedEventSchema {
+    /// Convert from SRVDestroyedEvent
+    fn from(value: SRVDestroyedEvent) -> Self {
+        let (srv_type, srv_type_localised) = deconstruct_localised_value(value.srv_type);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            id: value.id,
+            srv_type,
+            srv_type_localised,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_fighter_destroyed_event() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "event":"FighterDestroyed", "ID":13 }"#;
+
+        let event: FighterDestroyedEvent = serde_json::from_str(json).expect("Failed to deserialize FighterDestroyedEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T15:10:58Z"));
+        assert_eq!(event.id, 13);
+    }
+
+    #[test]
+    fn test_srv_destroyed_event_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "ID":53, "SRVType":"testbuggy", "SRVType_Localised":"SRV Scarab" }"#;
+
+        let event: SRVDestroyedEvent = serde_json::from_str(json).expect("Failed to deserialize SRVDestroyedEvent");
+
+        assert_eq!(event.id, 53);
+        assert_eq!(event.srv_type, LocalisedValue::new("testbuggy".to_string(), Some("SRV Scarab".to_string())));
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize SRVDestroyedEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// One of the killers when the player was killed by a wing
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Killer {
+    /// The name of the killer
+    pub name: String,
+    /// The ship of the killer
+    pub ship: String,
+    /// The combat rank of the killer
+    pub rank: String,
+}
+
+/// Emitted when the player has been killed
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:20:11Z",
+///   "event": "Died",
+///   "KillerName": "$ShipName_Police_Independent;",
+///   "KillerName_Localised": "System Authority Vessel",
+///   "KillerShip": "viper",
+///   "KillerRank": "Deadly"
+/// }
+/// ```
+///
+/// If the player was killed by a wing then the ```Killers``` list is used instead:
+///
+/// ```json
+/// {
+///   "timestamp": "2016-06-10T14:32:03Z",
+///   "event": "Died",
+///   "Killers": [
+///     { "Name": "Cmdr HRC1", "Ship": "Vulture", "Rank": "Competent" },
+///     { "Name": "Cmdr HRC2", "Ship": "Python", "Rank": "Master" }
+///   ]
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#died
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "DiedEventSchema", into = "DiedEventSchema")]
+pub struct DiedEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The name of the killer (when killed by a single ship)
+    pub killer_name: Option<LocalisedValue>,
+
+    /// The ship of the killer (when killed by a single ship)
+    pub killer_ship: Option<String>,
+
+    /// The combat rank of the killer (when killed by a single ship)
+    pub killer_rank: Option<String>,
+
+    /// The killers (when killed by a wing)
+    pub killers: Option<Vec<Killer>>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<DiedEventSchema> for DiedEvent {
+    /// Convert from DiedEventSchema
+    fn from(value: DiedEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            killer_name: LocalisedValue::new_optional(value.killer_name, value.killer_name_localised),
+            killer_ship: value.killer_ship,
+            killer_rank: value.killer_rank,
+            killers: value.killers,
+        }
+    }
+}
+
+/// As the died event contains a localised value we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DiedEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the killer (when killed by a single ship)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killer_name: Option<String>,
+
+    /// The localised name of the killer
+    #[serde(rename = "KillerName_Localised", skip_serializing_if = "Option::is_none")]
+    pub killer_name_localised: Option<String>,
+
+    /// The ship of the killer (when killed by a single ship)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killer_ship: Option<String>,
+
+    /// The combat rank of the killer (when killed by a single ship)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killer_rank: Option<String>,
+
+    /// The killers (when killed by a wing)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killers: Option<Vec<Killer>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<DiedEvent> for DiedEventSchema {
+    /// Convert from DiedEvent
+    fn from(value: DiedEvent) -> Self {
+        let (killer_name, killer_name_localised) = deconstruct_optional_localised_value(value.killer_name);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            killer_name,
+            killer_name_localised,
+            killer_ship: value.killer_ship,
+            killer_rank: value.killer_rank,
+            killers: value.killers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_died_event_single_killer_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "KillerName":"$ShipName_Police_Independent;", "KillerName_Localised":"System Authority Vessel", "KillerShip":"viper", "KillerRank":"Deadly" }"#;
+
+        let event: DiedEvent = serde_json::from_str(json).expect("Failed to deserialize DiedEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T15:10:58Z"));
+        assert_eq!(event.killer_name, Some(LocalisedValue::new("$ShipName_Police_Independent;".to_string(), Some("System Authority Vessel".to_string()))));
+        assert_eq!(event.killer_ship, Some("viper".to_string()));
+        assert_eq!(event.killer_rank, Some("Deadly".to_string()));
+        assert_eq!(event.killers, None);
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize DiedEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn test_died_event_wing_killers() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "Killers":[ { "Name":"Cmdr HRC1", "Ship":"Vulture", "Rank":"Competent" }, { "Name":"Cmdr HRC2", "Ship":"Python", "Rank":"Master" } ] }"#;
+
+        let event: DiedEvent = serde_json::from_str(json).expect("Failed to deserialize DiedEvent");
+
+        assert_eq!(event.killer_name, None);
+        assert_eq!(event.killers, Some(vec![
+            Killer { name: "Cmdr HRC1".to_string(), ship: "Vulture".to_string(), rank: "Competent".to_string() },
+            Killer { name: "Cmdr HRC2".to_string(), ship: "Python".to_string(), rank: "Master".to_string() },
+        ]));
+    }
+}
@@ -0,0 +1,389 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Emitted when the player has escaped an interdiction
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:01:44Z",
+///   "event": "EscapeInterdiction",
+///   "Interdictor": "$npc_name_decorate:#name=Kumiko Kalaka;",
+///   "Interdictor_Localised": "Kumiko Kalaka",
+///   "IsPlayer": false
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#escapeinterdiction
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "EscapeInterdictionEventSchema", into = "EscapeInterdictionEventSchema")]
+pub struct EscapeInterdictionEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The name of the interdictor
+    pub interdictor: LocalisedValue,
+
+    /// Was the interdictor a player
+    pub is_player: bool,
+
+    /// Was the interdictor a thargoid
+    pub is_thargoid: Option<bool>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<EscapeInterdictionEventSchema> for EscapeInterdictionEvent {
+    /// Convert from EscapeInterdictionEventSchema
+    fn from(value: EscapeInterdictionEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            interdictor: LocalisedValue::new(value.interdictor, value.interdictor_localised),
+            is_player: value.is_player,
+            is_thargoid: value.is_thargoid,
+        }
+    }
+}
+
+/// As the escape interdiction event contains a localised value we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EscapeInterdictionEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the interdictor
+    pub interdictor: String,
+
+    /// The localised name of the interdictor
+    #[serde(rename = "Interdictor_Localised", skip_serializing_if = "Option::is_none")]
+    pub interdictor_localised: Option<String>,
+
+    /// Was the interdictor a player
+    pub is_player: bool,
+
+    /// Was the interdictor a thargoid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_thargoid: Option<bool>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<EscapeInterdictionEvent> for EscapeInterdictionEventSchema {
+    /// Convert from EscapeInterdictionEvent
+    fn from(value: EscapeInterdictionEvent) -> Self {
+        let (interdictor, interdictor_localised) = deconstruct_localised_value(value.interdictor);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            interdictor,
+            interdictor_localised,
+            is_player: value.is_player,
+            is_thargoid: value.is_thargoid,
+        }
+    }
+}
+
+/// Emitted when the player has been interdicted
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:01:44Z",
+///   "event": "Interdicted",
+///   "Submitted": false,
+///   "Interdictor": "$npc_name_decorate:#name=Kumiko Kalaka;",
+///   "Interdictor_Localised": "Kumiko Kalaka",
+///   "IsPlayer": false,
+///   "Faction": "Society of Ngalinn"
+/// }
+/// ```
+///
+/// **Note**: ```CombatRank``` is only present when the interdictor is a player, ```Faction``` and
+/// ```Power``` only when an NPC. Currently this is not validated
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#interdicted
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "InterdictedEventSchema", into = "InterdictedEventSchema")]
+pub struct InterdictedEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// Did the player submit to the interdiction
+    pub submitted: bool,
+
+    /// The name of the interdictor
+    pub interdictor: LocalisedValue,
+
+    /// Was the interdictor a player
+    pub is_player: bool,
+
+    /// The combat rank of the interdictor (player only)
+    pub combat_rank: Option<u8>,
+
+    /// The faction of the interdictor (NPC only)
+    pub faction: Option<String>,
+
+    /// The power the interdictor works for (NPC only)
+    pub power: Option<String>,
+
+    /// Was the interdictor a thargoid
+    pub is_thargoid: Option<bool>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<InterdictedEventSchema> for InterdictedEvent {
+    /// Convert from InterdictedEventSchema
+    fn from(value: InterdictedEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            submitted: value.submitted,
+            interdictor: LocalisedValue::new(value.interdictor, value.interdictor_localised),
+            is_player: value.is_player,
+            combat_rank: value.combat_rank,
+            faction: value.faction,
+            power: value.power,
+            is_thargoid: value.is_thargoid,
+        }
+    }
+}
+
+/// As the interdicted event contains a localised value we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct InterdictedEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// Did the player submit to the interdiction
+    pub submitted: bool,
+
+    /// The name of the interdictor
+    pub interdictor: String,
+
+    /// The localised name of the interdictor
+    #[serde(rename = "Interdictor_Localised", skip_serializing_if = "Option::is_none")]
+    pub interdictor_localised: Option<String>,
+
+    /// Was the interdictor a player
+    pub is_player: bool,
+
+    /// The combat rank of the interdictor (player only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub combat_rank: Option<u8>,
+
+    /// The faction of the interdictor (NPC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+
+    /// The power the interdictor works for (NPC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<String>,
+
+    /// Was the interdictor a thargoid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_thargoid: Option<bool>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<InterdictedEvent> for InterdictedEventSchema {
+    /// Convert from InterdictedEvent
+    fn from(value: InterdictedEvent) -> Self {
+        let (interdictor, interdictor_localised) = deconstruct_localised_value(value.interdictor);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            submitted: value.submitted,
+            interdictor,
+            interdictor_localised,
+            is_player: value.is_player,
+            combat_rank: value.combat_rank,
+            faction: value.faction,
+            power: value.power,
+            is_thargoid: value.is_thargoid,
+        }
+    }
+}
+
+/// Emitted when the player has attempted to interdict another ship
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:04:51Z",
+///   "event": "Interdiction",
+///   "Success": true,
+///   "Interdicted": "$npc_name_decorate:#name=Kumiko Kalaka;",
+///   "Interdicted_Localised": "Kumiko Kalaka",
+///   "IsPlayer": false,
+///   "Faction": "Society of Ngalinn"
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#interdiction
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "InterdictionEventSchema", into = "InterdictionEventSchema")]
+pub struct InterdictionEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// Was the interdiction successful
+    pub success: bool,
+
+    /// The name of the interdicted pilot
+    pub interdicted: LocalisedValue,
+
+    /// Was the interdicted pilot a player
+    pub is_player: bool,
+
+    /// The combat rank of the interdicted pilot (player only)
+    pub combat_rank: Option<u8>,
+
+    /// The faction of the interdicted pilot (NPC only)
+    pub faction: Option<String>,
+
+    /// The power the interdicted pilot works for (NPC only)
+    pub power: Option<String>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<InterdictionEventSchema> for InterdictionEvent {
+    /// Convert from InterdictionEventSchema
+    fn from(value: InterdictionEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            success: value.success,
+            interdicted: LocalisedValue::new(value.interdicted, value.interdicted_localised),
+            is_player: value.is_player,
+            combat_rank: value.combat_rank,
+            faction: value.faction,
+            power: value.power,
+        }
+    }
+}
+
+/// As the interdiction event contains a localised value we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct InterdictionEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// Was the interdiction successful
+    pub success: bool,
+
+    /// The name of the interdicted pilot
+    pub interdicted: String,
+
+    /// The localised name of the interdicted pilot
+    #[serde(rename = "Interdicted_Localised", skip_serializing_if = "Option::is_none")]
+    pub interdicted_localised: Option<String>,
+
+    /// Was the interdicted pilot a player
+    pub is_player: bool,
+
+    /// The combat rank of the interdicted pilot (player only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub combat_rank: Option<u8>,
+
+    /// The faction of the interdicted pilot (NPC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+
+    /// The power the interdicted pilot works for (NPC only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<String>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<InterdictionEvent> for InterdictionEventSchema {
+    /// Convert from InterdictionEvent
+    fn from(value: InterdictionEvent) -> Self {
+        let (interdicted, interdicted_localised) = deconstruct_localised_value(value.interdicted);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            success: value.success,
+            interdicted,
+            interdicted_localised,
+            is_player: value.is_player,
+            combat_rank: value.combat_rank,
+            faction: value.faction,
+            power: value.power,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_escape_interdiction_event_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "Interdictor":"$npc_name_decorate:#name=Dread Pirate Roberts;", "Interdictor_Localised":"Dread Pirate Roberts", "IsPlayer":false, "IsThargoid":false }"#;
+
+        let event: EscapeInterdictionEvent = serde_json::from_str(json).expect("Failed to deserialize EscapeInterdictionEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T15:10:58Z"));
+        assert_eq!(event.interdictor.localised_value, Some("Dread Pirate Roberts".to_string()));
+        assert!(!event.is_player);
+        assert_eq!(event.is_thargoid, Some(false));
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize EscapeInterdictionEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn test_interdicted_event_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "Submitted":false, "Interdictor":"Dread Pirate Roberts", "IsPlayer":false, "Faction":"Timocani Purple Posse" }"#;
+
+        let event: InterdictedEvent = serde_json::from_str(json).expect("Failed to deserialize InterdictedEvent");
+
+        assert!(!event.submitted);
+        assert_eq!(event.interdictor, LocalisedValue::new("Dread Pirate Roberts".to_string(), None));
+        assert_eq!(event.faction, Some("Timocani Purple Posse".to_string()));
+        assert_eq!(event.combat_rank, None);
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize InterdictedEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn test_interdiction_event_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "Success":true, "Interdicted":"Fred Flintstone", "IsPlayer":true, "CombatRank":5 }"#;
+
+        let event: InterdictionEvent = serde_json::from_str(json).expect("Failed to deserialize InterdictionEvent");
+
+        assert!(event.success);
+        assert_eq!(event.interdicted.value, "Fred Flintstone");
+        assert!(event.is_player);
+        assert_eq!(event.combat_rank, Some(5));
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize InterdictionEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Emitted when the player kills another player
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:31:40Z",
+///   "event": "PVPKill",
+///   "Victim": "Bob",
+///   "CombatRank": 3
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#pvpkill
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PVPKillEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the player killed
+    pub victim: String,
+
+    /// The combat rank of the player killed
+    pub combat_rank: u8,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_pvp_kill_event() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "event":"PVPKill", "Victim":"Bob", "CombatRank":3 }"#;
+
+        let event: PVPKillEvent = serde_json::from_str(json).expect("Failed to deserialize PVPKillEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T15:10:58Z"));
+        assert_eq!(event.victim, "Bob");
+        assert_eq!(event.combat_rank, 3);
+    }
+}
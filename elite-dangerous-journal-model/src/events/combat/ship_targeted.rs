@@ -0,0 +1,272 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// The legal status of a targeted ship
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum LegalStatus {
+    /// The target is clean
+    Clean,
+    /// The target is wanted
+    Wanted,
+    /// The target is in a lawless system
+    Lawless,
+    /// The target is an enemy
+    Enemy,
+    /// The target is wanted and an enemy
+    WantedEnemy,
+    /// The target is a bounty hunter
+    Hunter,
+    /// Catch all for any other legal status
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Emitted when the player selects a target, the amount of detail depends on the scan stage
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T15:09:33Z",
+///   "event": "ShipTargeted",
+///   "TargetLocked": true,
+///   "Ship": "krait_mkii",
+///   "Ship_Localised": "Krait Mk II",
+///   "ScanStage": 3,
+///   "PilotName": "$npc_name_decorate:#name=Kumiko Kalaka;",
+///   "PilotName_Localised": "Kumiko Kalaka",
+///   "PilotRank": "Deadly",
+///   "ShieldHealth": 100.000000,
+///   "HullHealth": 100.000000,
+///   "Faction": "Society of Ngalinn",
+///   "LegalStatus": "Wanted",
+///   "Bounty": 248290
+/// }
+/// ```
+///
+/// **Note**: ```Ship``` and the scan details are only present when ```TargetLocked``` is true and increase
+/// with ```ScanStage```. Currently this is not validated
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Combat/#shiptargeted
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "ShipTargetedEventSchema", into = "ShipTargetedEventSchema")]
+pub struct ShipTargetedEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// Is a target locked
+    pub target_locked: bool,
+
+    /// The type of ship targeted
+    pub ship: Option<LocalisedValue>,
+
+    /// The scan stage (0 - 3)
+    pub scan_stage: Option<u8>,
+
+    /// The name of the pilot
+    pub pilot_name: Option<LocalisedValue>,
+
+    /// The combat rank of the pilot
+    pub pilot_rank: Option<String>,
+
+    /// The squadron id of the pilot
+    pub squadron_id: Option<String>,
+
+    /// The shield health of the target
+    pub shield_health: Option<f64>,
+
+    /// The hull health of the target
+    pub hull_health: Option<f64>,
+
+    /// The faction of the target
+    pub faction: Option<String>,
+
+    /// The legal status of the target
+    pub legal_status: Option<LegalStatus>,
+
+    /// The bounty on the target
+    pub bounty: Option<u64>,
+
+    /// The subsystem targeted
+    pub subsystem: Option<LocalisedValue>,
+
+    /// The health of the subsystem targeted
+    pub subsystem_health: Option<f64>,
+
+    /// The power the target is pledged to
+    pub power: Option<String>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ShipTargetedEventSchema> for ShipTargetedEvent {
+    /// Convert from ShipTargetedEventSchema
+    fn from(value: ShipTargetedEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            target_locked: value.target_locked,
+            ship: LocalisedValue::new_optional(value.ship, value.ship_localised),
+            scan_stage: value.scan_stage,
+            pilot_name: LocalisedValue::new_optional(value.pilot_name, value.pilot_name_localised),
+            pilot_rank: value.pilot_rank,
+            squadron_id: value.squadron_id,
+            shield_health: value.shield_health,
+            hull_health: value.hull_health,
+            faction: value.faction,
+            legal_status: value.legal_status,
+            bounty: value.bounty,
+            subsystem: LocalisedValue::new_optional(value.subsystem, value.subsystem_localised),
+            subsystem_health: value.subsystem_health,
+            power: value.power,
+        }
+    }
+}
+
+/// As the ship targeted event contains localised values we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipTargetedEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// Is a target locked
+    pub target_locked: bool,
+
+    /// The type of ship targeted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship: Option<String>,
+
+    /// The localised type of ship targeted
+    #[serde(rename = "Ship_Localised", skip_serializing_if = "Option::is_none")]
+    pub ship_localised: Option<String>,
+
+    /// The scan stage (0 - 3)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan_stage: Option<u8>,
+
+    /// The name of the pilot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pilot_name: Option<String>,
+
+    /// The localised name of the pilot
+    #[serde(rename = "PilotName_Localised", skip_serializing_if = "Option::is_none")]
+    pub pilot_name_localised: Option<String>,
+
+    /// The combat rank of the pilot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pilot_rank: Option<String>,
+
+    /// The squadron id of the pilot
+    #[serde(rename = "SquadronID", skip_serializing_if = "Option::is_none")]
+    pub squadron_id: Option<String>,
+
+    /// The shield health of the target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shield_health: Option<f64>,
+
+    /// The hull health of the target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hull_health: Option<f64>,
+
+    /// The faction of the target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+
+    /// The legal status of the target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_status: Option<LegalStatus>,
+
+    /// The bounty on the target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounty: Option<u64>,
+
+    /// The subsystem targeted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subsystem: Option<String>,
+
+    /// The localised subsystem targeted
+    #[serde(rename = "Subsystem_Localised", skip_serializing_if = "Option::is_none")]
+    pub subsystem_localised: Option<String>,
+
+    /// The health of the subsystem targeted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subsystem_health: Option<f64>,
+
+    /// The power the target is pledged to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<String>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ShipTargetedEvent> for ShipTargetedEventSchema {
+    /// Convert from ShipTargetedEvent
+    fn from(value: ShipTargetedEvent) -> Self {
+        let (ship, ship_localised) = deconstruct_optional_localised_value(value.ship);
+        let (pilot_name, pilot_name_localised) = deconstruct_optional_localised_value(value.pilot_name);
+        let (subsystem, subsystem_localised) = deconstruct_optional_localised_value(value.subsystem);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            target_locked: value.target_locked,
+            ship,
+            ship_localised,
+            scan_stage: value.scan_stage,
+            pilot_name,
+            pilot_name_localised,
+            pilot_rank: value.pilot_rank,
+            squadron_id: value.squadron_id,
+            shield_health: value.shield_health,
+            hull_health: value.hull_health,
+            faction: value.faction,
+            legal_status: value.legal_status,
+            bounty: value.bounty,
+            subsystem,
+            subsystem_localised,
+            subsystem_health: value.subsystem_health,
+            power: value.power,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_ship_targeted_event_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "TargetLocked":true, "Ship":"krait_mkii", "Ship_Localised":"Krait Mk II", "ScanStage":3, "PilotName":"$npc_name_decorate:#name=Kumiko Kalaka;", "PilotName_Localised":"Kumiko Kalaka", "PilotRank":"Dangerous", "ShieldHealth":100.0, "HullHealth":100.0, "Faction":"Society of Ngalinn", "LegalStatus":"Wanted", "Bounty":248290, "Subsystem":"$int_powerplant_size6_class5_name;", "Subsystem_Localised":"Power Plant", "SubsystemHealth":99.5 }"#;
+
+        let event: ShipTargetedEvent = serde_json::from_str(json).expect("Failed to deserialize ShipTargetedEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T15:10:58Z"));
+        assert!(event.target_locked);
+        assert_eq!(event.ship, Some(LocalisedValue::new("krait_mkii".to_string(), Some("Krait Mk II".to_string()))));
+        assert_eq!(event.scan_stage, Some(3));
+        assert_eq!(event.pilot_rank, Some("Dangerous".to_string()));
+        assert_eq!(event.legal_status, Some(LegalStatus::Wanted));
+        assert_eq!(event.bounty, Some(248290));
+        assert_eq!(event.subsystem_health, Some(99.5));
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize ShipTargetedEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn test_ship_targeted_event_target_lost() {
+        let json = r#"{ "timestamp":"2025-01-23T15:10:58Z", "TargetLocked":false }"#;
+
+        let event: ShipTargetedEvent = serde_json::from_str(json).expect("Failed to deserialize ShipTargetedEvent");
+
+        assert!(!event.target_locked);
+        assert_eq!(event.ship, None);
+        assert_eq!(event.scan_stage, None);
+    }
+}
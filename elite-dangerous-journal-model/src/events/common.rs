@@ -2,10 +2,37 @@
 /// Common system structs
 pub mod system;
 
+/// Common station structs
+pub mod station;
+
+/// Common engineering structs
+pub mod engineering;
+
+/// Parsing of the internal module identifiers
+pub mod module_id;
+
+/// Static catalogue of the ship hulls
+pub mod ship_catalogue;
+
 use serde_with::serde_as;
 use serde::{Deserialize, Serialize};
 use chrono::NaiveDateTime;
 
+/// The fields of an event that are not modelled, keyed by the json field name
+///
+/// Every event (and the nested structs of an event) flattens these into an ```extra``` field, so fields the model does
+/// not know about, such as those added by a game update, are kept rather than dropped. They are re-emitted when the
+/// event is serialized, so a parsed journal line serializes back to the same json
+pub type ExtraFields = serde_json::Map<String, serde_json::Value>;
+
+/// Removes the fields of the modelled value from the extra fields, serde does not consume the fields of a flattened
+/// enum so without this they would also be captured in the extra fields (and written out twice)
+pub(crate) fn remove_modelled_fields<T: Serialize>(extra: &mut ExtraFields, modelled: &T) {
+    if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(modelled) {
+        fields.keys().for_each(|key| { extra.remove(key); });
+    }
+}
+
 /// A common struct that is present in all events
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -17,7 +44,7 @@ pub struct EventMeta {
 }
 
 
-/// Meta data about a ship
+/// Metadata about a ship
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct ShipMeta {
@@ -25,7 +52,7 @@ pub struct ShipMeta {
     pub ship: String,
     /// The ID of the ship being described
     #[serde(rename = "ShipID")]
-    pub ship_id: u8,
+    pub ship_id: u64,
     /// The name of the ship
     pub ship_name: String,
     /// The in-game identity (e.g. "NCC-1701")
@@ -36,9 +63,12 @@ pub struct ShipMeta {
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct EmptyEvent {
-    /// the basic event meta data object
+    /// The event metadata
     #[serde(flatten)]
-    pub meta: EventMeta,
+    pub event_meta: EventMeta,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// A structure that allows a field that has both localised and non localised value as one
@@ -96,4 +126,39 @@ pub fn deconstruct_optional_localised_value(value: Option<LocalisedValue>) -> (O
         None => (None, None),
         Some(v) => (Some(String::from(v.get_value())), v.localised_value)
     }
-}
\ No newline at end of file
+}
+/// An event that the model does not (yet) support, the event name, timestamp and the full json are kept so that
+/// new Frontier events can still be handled (or counted) downstream
+///
+/// This serializes back to the raw json so no data is lost when an unknown event is written out again
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(try_from = "serde_json::Value", into = "serde_json::Value")]
+pub struct UnknownEvent {
+    /// The name of the event (the ```event``` field)
+    pub event: String,
+    /// The event metadata
+    pub event_meta: EventMeta,
+    /// The full json of the event, including the ```event``` and ```timestamp``` fields
+    pub raw: serde_json::Value,
+}
+
+impl TryFrom<serde_json::Value> for UnknownEvent {
+    type Error = String;
+
+    fn try_from(raw: serde_json::Value) -> Result<Self, Self::Error> {
+        let event = raw.get("event")
+            .and_then(|event| event.as_str())
+            .ok_or_else(|| String::from("Unknown event is missing the event field"))?
+            .to_string();
+        let event_meta = EventMeta::deserialize(&raw)
+            .map_err(|e| format!("Unknown event {} has invalid metadata: {}", event, e))?;
+
+        Ok(Self { event, event_meta, raw })
+    }
+}
+
+impl From<UnknownEvent> for serde_json::Value {
+    fn from(value: UnknownEvent) -> Self {
+        value.raw
+    }
+}
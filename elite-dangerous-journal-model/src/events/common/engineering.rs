@@ -0,0 +1,215 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, BoolFromInt};
+use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, LocalisedValue};
+
+/// A change to a module attribute made by engineering, most modifiers are numeric but some (e.g. the weapon mode)
+/// are text in which case only the ```value_str``` is present
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "EngineeringModifierSchema", into = "EngineeringModifierSchema")]
+pub struct EngineeringModifier {
+    /// The attribute that has been modified
+    pub label: String,
+    /// The engineered value
+    pub value: Option<f64>,
+    /// The value before engineering
+    pub original_value: Option<f64>,
+    /// Is a lower value better
+    pub less_is_good: Option<bool>,
+    /// The value of a text modifier
+    pub value_str: Option<LocalisedValue>,
+}
+
+impl EngineeringModifier {
+
+    /// Has the modification improved the attribute, None for text modifiers
+    pub fn is_improvement(&self) -> Option<bool> {
+        match (self.value, self.original_value, self.less_is_good) {
+            (Some(value), Some(original_value), Some(less_is_good)) => Some((value < original_value) == less_is_good),
+            _ => None,
+        }
+    }
+}
+
+impl From<EngineeringModifierSchema> for EngineeringModifier {
+    fn from(value: EngineeringModifierSchema) -> Self {
+        Self {
+            label: value.label,
+            value: value.value,
+            original_value: value.original_value,
+            less_is_good: value.less_is_good,
+            value_str: LocalisedValue::new_optional(value.value_str, value.value_str_localised),
+        }
+    }
+}
+
+/// Schema for the modifier due to the localised value
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct EngineeringModifierSchema {
+    label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_value: Option<f64>,
+    #[serde_as(as = "Option<BoolFromInt>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    less_is_good: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_str: Option<String>,
+    #[serde(rename = "ValueStr_Localised", skip_serializing_if = "Option::is_none")]
+    value_str_localised: Option<String>,
+}
+
+impl From<EngineeringModifier> for EngineeringModifierSchema {
+    fn from(value: EngineeringModifier) -> Self {
+        let (value_str, value_str_localised) = deconstruct_optional_localised_value(value.value_str);
+        Self {
+            label: value.label,
+            value: value.value,
+            original_value: value.original_value,
+            less_is_good: value.less_is_good,
+            value_str,
+            value_str_localised,
+        }
+    }
+}
+
+/// The engineering applied to a module
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "EngineeringSchema", into = "EngineeringSchema")]
+pub struct Engineering {
+    /// The name of the engineer, not present for pre-engineered modules
+    pub engineer: Option<String>,
+    /// The id of the engineer, not present for pre-engineered modules
+    pub engineer_id: Option<u64>,
+    /// The id of the blueprint
+    pub blueprint_id: u64,
+    /// The name of the blueprint
+    pub blueprint_name: String,
+    /// The grade of the blueprint
+    pub level: u8,
+    /// The progress through the grade, between 0 and 1
+    pub quality: f64,
+    /// The experimental effect applied if any
+    pub experimental_effect: Option<LocalisedValue>,
+    /// The changes made to the module
+    pub modifiers: Vec<EngineeringModifier>,
+}
+
+impl From<EngineeringSchema> for Engineering {
+    fn from(value: EngineeringSchema) -> Self {
+        Self {
+            engineer: value.engineer,
+            engineer_id: value.engineer_id,
+            blueprint_id: value.blueprint_id,
+            blueprint_name: value.blueprint_name,
+            level: value.level,
+            quality: value.quality,
+            experimental_effect: LocalisedValue::new_optional(value.experimental_effect, value.experimental_effect_localised),
+            modifiers: value.modifiers,
+        }
+    }
+}
+
+/// Schema for the engineering due to the localised value
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct EngineeringSchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    engineer: Option<String>,
+    #[serde(rename = "EngineerID", skip_serializing_if = "Option::is_none")]
+    engineer_id: Option<u64>,
+    #[serde(rename = "BlueprintID")]
+    blueprint_id: u64,
+    blueprint_name: String,
+    level: u8,
+    quality: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    experimental_effect: Option<String>,
+    #[serde(rename = "ExperimentalEffect_Localised", skip_serializing_if = "Option::is_none")]
+    experimental_effect_localised: Option<String>,
+    modifiers: Vec<EngineeringModifier>,
+}
+
+impl From<Engineering> for EngineeringSchema {
+    fn from(value: Engineering) -> Self {
+        let (experimental_effect, experimental_effect_localised) = deconstruct_optional_localised_value(value.experimental_effect);
+        Self {
+            engineer: value.engineer,
+            engineer_id: value.engineer_id,
+            blueprint_id: value.blueprint_id,
+            blueprint_name: value.blueprint_name,
+            level: value.level,
+            quality: value.quality,
+            experimental_effect,
+            experimental_effect_localised,
+            modifiers: value.modifiers,
+        }
+    }
+}
+
+/// A quantity of a material or commodity used when crafting, synthesising or unlocking at a technology broker
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "IngredientSchema", into = "IngredientSchema")]
+pub struct Ingredient {
+    /// The material or commodity
+    pub name: LocalisedValue,
+    /// The number used
+    pub count: u32,
+}
+
+impl From<IngredientSchema> for Ingredient {
+    fn from(value: IngredientSchema) -> Self {
+        Self {
+            name: LocalisedValue::new(value.name, value.name_localised),
+            count: value.count,
+        }
+    }
+}
+
+/// Schema for the ingredient due to the localised value
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct IngredientSchema {
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+    count: u32,
+}
+
+impl From<Ingredient> for IngredientSchema {
+    fn from(value: Ingredient) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            name,
+            name_localised,
+            count: value.count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_serialize_engineering() {
+        let json = r#"{"Engineer":"Elvira Martuuk","EngineerID":300160,"BlueprintID":128673694,"BlueprintName":"FSD_LongRange","Level":5,"Quality":1.0,"ExperimentalEffect":"special_fsd_heavy","ExperimentalEffect_Localised":"Mass Manager","Modifiers":[{"Label":"Mass","Value":52.0,"OriginalValue":40.0,"LessIsGood":1},{"Label":"FSDOptimalMass","Value":3224.0,"OriginalValue":2000.0,"LessIsGood":0},{"Label":"WeaponMode","ValueStr":"$WeaponMode_Burst;","ValueStr_Localised":"Burst"}]}"#;
+
+        let engineering: Engineering = serde_json::from_str(json).unwrap();
+
+        assert_eq!(engineering.engineer, Some("Elvira Martuuk".to_string()));
+        assert_eq!(engineering.blueprint_name, "FSD_LongRange");
+        assert_eq!(engineering.level, 5);
+        assert_eq!(engineering.experimental_effect, Some(LocalisedValue::new("special_fsd_heavy".to_string(), Some("Mass Manager".to_string()))));
+        assert_eq!(engineering.modifiers[0].less_is_good, Some(true));
+        assert_eq!(engineering.modifiers[0].is_improvement(), Some(false));
+        assert_eq!(engineering.modifiers[1].is_improvement(), Some(true));
+        assert_eq!(engineering.modifiers[2].value_str, Some(LocalisedValue::new("$WeaponMode_Burst;".to_string(), Some("Burst".to_string()))));
+        assert_eq!(engineering.modifiers[2].is_improvement(), None);
+
+        let serialized = serde_json::to_value(&engineering).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -0,0 +1,482 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+/// Where on the ship a module is fitted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModuleCategory {
+    /// A weapon (or mining tool) hardpoint e.g. ```hpt_pulselaser_gimbal_medium```
+    Hardpoint,
+    /// A utility mount e.g. ```hpt_shieldbooster_size0_class5```
+    Utility,
+    /// A core or optional internal e.g. ```int_hyperdrive_size5_class5```
+    Internal,
+    /// The bulkheads e.g. ```anaconda_armour_grade3```
+    Armour,
+    /// The identifier is not in a recognised format
+    Unknown,
+}
+
+/// How a hardpoint weapon is mounted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModuleMount {
+    /// Fixed forward
+    Fixed,
+    /// Gimballed, tracks the target within a cone
+    Gimballed,
+    /// Turreted, tracks the target in any direction
+    Turreted,
+}
+
+impl Display for ModuleMount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModuleMount::Fixed => write!(f, "Fixed"),
+            ModuleMount::Gimballed => write!(f, "Gimballed"),
+            ModuleMount::Turreted => write!(f, "Turreted"),
+        }
+    }
+}
+
+/// The size of a hardpoint, utility mounts are tiny
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HardpointSize {
+    /// A utility mount
+    Tiny,
+    /// Size 1
+    Small,
+    /// Size 2
+    Medium,
+    /// Size 3
+    Large,
+    /// Size 4
+    Huge,
+}
+
+impl Display for HardpointSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HardpointSize::Tiny => write!(f, "Tiny"),
+            HardpointSize::Small => write!(f, "Small"),
+            HardpointSize::Medium => write!(f, "Medium"),
+            HardpointSize::Large => write!(f, "Large"),
+            HardpointSize::Huge => write!(f, "Huge"),
+        }
+    }
+}
+
+/// An internal module identifier as written in the journal, e.g. ```int_hyperdrive_size5_class5``` or
+/// ```hpt_pulselaser_gimbal_medium```, parsed into its parts.
+///
+/// The outfitting events write the identifier as a localisation symbol e.g. ```$int_hyperdrive_size5_class5_name;```,
+/// these are parsed the same as the plain identifier.
+///
+/// Parsing never fails, identifiers that are not in a recognised format are [Unknown](ModuleCategory::Unknown) and
+/// the original identifier is always kept so it is serialized back untouched
+///
+/// Example:
+/// ```
+/// use elite_dangerous_journal_model::events::common::module_id::{ModuleId, ModuleMount, HardpointSize};
+///
+/// let module: ModuleId = "hpt_pulselaser_gimbal_medium".parse().unwrap();
+/// assert_eq!(module.mount(), Some(ModuleMount::Gimballed));
+/// assert_eq!(module.hardpoint_size(), Some(HardpointSize::Medium));
+/// assert_eq!(module.display_name(), "Medium Gimballed Pulse Laser");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct ModuleId {
+    raw: String,
+    category: ModuleCategory,
+    kind: Option<String>,
+    size: Option<u8>,
+    class: Option<u8>,
+    mount: Option<ModuleMount>,
+    hardpoint_size: Option<HardpointSize>,
+    variant: Option<String>,
+}
+
+impl ModuleId {
+
+    /// The identifier as written in the journal
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Where on the ship the module is fitted
+    pub fn category(&self) -> ModuleCategory {
+        self.category
+    }
+
+    /// The (lowercase) kind of module e.g. ```hyperdrive```, ```pulselaser``` or ```guardianfsdbooster```
+    pub fn kind(&self) -> Option<&str> {
+        self.kind.as_deref()
+    }
+
+    /// The size of the module, utility mounts are size 0
+    pub fn size(&self) -> Option<u8> {
+        self.size
+    }
+
+    /// The class of the module, 1 is an E rated module and 5 is an A rated module
+    pub fn class(&self) -> Option<u8> {
+        self.class
+    }
+
+    /// The rating letter of the module, e.g. ```A``` for class 5
+    pub fn rating(&self) -> Option<char> {
+        match self.class? {
+            1 => Some('E'),
+            2 => Some('D'),
+            3 => Some('C'),
+            4 => Some('B'),
+            5 => Some('A'),
+            _ => None,
+        }
+    }
+
+    /// How a hardpoint is mounted
+    pub fn mount(&self) -> Option<ModuleMount> {
+        self.mount
+    }
+
+    /// The size of a hardpoint
+    pub fn hardpoint_size(&self) -> Option<HardpointSize> {
+        self.hardpoint_size
+    }
+
+    /// The variant of the module, for pre-engineered modules this is the (lowercase) variant e.g. ```disruptor```
+    /// and for armour it is the grade e.g. ```grade3``` or ```reactive```
+    pub fn variant(&self) -> Option<&str> {
+        self.variant.as_deref()
+    }
+
+    /// Is this a Guardian technology module
+    pub fn is_guardian(&self) -> bool {
+        self.kind.as_deref().is_some_and(|kind| kind.starts_with("guardian"))
+    }
+
+    /// Is this a pre-engineered (or otherwise special) variant of a module e.g. ```hpt_pulselaser_fixed_medium_disruptor```
+    pub fn is_engineered_variant(&self) -> bool {
+        self.category != ModuleCategory::Armour && self.variant.is_some()
+    }
+
+    /// A human readable name for the module e.g. ```5A Frame Shift Drive```, unknown identifiers are returned as is
+    pub fn display_name(&self) -> String {
+        let Some(kind) = self.kind.as_deref() else {
+            return self.raw.clone();
+        };
+
+        if self.category == ModuleCategory::Armour {
+            let grade = match self.variant.as_deref() {
+                Some("grade1") => "Lightweight Alloy".to_string(),
+                Some("grade2") => "Reinforced Alloy".to_string(),
+                Some("grade3") => "Military Grade Composite".to_string(),
+                Some("mirrored") => "Mirrored Surface Composite".to_string(),
+                Some("reactive") => "Reactive Surface Composite".to_string(),
+                Some(grade) => title_case(grade),
+                None => return "Armour".to_string(),
+            };
+            return format!("{} Armour", grade);
+        }
+
+        let mut parts = Vec::new();
+        if let Some(hardpoint_size) = self.hardpoint_size.filter(|size| *size != HardpointSize::Tiny) {
+            parts.push(hardpoint_size.to_string());
+        } else if let Some(size) = self.size {
+            parts.push(match self.rating() {
+                Some(rating) => format!("{}{}", size, rating),
+                None => format!("Size {}", size),
+            });
+        }
+        if let Some(mount) = self.mount {
+            parts.push(mount.to_string());
+        }
+        parts.push(module_name(kind).map(str::to_string).unwrap_or_else(|| title_case(kind)));
+        if let Some(variant) = self.variant.as_deref() {
+            parts.push(format!("({})", title_case(variant)));
+        }
+        parts.join(" ")
+    }
+
+    fn unknown(raw: &str) -> Self {
+        Self {
+            raw: raw.to_string(),
+            category: ModuleCategory::Unknown,
+            kind: None,
+            size: None,
+            class: None,
+            mount: None,
+            hardpoint_size: None,
+            variant: None,
+        }
+    }
+
+    fn parse(raw: &str) -> Self {
+        let lower = raw.to_lowercase();
+        let lower = lower.strip_prefix('$')
+            .and_then(|symbol| symbol.strip_suffix("_name;"))
+            .unwrap_or(&lower);
+        let tokens: Vec<&str> = lower.split('_').collect();
+
+        // Ship names can span several tokens e.g. ```krait_mkii_armour_grade3``` so the armour token is searched for
+        let armour = tokens.iter().skip(1).position(|token| *token == "armour").map(|position| position + 1);
+        let (category, tokens) = match (tokens.as_slice(), armour) {
+            (["int", rest @ ..], _) => (ModuleCategory::Internal, rest),
+            (["hpt", rest @ ..], _) => (ModuleCategory::Hardpoint, rest),
+            (tokens, Some(position)) => (ModuleCategory::Armour, &tokens[position + 1..]),
+            _ => return Self::unknown(raw),
+        };
+
+        let mut id = Self::unknown(raw);
+        id.category = category;
+
+        if category == ModuleCategory::Armour {
+            id.kind = Some("armour".to_string());
+            id.variant = (!tokens.is_empty()).then(|| tokens.join("_"));
+            return id;
+        }
+
+        let mut kind = Vec::new();
+        let mut variant = Vec::new();
+        for token in tokens {
+            if let Some(size) = token.strip_prefix("size").and_then(|size| size.parse().ok()) {
+                id.size = Some(size);
+            } else if let Some(class) = token.strip_prefix("class").and_then(|class| class.parse().ok()) {
+                id.class = Some(class);
+            } else if let Some(mount) = parse_mount(token) {
+                id.mount = Some(mount);
+            } else if let Some(hardpoint_size) = parse_hardpoint_size(token) {
+                id.hardpoint_size = Some(hardpoint_size);
+            } else if id.size.is_none() && id.class.is_none() && id.mount.is_none() && id.hardpoint_size.is_none() {
+                kind.push(*token);
+            } else {
+                variant.push(*token);
+            }
+        }
+
+        if kind.is_empty() {
+            return Self::unknown(raw);
+        }
+        id.kind = Some(kind.join("_"));
+        id.variant = (!variant.is_empty()).then(|| variant.join("_"));
+
+        if category == ModuleCategory::Hardpoint && (id.size == Some(0) || id.hardpoint_size == Some(HardpointSize::Tiny)) {
+            id.category = ModuleCategory::Utility;
+        }
+        id
+    }
+}
+
+impl Display for ModuleId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl FromStr for ModuleId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
+
+fn parse_mount(token: &str) -> Option<ModuleMount> {
+    match token {
+        "fixed" => Some(ModuleMount::Fixed),
+        "gimbal" => Some(ModuleMount::Gimballed),
+        "turret" => Some(ModuleMount::Turreted),
+        _ => None,
+    }
+}
+
+fn parse_hardpoint_size(token: &str) -> Option<HardpointSize> {
+    match token {
+        "tiny" => Some(HardpointSize::Tiny),
+        "small" => Some(HardpointSize::Small),
+        "medium" => Some(HardpointSize::Medium),
+        "large" => Some(HardpointSize::Large),
+        "huge" => Some(HardpointSize::Huge),
+        _ => None,
+    }
+}
+
+fn title_case(value: &str) -> String {
+    value.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The in game names of the common module kinds
+fn module_name(kind: &str) -> Option<&'static str> {
+    let name = match kind {
+        "hyperdrive" => "Frame Shift Drive",
+        "hyperdrive_overcharge" => "Frame Shift Drive (SCO)",
+        "engine" => "Thrusters",
+        "powerplant" => "Power Plant",
+        "powerdistributor" => "Power Distributor",
+        "lifesupport" => "Life Support",
+        "sensors" => "Sensors",
+        "fueltank" => "Fuel Tank",
+        "fuelscoop" => "Fuel Scoop",
+        "cargorack" => "Cargo Rack",
+        "corrosionproofcargorack" => "Corrosion Resistant Cargo Rack",
+        "shieldgenerator" => "Shield Generator",
+        "shieldcellbank" => "Shield Cell Bank",
+        "hullreinforcement" => "Hull Reinforcement Package",
+        "modulereinforcement" => "Module Reinforcement Package",
+        "detailedsurfacescanner" => "Detailed Surface Scanner",
+        "dronecontrol_collection" => "Collector Limpet Controller",
+        "dronecontrol_prospector" => "Prospector Limpet Controller",
+        "dronecontrol_fueltransfer" => "Fuel Transfer Limpet Controller",
+        "dronecontrol_repair" => "Repair Limpet Controller",
+        "dronecontrol_resourcesiphon" => "Hatch Breaker Limpet Controller",
+        "dronecontrol_recon" => "Recon Limpet Controller",
+        "repairer" => "Auto Field-Maintenance Unit",
+        "refinery" => "Refinery",
+        "fsdinterdictor" => "Frame Shift Drive Interdictor",
+        "supercruiseassist" => "Supercruise Assist",
+        "dockingcomputer_standard" => "Standard Docking Computer",
+        "dockingcomputer_advanced" => "Advanced Docking Computer",
+        "buggybay" => "Planetary Vehicle Hangar",
+        "fighterbay" => "Fighter Hangar",
+        "passengercabin" => "Passenger Cabin",
+        "planetapproachsuite" | "planetapproachsuite_advanced" => "Planetary Approach Suite",
+        "guardianfsdbooster" => "Guardian FSD Booster",
+        "guardianshieldreinforcement" => "Guardian Shield Reinforcement Package",
+        "guardianhullreinforcement" => "Guardian Hull Reinforcement Package",
+        "guardianmodulereinforcement" => "Guardian Module Reinforcement Package",
+        "guardianpowerplant" => "Guardian Hybrid Power Plant",
+        "guardianpowerdistributor" => "Guardian Hybrid Power Distributor",
+        "pulselaser" => "Pulse Laser",
+        "pulselaserburst" => "Burst Laser",
+        "beamlaser" => "Beam Laser",
+        "multicannon" => "Multi-Cannon",
+        "cannon" => "Cannon",
+        "slugshot" => "Fragment Cannon",
+        "railgun" => "Rail Gun",
+        "plasmaaccelerator" => "Plasma Accelerator",
+        "dumbfiremissilerack" => "Missile Rack",
+        "basicmissilerack" => "Seeker Missile Rack",
+        "drunkmissilerack" => "Pack-Hound Missile Rack",
+        "minelauncher" => "Mine Launcher",
+        "mininglaser" => "Mining Laser",
+        "mining_abrblstr" => "Abrasion Blaster",
+        "mining_seismchrgwarhd" => "Seismic Charge Launcher",
+        "mining_subsurfdispmisle" => "Sub-Surface Displacement Missile",
+        "flakmortar" => "Remote Release Flak Launcher",
+        "flechettelauncher" => "Remote Release Flechette Launcher",
+        "atmulticannon" => "AX Multi-Cannon",
+        "atdumbfiremissile" => "AX Missile Rack",
+        "guardian_gausscannon" => "Guardian Gauss Cannon",
+        "guardian_plasmalauncher" => "Guardian Plasma Charger",
+        "guardian_shardcannon" => "Guardian Shard Cannon",
+        "shieldbooster" => "Shield Booster",
+        "heatsinklauncher" => "Heat Sink Launcher",
+        "chafflauncher" => "Chaff Launcher",
+        "plasmapointdefence" => "Point Defence",
+        "electroniccountermeasure" => "Electronic Countermeasure",
+        "cargoscanner" => "Manifest Scanner",
+        "cloudscanner" => "Frame Shift Wake Scanner",
+        "crimescanner" => "Kill Warrant Scanner",
+        "mrascanner" => "Pulse Wave Analyser",
+        "xenoscanner" => "Xeno Scanner",
+        _ => return None,
+    };
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_internal_module() {
+        let module: ModuleId = "int_hyperdrive_size5_class5".parse().unwrap();
+
+        assert_eq!(module.category(), ModuleCategory::Internal);
+        assert_eq!(module.kind(), Some("hyperdrive"));
+        assert_eq!(module.size(), Some(5));
+        assert_eq!(module.class(), Some(5));
+        assert_eq!(module.rating(), Some('A'));
+        assert_eq!(module.mount(), None);
+        assert!(!module.is_guardian());
+        assert_eq!(module.display_name(), "5A Frame Shift Drive");
+    }
+
+    #[test]
+    fn test_parse_hardpoints_and_utilities() {
+        let module: ModuleId = "Hpt_PulseLaser_Fixed_Medium_Disruptor".parse().unwrap();
+        assert_eq!(module.category(), ModuleCategory::Hardpoint);
+        assert_eq!(module.kind(), Some("pulselaser"));
+        assert_eq!(module.mount(), Some(ModuleMount::Fixed));
+        assert_eq!(module.hardpoint_size(), Some(HardpointSize::Medium));
+        assert_eq!(module.variant(), Some("disruptor"));
+        assert!(module.is_engineered_variant());
+        assert_eq!(module.display_name(), "Medium Fixed Pulse Laser (Disruptor)");
+
+        let module: ModuleId = "hpt_shieldbooster_size0_class5".parse().unwrap();
+        assert_eq!(module.category(), ModuleCategory::Utility);
+        assert_eq!(module.display_name(), "0A Shield Booster");
+
+        let module: ModuleId = "$hpt_multicannon_gimbal_medium_name;".parse().unwrap();
+        assert_eq!(module.as_str(), "$hpt_multicannon_gimbal_medium_name;");
+        assert_eq!(module.display_name(), "Medium Gimballed Multi-Cannon");
+
+        let module: ModuleId = "hpt_heatsinklauncher_turret_tiny".parse().unwrap();
+        assert_eq!(module.category(), ModuleCategory::Utility);
+        assert_eq!(module.display_name(), "Turreted Heat Sink Launcher");
+    }
+
+    #[test]
+    fn test_parse_guardian_and_armour() {
+        let module: ModuleId = "int_guardianfsdbooster_size5".parse().unwrap();
+        assert!(module.is_guardian());
+        assert_eq!(module.class(), None);
+        assert_eq!(module.display_name(), "Size 5 Guardian FSD Booster");
+
+        let module: ModuleId = "anaconda_armour_grade3".parse().unwrap();
+        assert_eq!(module.category(), ModuleCategory::Armour);
+        assert_eq!(module.variant(), Some("grade3"));
+        assert!(!module.is_engineered_variant());
+        assert_eq!(module.display_name(), "Military Grade Composite Armour");
+    }
+
+    #[test]
+    fn test_parse_armour_with_multi_token_ship_names() {
+        for (raw, grade) in [
+            ("krait_mkii_armour_grade3", "grade3"),
+            ("federation_corvette_armour_grade3", "grade3"),
+            ("type9_military_armour_grade3", "grade3"),
+            ("empire_trader_armour_grade3", "grade3"),
+            ("asp_scout_armour_grade1", "grade1"),
+            ("Krait_MkII_Armour_Reactive", "reactive"),
+        ] {
+            let module: ModuleId = raw.parse().unwrap();
+            assert_eq!(module.category(), ModuleCategory::Armour, "{}", raw);
+            assert_eq!(module.kind(), Some("armour"), "{}", raw);
+            assert_eq!(module.variant(), Some(grade), "{}", raw);
+        }
+
+        let module: ModuleId = "asp_scout_armour_grade1".parse().unwrap();
+        assert_eq!(module.display_name(), "Lightweight Alloy Armour");
+    }
+
+    #[test]
+    fn test_unknown_module_round_trips() {
+        let json = r#""PaintJob_Anaconda_Vibrant_Blue""#;
+
+        let module: ModuleId = serde_json::from_str(json).unwrap();
+
+        assert_eq!(module.category(), ModuleCategory::Unknown);
+        assert_eq!(module.kind(), None);
+        assert_eq!(module.display_name(), "PaintJob_Anaconda_Vibrant_Blue");
+        assert_eq!(serde_json::to_string(&module).unwrap(), json);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::events::common::module_id::HardpointSize;
+use crate::events::common::ShipMeta;
+
+/// The size of landing pad a ship needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LandingPadSize {
+    /// Small pads, available at outposts
+    Small,
+    /// Medium pads, available at outposts
+    Medium,
+    /// Large pads, not available at outposts
+    Large,
+}
+
+/// The sizes of the core internal slots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoreSlots {
+    /// The power plant slot
+    pub power_plant: u8,
+    /// The thrusters slot
+    pub thrusters: u8,
+    /// The frame shift drive slot
+    pub frame_shift_drive: u8,
+    /// The life support slot
+    pub life_support: u8,
+    /// The power distributor slot
+    pub power_distributor: u8,
+    /// The sensors slot
+    pub sensors: u8,
+    /// The fuel tank slot
+    pub fuel_tank: u8,
+}
+
+/// The slots a hull has
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotLayout {
+    /// The sizes of the weapon hardpoints
+    pub hardpoints: &'static [HardpointSize],
+    /// The number of utility mounts
+    pub utility: u8,
+    /// The core internal slots
+    pub core: CoreSlots,
+    /// The sizes of the optional internal slots (including military slots)
+    pub optional: &'static [u8],
+}
+
+/// The data of a hull that is not written in the journal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShipInfo {
+    /// The (lowercase) symbol used in the journal e.g. ```krait_mkii```
+    pub symbol: &'static str,
+    /// The in game name e.g. ```Krait MkII```
+    pub name: &'static str,
+    /// The manufacturer of the hull
+    pub manufacturer: &'static str,
+    /// The smallest landing pad the ship can use
+    pub pad_size: LandingPadSize,
+    /// The mass of the hull with no modules fitted in tons
+    pub hull_mass: f32,
+    /// The capacity of the stock fuel tank in tons
+    pub fuel_capacity: f32,
+    /// The slots of the hull
+    pub slots: SlotLayout,
+}
+
+use LandingPadSize::{Large, Medium, Small};
+
+const S: HardpointSize = HardpointSize::Small;
+const M: HardpointSize = HardpointSize::Medium;
+const L: HardpointSize = HardpointSize::Large;
+const H: HardpointSize = HardpointSize::Huge;
+
+#[allow(clippy::too_many_arguments)]
+const fn ship(
+    symbol: &'static str,
+    name: &'static str,
+    manufacturer: &'static str,
+    pad_size: LandingPadSize,
+    hull_mass: f32,
+    core: [u8; 7],
+    hardpoints: &'static [HardpointSize],
+    utility: u8,
+    optional: &'static [u8],
+) -> ShipInfo {
+    let [power_plant, thrusters, frame_shift_drive, life_support, power_distributor, sensors, fuel_tank] = core;
+    ShipInfo {
+        symbol,
+        name,
+        manufacturer,
+        pad_size,
+        hull_mass,
+        // the stock fuel tank is a class C tank which holds 2^size tons
+        fuel_capacity: (1u32 << fuel_tank) as f32,
+        slots: SlotLayout {
+            hardpoints,
+            utility,
+            core: CoreSlots { power_plant, thrusters, frame_shift_drive, life_support, power_distributor, sensors, fuel_tank },
+            optional,
+        },
+    }
+}
+
+/// The catalogue of player ships, the core slots are ordered power plant, thrusters, frame shift drive, life support,
+/// power distributor, sensors and fuel tank
+pub static SHIPS: &[ShipInfo] = &[
+    ship("adder", "Adder", "Zorgon Peterson", Small, 35.0, [3, 3, 3, 1, 2, 1, 3], &[M, S, S], 2, &[3, 3, 2, 1, 1]),
+    ship("anaconda", "Anaconda", "Faulcon DeLacy", Large, 400.0, [8, 7, 6, 5, 8, 8, 5], &[H, L, L, L, M, M, S, S], 8, &[7, 6, 6, 6, 5, 5, 5, 4, 4, 4, 2, 1]),
+    ship("asp", "Asp Explorer", "Lakon", Medium, 280.0, [5, 5, 5, 4, 4, 5, 5], &[L, L, M, M, S, S], 2, &[6, 5, 3, 3, 3, 2, 2, 1]),
+    ship("asp_scout", "Asp Scout", "Lakon", Medium, 150.0, [4, 4, 5, 4, 4, 3, 4], &[M, M, S, S], 2, &[5, 4, 3, 3, 2, 2, 1]),
+    ship("belugaliner", "Beluga Liner", "Saud Kruger", Large, 950.0, [6, 7, 7, 8, 6, 5, 6], &[M, M, M, M], 6, &[6, 6, 6, 6, 5, 5, 4, 3, 3, 1]),
+    ship("cobramkiii", "Cobra MkIII", "Faulcon DeLacy", Small, 180.0, [4, 4, 4, 3, 3, 3, 4], &[M, M, S, S], 2, &[4, 4, 4, 2, 2, 2, 1]),
+    ship("cobramkv", "Cobra MkV", "Faulcon DeLacy", Small, 150.0, [4, 4, 4, 3, 4, 3, 4], &[M, M, M, S, S], 2, &[5, 4, 4, 3, 3, 2, 2, 1]),
+    ship("cobramkiv", "Cobra MkIV", "Faulcon DeLacy", Small, 210.0, [4, 4, 4, 3, 3, 3, 4], &[M, M, S, S, S], 2, &[4, 4, 4, 3, 3, 2, 2, 1]),
+    ship("corsair", "Corsair", "Gutamaya", Medium, 265.0, [6, 6, 5, 4, 6, 4, 5], &[L, L, M, M, S, S], 4, &[6, 5, 5, 5, 4, 3, 3, 2, 1]),
+    ship("cutter", "Imperial Cutter", "Gutamaya", Large, 1100.0, [8, 8, 7, 7, 7, 7, 6], &[H, L, L, M, M, M, M], 8, &[8, 8, 6, 6, 6, 5, 5, 4, 3, 1]),
+    ship("diamondback", "Diamondback Scout", "Lakon", Small, 170.0, [4, 4, 4, 2, 3, 2, 4], &[M, M, S, S], 4, &[3, 3, 3, 2, 1]),
+    ship("diamondbackxl", "Diamondback Explorer", "Lakon", Small, 260.0, [4, 4, 5, 3, 4, 4, 5], &[L, M, M], 4, &[4, 4, 3, 3, 3, 2, 1]),
+    ship("dolphin", "Dolphin", "Saud Kruger", Small, 140.0, [4, 5, 4, 4, 3, 3, 4], &[S, S], 3, &[5, 4, 4, 3, 2, 2, 1]),
+    ship("eagle", "Eagle", "Core Dynamics", Small, 50.0, [2, 3, 3, 1, 2, 1, 2], &[S, S, S], 1, &[3, 2, 1, 1]),
+    ship("empire_courier", "Imperial Courier", "Gutamaya", Small, 35.0, [4, 3, 3, 1, 3, 2, 3], &[M, M, M], 4, &[3, 3, 2, 2, 1]),
+    ship("empire_eagle", "Imperial Eagle", "Gutamaya", Small, 50.0, [3, 3, 3, 1, 2, 2, 2], &[M, S, S], 1, &[3, 2, 1, 1]),
+    ship("empire_trader", "Imperial Clipper", "Gutamaya", Large, 400.0, [6, 6, 5, 5, 6, 5, 5], &[L, L, M, M], 4, &[7, 6, 4, 4, 3, 3, 2]),
+    ship("federation_corvette", "Federal Corvette", "Core Dynamics", Large, 900.0, [8, 7, 6, 5, 8, 8, 5], &[H, H, L, M, M, S, S], 8, &[7, 7, 7, 6, 6, 5, 5, 4, 4, 3, 1]),
+    ship("federation_dropship", "Federal Dropship", "Core Dynamics", Medium, 580.0, [6, 6, 5, 5, 6, 5, 5], &[L, M, M, M, M], 4, &[6, 5, 5, 4, 4, 3, 3]),
+    ship("federation_dropship_mkii", "Federal Assault Ship", "Core Dynamics", Medium, 480.0, [6, 6, 5, 5, 6, 4, 5], &[L, L, M, M], 4, &[5, 5, 4, 4, 3, 2]),
+    ship("federation_gunship", "Federal Gunship", "Core Dynamics", Medium, 580.0, [7, 6, 5, 5, 7, 5, 5], &[L, M, M, M, M, S, S], 4, &[6, 6, 5, 5, 4, 3, 3]),
+    ship("ferdelance", "Fer-de-Lance", "Zorgon Peterson", Medium, 250.0, [6, 5, 4, 4, 6, 4, 3], &[H, M, M, M, M], 6, &[5, 4, 4, 2, 1]),
+    ship("hauler", "Hauler", "Zorgon Peterson", Small, 14.0, [2, 2, 2, 1, 1, 1, 2], &[S], 2, &[3, 3, 2, 1, 1]),
+    ship("independant_trader", "Keelback", "Lakon", Medium, 180.0, [4, 4, 4, 1, 3, 2, 4], &[M, M, S, S], 3, &[5, 5, 4, 3, 2, 1]),
+    ship("krait_light", "Krait Phantom", "Faulcon DeLacy", Medium, 270.0, [7, 6, 5, 4, 7, 6, 5], &[L, L, M, M], 4, &[6, 6, 5, 5, 5, 3, 3]),
+    ship("krait_mkii", "Krait MkII", "Faulcon DeLacy", Medium, 320.0, [7, 6, 5, 4, 7, 6, 5], &[L, L, L, M, M], 4, &[6, 6, 5, 5, 4, 3, 3, 2]),
+    ship("mamba", "Mamba", "Zorgon Peterson", Medium, 250.0, [6, 5, 4, 4, 6, 4, 3], &[H, L, L, M, M], 6, &[5, 4, 4, 2, 1]),
+    ship("mandalay", "Mandalay", "Zorgon Peterson", Medium, 230.0, [5, 5, 5, 4, 5, 4, 4], &[M, M, S, S, S], 4, &[6, 5, 5, 4, 4, 3, 3, 2, 1]),
+    ship("orca", "Orca", "Saud Kruger", Large, 290.0, [5, 6, 5, 6, 6, 5, 5], &[L, M, S], 2, &[6, 5, 5, 5, 4, 4, 3, 2]),
+    ship("python", "Python", "Faulcon DeLacy", Medium, 350.0, [7, 6, 5, 4, 7, 6, 5], &[L, L, L, M, M], 4, &[6, 6, 6, 5, 5, 4, 3, 3, 2]),
+    ship("python_nx", "Python MkII", "Faulcon DeLacy", Medium, 450.0, [7, 6, 5, 4, 7, 6, 5], &[L, L, M, M, M, M], 4, &[6, 5, 5, 4, 3, 3, 2]),
+    ship("sidewinder", "Sidewinder", "Faulcon DeLacy", Small, 25.0, [2, 2, 2, 1, 1, 1, 1], &[S, S], 2, &[2, 2, 1, 1, 1]),
+    ship("type6", "Type-6 Transporter", "Lakon", Medium, 155.0, [3, 4, 4, 2, 3, 2, 4], &[S, S], 3, &[5, 5, 4, 4, 3, 3, 2, 1]),
+    ship("type7", "Type-7 Transporter", "Lakon", Large, 350.0, [4, 5, 5, 4, 4, 3, 5], &[S, S, S, S], 4, &[6, 6, 6, 5, 5, 5, 3, 3, 2, 1]),
+    ship("type8", "Type-8 Transporter", "Lakon", Medium, 400.0, [6, 6, 5, 4, 4, 4, 5], &[M, M, S, S, S], 4, &[6, 6, 6, 5, 5, 4, 4, 3, 2, 1]),
+    ship("type9", "Type-9 Heavy", "Lakon", Large, 850.0, [6, 7, 6, 5, 6, 4, 6], &[M, M, M, S, S], 4, &[8, 8, 7, 6, 5, 4, 4, 3, 3, 2]),
+    ship("type9_military", "Type-10 Defender", "Lakon", Large, 1200.0, [8, 7, 7, 5, 7, 4, 6], &[L, L, L, M, M, M, M, S, S], 8, &[8, 8, 7, 5, 4, 4, 3, 2]),
+    ship("typex", "Alliance Chieftain", "Lakon", Medium, 400.0, [6, 6, 5, 5, 4, 4, 5], &[L, L, M, S, S, S], 4, &[6, 5, 5, 4, 4, 3, 3, 2]),
+    ship("typex_2", "Alliance Crusader", "Lakon", Medium, 500.0, [6, 6, 5, 5, 4, 4, 5], &[L, M, M, S, S, S], 4, &[6, 5, 5, 4, 4, 3, 3, 2]),
+    ship("typex_3", "Alliance Challenger", "Lakon", Medium, 450.0, [6, 6, 5, 5, 4, 4, 5], &[L, M, M, M, M, S, S], 4, &[6, 5, 5, 4, 4, 3, 3, 2]),
+    ship("viper", "Viper MkIII", "Faulcon DeLacy", Small, 50.0, [3, 3, 3, 2, 3, 3, 2], &[M, M, S, S], 2, &[3, 3, 2, 1, 1]),
+    ship("viper_mkiv", "Viper MkIV", "Faulcon DeLacy", Small, 190.0, [4, 4, 4, 2, 3, 3, 4], &[M, M, S, S], 2, &[4, 4, 3, 3, 2, 2, 1]),
+    ship("vulture", "Vulture", "Core Dynamics", Small, 230.0, [4, 5, 5, 4, 5, 4, 3], &[L, L], 4, &[5, 4, 2, 1, 1]),
+];
+
+/// Look up a ship in the [catalogue](SHIPS) by its journal symbol, the journal is not consistent with the case of the
+/// symbols so the lookup ignores case
+///
+/// Example:
+/// ```
+/// use elite_dangerous_journal_model::events::common::ship_catalogue::{ship_info, LandingPadSize};
+///
+/// let ship = ship_info("Krait_MkII").unwrap();
+/// assert_eq!(ship.name, "Krait MkII");
+/// assert_eq!(ship.pad_size, LandingPadSize::Medium);
+/// ```
+pub fn ship_info(symbol: &str) -> Option<&'static ShipInfo> {
+    SHIPS.iter().find(|ship| ship.symbol.eq_ignore_ascii_case(symbol))
+}
+
+impl ShipMeta {
+
+    /// The catalogue data for the ship, None if the ship is not in the catalogue
+    pub fn ship_info(&self) -> Option<&'static ShipInfo> {
+        ship_info(&self.ship)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ship_info() {
+        let ship = ship_info("Anaconda").unwrap();
+
+        assert_eq!(ship.symbol, "anaconda");
+        assert_eq!(ship.manufacturer, "Faulcon DeLacy");
+        assert_eq!(ship.pad_size, LandingPadSize::Large);
+        assert_eq!(ship.hull_mass, 400.0);
+        assert_eq!(ship.fuel_capacity, 32.0);
+        assert_eq!(ship.slots.hardpoints.len(), 8);
+        assert_eq!(ship.slots.core.frame_shift_drive, 6);
+
+        assert_eq!(ship_info("type9_military").unwrap().name, "Type-10 Defender");
+        assert_eq!(ship_info("Python_NX").unwrap().name, "Python MkII");
+        for symbol in ["type8", "mandalay", "cobramkv", "corsair"] {
+            assert!(ship_info(symbol).is_some(), "{} is missing from the catalogue", symbol);
+        }
+        assert_eq!(ship_info("wibble"), None);
+    }
+
+    #[test]
+    fn test_catalogue_symbols_are_unique_and_lowercase() {
+        for (index, ship) in SHIPS.iter().enumerate() {
+            assert_eq!(ship.symbol, ship.symbol.to_lowercase());
+            assert!(SHIPS[index + 1..].iter().all(|other| other.symbol != ship.symbol), "{} is duplicated", ship.symbol);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, LocalisedValue};
+
+/// The type of a station (or other dockable location)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum StationType {
+    /// Coriolis starport
+    Coriolis,
+    /// Orbis starport
+    Orbis,
+    /// Ocellus starport
+    Ocellus,
+    /// Outpost
+    Outpost,
+    /// Surface outpost
+    CraterOutpost,
+    /// Surface port
+    CraterPort,
+    /// Asteroid base
+    AsteroidBase,
+    /// Mega ship
+    MegaShip,
+    /// Fleet carrier
+    FleetCarrier,
+    /// Odyssey settlement
+    OnFootSettlement,
+    /// Surface station
+    SurfaceStation,
+    /// Any other station type
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A service that is offered by a station
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StationService {
+    Dock,
+    Autodock,
+    BlackMarket,
+    Commodities,
+    Contacts,
+    Exploration,
+    Missions,
+    Outfitting,
+    CrewLounge,
+    Rearm,
+    Refuel,
+    Repair,
+    Shipyard,
+    Tuning,
+    Engineer,
+    MissionsGenerated,
+    Facilitator,
+    FlightController,
+    StationOperations,
+    Powerplay,
+    SearchRescue,
+    MaterialTrader,
+    #[serde(rename = "techBroker")]
+    TechBroker,
+    #[serde(rename = "stationMenu")]
+    StationMenu,
+    Shop,
+    Livery,
+    SocialSpace,
+    Bartender,
+    VistaGenomics,
+    PioneerSupplies,
+    ApexInterstellar,
+    FrontlineSolutions,
+    CarrierManagement,
+    CarrierFuel,
+    CarrierVendor,
+    VoucherRedemption,
+    /// Any other service
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The controlling faction of a station
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct StationFaction {
+    /// The name of the faction
+    pub name: String,
+    /// The state of the faction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction_state: Option<String>,
+}
+
+/// One of the economies of a station
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "StationEconomySchema", into = "StationEconomySchema")]
+pub struct StationEconomy {
+    /// The economy
+    pub name: LocalisedValue,
+    /// The proportion of the station that has this economy
+    pub proportion: f64,
+}
+
+impl From<StationEconomySchema> for StationEconomy {
+    fn from(value: StationEconomySchema) -> Self {
+        Self {
+            name: LocalisedValue::new(value.name, value.name_localised),
+            proportion: value.proportion,
+        }
+    }
+}
+
+/// Schema for the station economy due to the localised value
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct StationEconomySchema {
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+    proportion: f64,
+}
+
+impl From<StationEconomy> for StationEconomySchema {
+    fn from(value: StationEconomy) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            name,
+            name_localised,
+            proportion: value.proportion,
+        }
+    }
+}
+
+/// The information about a station that is given when docking at, approaching or starting the game at a station
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "StationInformationSchema", into = "StationInformationSchema")]
+pub struct StationInformation {
+    /// The controlling faction of the station
+    pub station_faction: StationFaction,
+    /// The government of the station
+    pub station_government: LocalisedValue,
+    /// The services offered by the station
+    pub station_services: Vec<StationService>,
+    /// The main economy of the station
+    pub station_economy: LocalisedValue,
+    /// All the economies of the station
+    pub station_economies: Vec<StationEconomy>,
+}
+
+impl From<StationInformationSchema> for StationInformation {
+    fn from(value: StationInformationSchema) -> Self {
+        Self {
+            station_faction: value.station_faction,
+            station_government: LocalisedValue::new(value.station_government, value.station_government_localised),
+            station_services: value.station_services,
+            station_economy: LocalisedValue::new(value.station_economy, value.station_economy_localised),
+            station_economies: value.station_economies,
+        }
+    }
+}
+
+/// Schema for the station information due to the localised values
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct StationInformationSchema {
+    station_faction: StationFaction,
+    station_government: String,
+    #[serde(rename = "StationGovernment_Localised", skip_serializing_if = "Option::is_none")]
+    station_government_localised: Option<String>,
+    station_services: Vec<StationService>,
+    station_economy: String,
+    #[serde(rename = "StationEconomy_Localised", skip_serializing_if = "Option::is_none")]
+    station_economy_localised: Option<String>,
+    station_economies: Vec<StationEconomy>,
+}
+
+impl From<StationInformation> for StationInformationSchema {
+    fn from(value: StationInformation) -> Self {
+        let (station_government, station_government_localised) = deconstruct_localised_value(value.station_government);
+        let (station_economy, station_economy_localised) = deconstruct_localised_value(value.station_economy);
+        Self {
+            station_faction: value.station_faction,
+            station_government,
+            station_government_localised,
+            station_services: value.station_services,
+            station_economy,
+            station_economy_localised,
+            station_economies: value.station_economies,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_serialize_station_information() {
+        let json = r#"{"StationFaction":{"Name":"Elvira Martuuk"},"StationGovernment":"$government_Engineer;","StationGovernment_Localised":"Workshop","StationServices":["dock","autodock","engineer","stationMenu","wibble"],"StationEconomy":"$economy_Colony;","StationEconomy_Localised":"Colony","StationEconomies":[{"Name":"$economy_Colony;","Name_Localised":"Colony","Proportion":1.0}]}"#;
+
+        let station: StationInformation = serde_json::from_str(json).unwrap();
+
+        assert_eq!(station.station_faction, StationFaction { name: "Elvira Martuuk".to_string(), faction_state: None });
+        assert_eq!(station.station_government, LocalisedValue::new("$government_Engineer;".to_string(), Some("Workshop".to_string())));
+        assert_eq!(station.station_services, vec![
+            StationService::Dock,
+            StationService::Autodock,
+            StationService::Engineer,
+            StationService::StationMenu,
+            StationService::Other("wibble".to_string()),
+        ]);
+        assert_eq!(station.station_economies, vec![StationEconomy {
+            name: LocalisedValue::new("$economy_Colony;".to_string(), Some("Colony".to_string())),
+            proportion: 1.0,
+        }]);
+
+        let serialized = serde_json::to_value(&station).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_serialize_station_services() {
+        let json = r#"["dock","autodock","blackmarket","commodities","contacts","exploration","missions","outfitting","crewlounge","rearm","refuel","repair","shipyard","tuning","engineer","missionsgenerated","facilitator","flightcontroller","stationoperations","powerplay","searchrescue","materialtrader","techBroker","stationMenu","shop","livery","socialspace","bartender","vistagenomics","pioneersupplies","apexinterstellar","frontlinesolutions","carriermanagement","carrierfuel","carriervendor","voucherredemption"]"#;
+
+        let services: Vec<StationService> = serde_json::from_str(json).unwrap();
+
+        assert!(services.contains(&StationService::TechBroker));
+        assert!(services.contains(&StationService::StationMenu));
+        assert!(!services.iter().any(|service| matches!(service, StationService::Other(_))));
+
+        let serialized = serde_json::to_value(&services).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::events::common::LocalisedValue;
+use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, LocalisedValue};
+use crate::events::travel::fsd_jump::star_position::StarPosition;
 use serde::{Deserialize, Serialize};
 
 /// What is the allegiance of the system/faction etc
@@ -25,6 +26,7 @@ pub struct FactionState {
     /// The name of the state
     pub state: String,
     /// The trend if any
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub trend: Option<i32>,
 }
 
@@ -67,7 +69,7 @@ pub struct ThargoidWar {
     /// Has success state been reached
     pub success_state_reached: bool,
     /// The war progress
-    pub war_progress: f32,
+    pub war_progress: f64,
     /// How many ports are remaining
     pub remaining_ports: u32,
 }
@@ -83,13 +85,13 @@ pub struct Faction {
     /// The government type
     pub government: Option<String>,
     /// The influence of the faction,
-    pub influence: Option<f32>,
+    pub influence: Option<f64>,
     /// The allegiance of the faction
     pub allegiance: Option<Allegiance>,
     /// The happiness of the faction
     pub happiness: Option<LocalisedValue>,
     /// Pilots reputation with faction
-    pub my_reputation: Option<f32>,
+    pub my_reputation: Option<f64>,
     /// The currently active states
     pub active_states: Option<Vec<FactionState>>,
     /// The pending states if any
@@ -100,18 +102,13 @@ pub struct Faction {
 
 impl From<FactionSchema> for Faction {
     fn from(value: FactionSchema) -> Self {
-        let happiness = Some(LocalisedValue {
-            value: value.happiness.unwrap_or_default(),
-            localised_value: Some(value.happiness_localised.unwrap_or_default()),
-        });
-
         Self {
             name: value.name,
             faction_state: value.faction_state,
             government: value.government,
             influence: value.influence,
             allegiance: value.allegiance,
-            happiness,
+            happiness: LocalisedValue::new_optional(value.happiness, value.happiness_localised),
             my_reputation: value.my_reputation,
             active_states: value.active_states,
             pending_states: value.pending_states,
@@ -127,44 +124,40 @@ pub(crate) struct FactionSchema {
     /// The name of the faction
     pub name: String,
     /// The state of the faction
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub faction_state: Option<String>,
     /// The government type
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub government: Option<String>,
     /// The influence of the faction,
-    pub influence: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub influence: Option<f64>,
     /// The allegiance of the faction
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allegiance: Option<Allegiance>,
+    /// The happiness of the faction
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub happiness: Option<String>,
     /// The localised happiness of the faction
-    #[serde(rename = "Happiness_Localised")]
+    #[serde(rename = "Happiness_Localised", skip_serializing_if = "Option::is_none")]
     pub happiness_localised: Option<String>,
     /// Pilots reputation with faction
-    pub my_reputation: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub my_reputation: Option<f64>,
     /// The currently active states
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub active_states: Option<Vec<FactionState>>,
     /// The pending states if any
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pending_states: Option<Vec<FactionState>>,
     /// The recoving states if any
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub recovering_states: Option<Vec<FactionState>>,
 }
 
 impl From<Faction> for FactionSchema {
     fn from(value: Faction) -> Self {
-        let happiness;
-        let happiness_localised;
-        match value.happiness {
-            None => {
-                happiness = None;
-                happiness_localised = None;
-            }
-            Some(v) => {
-                happiness = Some(v.value);
-                happiness_localised = match v.localised_value {
-                    None => None,
-                    Some(lv) => Some(lv),
-                };
-            }
-        }
+        let (happiness, happiness_localised) = deconstruct_optional_localised_value(value.happiness);
         Self {
             name: value.name,
             faction_state: value.faction_state,
@@ -181,6 +174,117 @@ impl From<Faction> for FactionSchema {
     }
 }
 
+/// The information about the star system the commander is in, given on arriving in or starting the game in a system
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "SystemInformationSchema", into = "SystemInformationSchema")]
+pub struct SystemInformation {
+    /// The name of the star system
+    pub star_system: String,
+    /// The address of the system
+    pub system_address: u64,
+    /// The position of the star
+    pub star_pos: StarPosition,
+    /// The allegiance of the system
+    pub system_allegiance: Allegiance,
+    /// The economy of the system
+    pub system_economy: LocalisedValue,
+    /// The second economy of the system
+    pub system_second_economy: LocalisedValue,
+    /// The government type of the system
+    pub system_government: LocalisedValue,
+    /// The security of the system
+    pub system_security: LocalisedValue,
+    /// The population of the system
+    pub population: u64,
+    /// The factions in the system
+    pub factions: Option<Vec<Faction>>,
+    /// The controlling faction of the system
+    pub system_faction: Option<Faction>,
+    /// Conflicts in the system
+    pub conflicts: Option<Vec<Conflict>>,
+    /// Thargoid war state
+    pub thargoid_war: Option<ThargoidWar>,
+}
+
+impl From<SystemInformationSchema> for SystemInformation {
+    fn from(value: SystemInformationSchema) -> Self {
+        Self {
+            star_system: value.star_system,
+            system_address: value.system_address,
+            star_pos: value.star_pos,
+            system_allegiance: value.system_allegiance,
+            system_economy: LocalisedValue::new(value.system_economy, value.system_economy_localised),
+            system_second_economy: LocalisedValue::new(value.system_second_economy, value.system_second_economy_localised),
+            system_government: LocalisedValue::new(value.system_government, value.system_government_localised),
+            system_security: LocalisedValue::new(value.system_security, value.system_security_localised),
+            population: value.population,
+            factions: value.factions,
+            system_faction: value.system_faction,
+            conflicts: value.conflicts,
+            thargoid_war: value.thargoid_war,
+        }
+    }
+}
+
+/// Schema for the system information due to the localised values
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct SystemInformationSchema {
+    star_system: String,
+    system_address: u64,
+    star_pos: StarPosition,
+    system_allegiance: Allegiance,
+    system_economy: String,
+    #[serde(rename = "SystemEconomy_Localised", skip_serializing_if = "Option::is_none")]
+    system_economy_localised: Option<String>,
+    system_second_economy: String,
+    #[serde(rename = "SystemSecondEconomy_Localised", skip_serializing_if = "Option::is_none")]
+    system_second_economy_localised: Option<String>,
+    system_government: String,
+    #[serde(rename = "SystemGovernment_Localised", skip_serializing_if = "Option::is_none")]
+    system_government_localised: Option<String>,
+    system_security: String,
+    #[serde(rename = "SystemSecurity_Localised", skip_serializing_if = "Option::is_none")]
+    system_security_localised: Option<String>,
+    population: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    factions: Option<Vec<Faction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_faction: Option<Faction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conflicts: Option<Vec<Conflict>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thargoid_war: Option<ThargoidWar>,
+}
+
+impl From<SystemInformation> for SystemInformationSchema {
+    fn from(value: SystemInformation) -> Self {
+        let (system_economy, system_economy_localised) = deconstruct_localised_value(value.system_economy);
+        let (system_second_economy, system_second_economy_localised) = deconstruct_localised_value(value.system_second_economy);
+        let (system_government, system_government_localised) = deconstruct_localised_value(value.system_government);
+        let (system_security, system_security_localised) = deconstruct_localised_value(value.system_security);
+        Self {
+            star_system: value.star_system,
+            system_address: value.system_address,
+            star_pos: value.star_pos,
+            system_allegiance: value.system_allegiance,
+            system_economy,
+            system_economy_localised,
+            system_second_economy,
+            system_second_economy_localised,
+            system_government,
+            system_government_localised,
+            system_security,
+            system_security_localised,
+            population: value.population,
+            factions: value.factions,
+            system_faction: value.system_faction,
+            conflicts: value.conflicts,
+            thargoid_war: value.thargoid_war,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::events::common::system::{Allegiance, Faction, FactionState};
@@ -208,7 +312,7 @@ mod tests {
           "State": "Expansion",
           "Trend": 0
         }}
-      ],
+      ]
     }}"#
         );
 
@@ -227,4 +331,17 @@ mod tests {
         assert_eq!(faction.pending_states, None);
         assert_eq!(faction.recovering_states, Some(vec![FactionState { state: "Expansion".to_string(), trend: Some(0) }]));
     }
+
+    #[test]
+    fn test_deserialize_serialize_faction_without_happiness() {
+        let json = r#"{"Name":"Canonn","FactionState":"Investment"}"#;
+
+        let faction: Faction = serde_json::from_str(json).unwrap();
+
+        assert_eq!(faction.happiness, None);
+        assert_eq!(faction.influence, None);
+
+        let serialized = serde_json::to_value(&faction).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
 }
@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use crate::events::{EliteDangerousEvent, JournalEvent};
+use crate::events::startup::commander::Commander;
+
+/// Where in the journal directory an event was read from
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventSource {
+    /// The path of the file the event was read from
+    pub path: PathBuf,
+    /// The part number of the journal file, None for companion files
+    pub part: Option<u32>,
+    /// The line number (starting at 1) of the event in the journal file, None for companion files
+    pub line_number: Option<u64>,
+    /// The byte offset of the start of the line in the journal file, None for companion files
+    pub byte_offset: Option<u64>,
+}
+
+/// The commander session an event belongs to, this is taken from the preceding ```Commander```/```LoadGame``` event
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommanderSession {
+    /// The commander that is playing
+    pub commander: Commander,
+    /// The timestamp of the event that started the session, this identifies the session for the commander
+    pub started: NaiveDateTime,
+}
+
+/// Wraps every [EliteDangerousEvent](EliteDangerousEvent) that is emitted with its provenance
+///
+/// The sequence number orders the events from an emitter, the journal watcher saves it with the file positions so it
+/// does not start again at 0 after a restart. Events read again after a crash (before the file position was saved)
+/// may be given different sequence numbers, so the [source](EventSource) rather than the sequence identifies a
+/// journal line
+#[derive(Debug)]
+pub struct EventEnvelope {
+    /// The event
+    pub event: EliteDangerousEvent,
+    /// Where the event was read from, None for events that are derived rather than read (e.g. status changes)
+    pub source: Option<EventSource>,
+    /// The wall clock time the event was received
+    pub received: DateTime<Utc>,
+    /// The sequence number of the event
+    pub sequence: u64,
+    /// The commander session the event belongs to, None if no ```Commander```/```LoadGame``` event has been seen yet
+    pub session: Option<CommanderSession>,
+}
+
+impl CommanderSession {
+
+    /// Update the current session from the journal event, a ```Commander``` event starts a new session and a
+    /// ```LoadGame``` event starts one if the commander has changed (or no session has been seen yet)
+    pub fn track(session: &mut Option<CommanderSession>, event: &JournalEvent) {
+        let (commander, started) = match event {
+            JournalEvent::Commander(commander_event) => {
+                (commander_event.commander.clone(), commander_event.event_meta.timestamp)
+            }
+            JournalEvent::LoadGame(load_game) => {
+                if session.as_ref().is_some_and(|s| s.commander.fid == load_game.fid) {
+                    return
+                }
+                (Commander { fid: load_game.fid.clone(), name: load_game.commander.clone() }, load_game.event_meta.timestamp)
+            }
+            _ => return,
+        };
+        *session = Some(CommanderSession { commander, started });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_track_commander_session() {
+        let commander: JournalEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-04T19:27:09Z", "event":"Commander", "FID":"F00000000", "Name":"ANON" }"#).unwrap();
+        let load_game: JournalEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-04T19:27:20Z", "event":"LoadGame", "FID":"F00000000", "Commander":"ANON", "Horizons":true, "Odyssey":true, "Ship":"Anaconda", "ShipID":5, "ShipName":"", "ShipIdent":"", "FuelLevel":32.000000, "FuelCapacity":32.000000, "GameMode":"Solo", "Credits":53038302, "Loan":0, "language":"English/UK", "gameversion":"4.0.0.1904", "build":"r308767/r0 " }"#).unwrap();
+
+        let mut session = None;
+        CommanderSession::track(&mut session, &load_game);
+        assert_eq!(session.as_ref().map(|s| s.started), Some(create_timestamp("2025-01-04T19:27:20Z")));
+
+        CommanderSession::track(&mut session, &commander);
+        let expected = CommanderSession {
+            commander: Commander { fid: "F00000000".to_string(), name: "ANON".to_string() },
+            started: create_timestamp("2025-01-04T19:27:09Z"),
+        };
+        assert_eq!(session, Some(expected.clone()));
+
+        // A load game for the same commander continues the session
+        CommanderSession::track(&mut session, &load_game);
+        assert_eq!(session, Some(expected));
+    }
+}
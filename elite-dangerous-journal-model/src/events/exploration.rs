@@ -2,5 +2,23 @@
 pub mod fss_signal_discovered;
 /// For scan events
 pub mod scan;
-/// Material Collected events
-pub mod material_collected;
\ No newline at end of file
+/// Material Collected and Material Discarded events
+pub mod material_collected;
+/// FSS discovery scan (honk) events
+pub mod fss_discovery_scan;
+/// FSS all bodies found events
+pub mod fss_all_bodies_found;
+/// FSSBodySignals and SAASignalsFound events
+pub mod body_signals;
+/// SAA scan complete events
+pub mod saa_scan_complete;
+/// Scan bary centre events
+pub mod scan_bary_centre;
+/// Codex entry events
+pub mod codex_entry;
+/// SellExplorationData and MultiSellExplorationData events
+pub mod sell_exploration_data;
+/// Nav beacon scan events
+pub mod nav_beacon_scan;
+/// Discovery scan events
+pub mod discovery_scan;
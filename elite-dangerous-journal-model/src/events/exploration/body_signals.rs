@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// The number of signals of a type found on a body
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "SignalSchema", into = "SignalSchema")]
+pub struct Signal {
+    /// The type of the signal e.g. Biological, Geological
+    pub signal_type: LocalisedValue,
+    /// The number of signals
+    pub count: u32,
+}
+
+impl From<SignalSchema> for Signal {
+    fn from(value: SignalSchema) -> Self {
+        Self {
+            signal_type: LocalisedValue::new(value.signal_type, value.signal_type_localised),
+            count: value.count,
+        }
+    }
+}
+
+/// Schema for the signal due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct SignalSchema {
+    #[serde(rename = "Type")]
+    signal_type: String,
+    #[serde(rename = "Type_Localised", skip_serializing_if = "Option::is_none")]
+    signal_type_localised: Option<String>,
+    count: u32,
+}
+
+impl From<Signal> for SignalSchema {
+    fn from(value: Signal) -> Self {
+        let (signal_type, signal_type_localised) = deconstruct_localised_value(value.signal_type);
+        Self {
+            signal_type,
+            signal_type_localised,
+            count: value.count,
+        }
+    }
+}
+
+/// A genus of the biological signals found on a body
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "GenusSchema", into = "GenusSchema")]
+pub struct Genus {
+    /// The genus e.g. Bacterium
+    pub genus: LocalisedValue,
+}
+
+impl From<GenusSchema> for Genus {
+    fn from(value: GenusSchema) -> Self {
+        Self {
+            genus: LocalisedValue::new(value.genus, value.genus_localised),
+        }
+    }
+}
+
+/// Schema for the genus due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct GenusSchema {
+    genus: String,
+    #[serde(rename = "Genus_Localised", skip_serializing_if = "Option::is_none")]
+    genus_localised: Option<String>,
+}
+
+impl From<Genus> for GenusSchema {
+    fn from(value: Genus) -> Self {
+        let (genus, genus_localised) = deconstruct_localised_value(value.genus);
+        Self {
+            genus,
+            genus_localised,
+        }
+    }
+}
+
+/// Signals have been found on a body with the full spectrum scanner
+///
+/// ```json
+/// { "timestamp":"2025-01-05T21:12:33Z", "event":"FSSBodySignals", "BodyName":"Synuefe EN-H d11-96 3", "BodyID":8, "SystemAddress":5031654888146, "Signals":[ { "Type":"$SAA_SignalType_Biological;", "Type_Localised":"Biological", "Count":2 }, { "Type":"$SAA_SignalType_Geological;", "Type_Localised":"Geological", "Count":4 } ] }
+/// ```
+///
+/// ReadTheDocs: [FSSBodySignals](https://elite-journal.readthedocs.io/en/latest/Exploration/#fssbodysignals)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct FSSBodySignalsEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the body
+    pub body_name: String,
+
+    /// The id of the body
+    #[serde(rename = "BodyID")]
+    pub body_id: u64,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The signals found on the body
+    pub signals: Vec<Signal>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Signals have been found on a body with the detailed surface scanner, this includes the genuses of any biological
+/// signals
+///
+/// ```json
+/// { "timestamp":"2025-01-04T20:49:26Z", "event":"SAASignalsFound", "BodyName":"Praea Euq DC-D d12-79 1", "SystemAddress":2724947036651, "BodyID":14, "Signals":[ { "Type":"$SAA_SignalType_Geological;", "Type_Localised":"Geological", "Count":3 } ], "Genuses":[  ] }
+/// ```
+///
+/// ReadTheDocs: [SAASignalsFound](https://elite-journal.readthedocs.io/en/latest/Exploration/#saasignalsfound)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SAASignalsFoundEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the body
+    pub body_name: String,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The id of the body
+    #[serde(rename = "BodyID")]
+    pub body_id: u64,
+
+    /// The signals found on the body
+    pub signals: Vec<Signal>,
+
+    /// The genuses of the biological signals found on the body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genuses: Option<Vec<Genus>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_fss_body_signals_event() {
+        let json = r#"{ "timestamp":"2025-01-05T21:12:33Z", "event":"FSSBodySignals", "BodyName":"Synuefe EN-H d11-96 3", "BodyID":8, "SystemAddress":5031654888146, "Signals":[ { "Type":"$SAA_SignalType_Biological;", "Type_Localised":"Biological", "Count":2 }, { "Type":"$SAA_SignalType_Geological;", "Type_Localised":"Geological", "Count":4 } ] }"#;
+
+        let event: FSSBodySignalsEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-05T21:12:33Z"));
+        assert_eq!(event.body_name, "Synuefe EN-H d11-96 3");
+        assert_eq!(event.body_id, 8);
+        assert_eq!(event.signals, vec![
+            Signal { signal_type: LocalisedValue::new("$SAA_SignalType_Biological;".to_string(), Some("Biological".to_string())), count: 2 },
+            Signal { signal_type: LocalisedValue::new("$SAA_SignalType_Geological;".to_string(), Some("Geological".to_string())), count: 4 },
+        ]);
+    }
+
+    #[test]
+    fn test_deserialize_serialize_saa_signals_found_event() {
+        let json = r#"{"timestamp":"2025-01-05T21:20:10Z","BodyName":"Synuefe EN-H d11-96 3","SystemAddress":5031654888146,"BodyID":8,"Signals":[{"Type":"$SAA_SignalType_Biological;","Type_Localised":"Biological","Count":2}],"Genuses":[{"Genus":"$Codex_Ent_Bacterial_Genus_Name;","Genus_Localised":"Bacterium"},{"Genus":"$Codex_Ent_Fungoids_Genus_Name;","Genus_Localised":"Fungoida"}]}"#;
+
+        let event: SAASignalsFoundEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.signals.len(), 1);
+        assert_eq!(event.genuses, Some(vec![
+            Genus { genus: LocalisedValue::new("$Codex_Ent_Bacterial_Genus_Name;".to_string(), Some("Bacterium".to_string())) },
+            Genus { genus: LocalisedValue::new("$Codex_Ent_Fungoids_Genus_Name;".to_string(), Some("Fungoida".to_string())) },
+        ]));
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Something has been logged in the codex, e.g. a type of body, a star or a biological species
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:58:27Z", "event":"CodexEntry", "EntryID":1301701, "Name":"$Codex_Ent_Standard_Ter_Rocky_Ice_Name;", "Name_Localised":"Non Terraformable", "SubCategory":"$Codex_SubCategory_Terrestrials;", "SubCategory_Localised":"Terrestrial planets", "Category":"$Codex_Category_StellarBodies;", "Category_Localised":"Astronomical Bodies", "Region":"$Codex_RegionName_18;", "Region_Localised":"Inner Orion Spur", "System":"Col 285 Sector DT-D b27-6", "SystemAddress":13865630967297, "BodyID":0, "IsNewEntry":true }
+/// ```
+///
+/// ReadTheDocs: [CodexEntry](https://elite-journal.readthedocs.io/en/latest/Exploration/#codexentry)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "CodexEntryEventSchema", into = "CodexEntryEventSchema")]
+pub struct CodexEntryEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the codex entry
+    pub entry_id: u64,
+
+    /// The name of the entry
+    pub name: LocalisedValue,
+
+    /// The sub category of the entry
+    pub sub_category: LocalisedValue,
+
+    /// The category of the entry
+    pub category: LocalisedValue,
+
+    /// The galactic region the entry was logged in
+    pub region: LocalisedValue,
+
+    /// The name of the system
+    pub system: String,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The id of the body
+    pub body_id: Option<u64>,
+
+    /// The nearest point of interest
+    pub nearest_destination: Option<LocalisedValue>,
+
+    /// The latitude if on a planet
+    pub latitude: Option<f64>,
+
+    /// The longitude if on a planet
+    pub longitude: Option<f64>,
+
+    /// Is this the first time the commander has logged the entry
+    pub is_new_entry: Option<bool>,
+
+    /// Have new traits been discovered
+    pub new_traits_discovered: Option<bool>,
+
+    /// The traits that have been discovered
+    pub traits: Option<Vec<String>>,
+
+    /// The value of the voucher awarded for the entry
+    pub voucher_amount: Option<u64>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<CodexEntryEventSchema> for CodexEntryEvent {
+    fn from(value: CodexEntryEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            entry_id: value.entry_id,
+            name: LocalisedValue::new(value.name, value.name_localised),
+            sub_category: LocalisedValue::new(value.sub_category, value.sub_category_localised),
+            category: LocalisedValue::new(value.category, value.category_localised),
+            region: LocalisedValue::new(value.region, value.region_localised),
+            system: value.system,
+            system_address: value.system_address,
+            body_id: value.body_id,
+            nearest_destination: LocalisedValue::new_optional(value.nearest_destination, value.nearest_destination_localised),
+            latitude: value.latitude,
+            longitude: value.longitude,
+            is_new_entry: value.is_new_entry,
+            new_traits_discovered: value.new_traits_discovered,
+            traits: value.traits,
+            voucher_amount: value.voucher_amount,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CodexEntryEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the codex entry
+    #[serde(rename = "EntryID")]
+    pub entry_id: u64,
+
+    /// The name of the entry
+    pub name: String,
+
+    /// The localised name of the entry
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+
+    /// The sub category of the entry
+    pub sub_category: String,
+
+    /// The localised sub category of the entry
+    #[serde(rename = "SubCategory_Localised", skip_serializing_if = "Option::is_none")]
+    pub sub_category_localised: Option<String>,
+
+    /// The category of the entry
+    pub category: String,
+
+    /// The localised category of the entry
+    #[serde(rename = "Category_Localised", skip_serializing_if = "Option::is_none")]
+    pub category_localised: Option<String>,
+
+    /// The galactic region the entry was logged in
+    pub region: String,
+
+    /// The localised galactic region
+    #[serde(rename = "Region_Localised", skip_serializing_if = "Option::is_none")]
+    pub region_localised: Option<String>,
+
+    /// The name of the system
+    pub system: String,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The id of the body
+    #[serde(rename = "BodyID", skip_serializing_if = "Option::is_none")]
+    pub body_id: Option<u64>,
+
+    /// The nearest point of interest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nearest_destination: Option<String>,
+
+    /// The localised nearest point of interest
+    #[serde(rename = "NearestDestination_Localised", skip_serializing_if = "Option::is_none")]
+    pub nearest_destination_localised: Option<String>,
+
+    /// The latitude if on a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+
+    /// The longitude if on a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+
+    /// Is this the first time the commander has logged the entry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_new_entry: Option<bool>,
+
+    /// Have new traits been discovered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_traits_discovered: Option<bool>,
+
+    /// The traits that have been discovered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traits: Option<Vec<String>>,
+
+    /// The value of the voucher awarded for the entry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voucher_amount: Option<u64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<CodexEntryEvent> for CodexEntryEventSchema {
+    fn from(value: CodexEntryEvent) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        let (sub_category, sub_category_localised) = deconstruct_localised_value(value.sub_category);
+        let (category, category_localised) = deconstruct_localised_value(value.category);
+        let (region, region_localised) = deconstruct_localised_value(value.region);
+        let (nearest_destination, nearest_destination_localised) = deconstruct_optional_localised_value(value.nearest_destination);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            entry_id: value.entry_id,
+            name,
+            name_localised,
+            sub_category,
+            sub_category_localised,
+            category,
+            category_localised,
+            region,
+            region_localised,
+            system: value.system,
+            system_address: value.system_address,
+            body_id: value.body_id,
+            nearest_destination,
+            nearest_destination_localised,
+            latitude: value.latitude,
+            longitude: value.longitude,
+            is_new_entry: value.is_new_entry,
+            new_traits_discovered: value.new_traits_discovered,
+            traits: value.traits,
+            voucher_amount: value.voucher_amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_codex_entry_event() {
+        let json = r#"{"timestamp":"2025-01-04T19:58:27Z","EntryID":1301701,"Name":"$Codex_Ent_Standard_Ter_Rocky_Ice_Name;","Name_Localised":"Non Terraformable","SubCategory":"$Codex_SubCategory_Terrestrials;","SubCategory_Localised":"Terrestrial planets","Category":"$Codex_Category_StellarBodies;","Category_Localised":"Astronomical Bodies","Region":"$Codex_RegionName_18;","Region_Localised":"Inner Orion Spur","System":"Col 285 Sector DT-D b27-6","SystemAddress":13865630967297,"BodyID":0,"IsNewEntry":true}"#;
+
+        let event: CodexEntryEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:58:27Z"));
+        assert_eq!(event.entry_id, 1301701);
+        assert_eq!(event.name, LocalisedValue::new("$Codex_Ent_Standard_Ter_Rocky_Ice_Name;".to_string(), Some("Non Terraformable".to_string())));
+        assert_eq!(event.region, LocalisedValue::new("$Codex_RegionName_18;".to_string(), Some("Inner Orion Spur".to_string())));
+        assert_eq!(event.system, "Col 285 Sector DT-D b27-6");
+        assert_eq!(event.body_id, Some(0));
+        assert_eq!(event.is_new_entry, Some(true));
+        assert_eq!(event.voucher_amount, None);
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
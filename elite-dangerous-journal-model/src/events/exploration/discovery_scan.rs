@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// The discovery scanner has been used (the pre full spectrum scanner version of FSSDiscoveryScan)
+///
+/// ```json
+/// { "timestamp":"2025-01-07T18:41:02Z", "event":"DiscoveryScan", "SystemAddress":3107241104074, "Bodies":6 }
+/// ```
+///
+/// ReadTheDocs: [DiscoveryScan](https://elite-journal.readthedocs.io/en/latest/Exploration/#discoveryscan)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DiscoveryScanEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The number of new bodies discovered
+    pub bodies: u32,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_discovery_scan_event() {
+        let json = r#"{ "timestamp":"2025-01-07T18:41:02Z", "event":"DiscoveryScan", "SystemAddress":3107241104074, "Bodies":6 }"#;
+
+        let event: DiscoveryScanEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-07T18:41:02Z"));
+        assert_eq!(event.system_address, 3107241104074);
+        assert_eq!(event.bodies, 6);
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// All the bodies in the system have been found with the full spectrum scanner
+///
+/// ```json
+/// { "timestamp":"2025-01-04T20:14:49Z", "event":"FSSAllBodiesFound", "SystemName":"Scorpius Dark Region LN-S b4-6", "SystemAddress":13866436339305, "Count":1 }
+/// ```
+///
+/// ReadTheDocs: [FSSAllBodiesFound](https://elite-journal.readthedocs.io/en/latest/Exploration/#fssallbodiesfound)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct FSSAllBodiesFoundEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the system
+    pub system_name: String,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The number of bodies in the system
+    pub count: u32,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_fss_all_bodies_found_event() {
+        let json = r#"{ "timestamp":"2025-01-04T20:14:49Z", "event":"FSSAllBodiesFound", "SystemName":"Scorpius Dark Region LN-S b4-6", "SystemAddress":13866436339305, "Count":1 }"#;
+
+        let event: FSSAllBodiesFoundEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T20:14:49Z"));
+        assert_eq!(event.system_name, "Scorpius Dark Region LN-S b4-6");
+        assert_eq!(event.system_address, 13866436339305);
+        assert_eq!(event.count, 1);
+    }
+}
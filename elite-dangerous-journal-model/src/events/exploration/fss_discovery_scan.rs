@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// The honk, the discovery scanner has been used on entering a system
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:46:05Z", "event":"FSSDiscoveryScan", "Progress":1.000000, "BodyCount":18, "NonBodyCount":11, "SystemName":"Marojini", "SystemAddress":5581276484306 }
+/// ```
+///
+/// ReadTheDocs: [FSSDiscoveryScan](https://elite-journal.readthedocs.io/en/latest/Exploration/#fssdiscoveryscan)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct FSSDiscoveryScanEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The proportion of the system that has been scanned, between 0 and 1
+    pub progress: f64,
+
+    /// The number of stellar bodies in the system
+    pub body_count: u32,
+
+    /// The number of non bodies (signals) in the system
+    pub non_body_count: u32,
+
+    /// The name of the system
+    pub system_name: String,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_fss_discovery_scan_event() {
+        let json = r#"{ "timestamp":"2025-01-04T19:46:05Z", "event":"FSSDiscoveryScan", "Progress":1.000000, "BodyCount":18, "NonBodyCount":11, "SystemName":"Marojini", "SystemAddress":5581276484306 }"#;
+
+        let event: FSSDiscoveryScanEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:46:05Z"));
+        assert_eq!(event.progress, 1.0);
+        assert_eq!(event.body_count, 18);
+        assert_eq!(event.non_body_count, 11);
+        assert_eq!(event.system_name, "Marojini");
+        assert_eq!(event.system_address, 5581276484306);
+    }
+}
@@ -1,6 +1,6 @@
 
 use serde::{Deserialize, Serialize};
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{EventMeta, ExtraFields, LocalisedValue};
 
 /// Enum to represent the signal type
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -57,6 +57,9 @@ pub struct FSSSignalDiscoveredEvent {
     pub signal_type: FssSignalType,
     /// Is the signal a station
     pub is_station: Option<bool>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<FSSSignalDiscoveredEventSchema> for FSSSignalDiscoveredEvent {
@@ -68,6 +71,7 @@ impl From<FSSSignalDiscoveredEventSchema> for FSSSignalDiscoveredEvent {
 
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             system_address: value.system_address,
             signal_name,
             signal_type: value.signal_type,
@@ -104,18 +108,24 @@ pub struct FSSSignalDiscoveredEventSchema {
     /// The Name of the signal
     pub signal_name: String,
     /// Localised version of signal name
-    #[serde(rename = "SignalName_Localised")]
+    #[serde(rename = "SignalName_Localised", skip_serializing_if = "Option::is_none")]
     pub localised_signal_name: Option<String>,
     /// The type of the signal
     pub signal_type: FssSignalType,
     /// Is the signal a station
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_station: Option<bool>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl From<FSSSignalDiscoveredEvent> for FSSSignalDiscoveredEventSchema {
     fn from(value: FSSSignalDiscoveredEvent) -> Self {
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             system_address: value.system_address,
             signal_name: value.signal_name.value,
             localised_signal_name: value.signal_name.localised_value,
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
 
 /// The category of material collected
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -16,7 +16,7 @@ pub enum MaterialCategory {
 #[derive(Serialize, Deserialize,Debug, PartialEq, Clone)]
 #[serde(from = "MaterialCollectedEventSchema", into = "MaterialCollectedEventSchema")]
 pub struct MaterialCollectedEvent {
-    /// The event meta data
+    /// The event metadata
     pub event_meta: EventMeta,
 
     /// The category of material collected
@@ -28,12 +28,15 @@ pub struct MaterialCollectedEvent {
     /// The number collected
     pub count: u64,
 
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<MaterialCollectedEventSchema> for MaterialCollectedEvent {
     fn from(value: MaterialCollectedEventSchema) -> Self {
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             category: value.category,
             name: LocalisedValue {
                 value: value.name,
@@ -61,7 +64,7 @@ impl From<MaterialCollectedEventSchema> for MaterialCollectedEvent {
 #[serde(rename_all = "PascalCase")]
 pub(crate) struct MaterialCollectedEventSchema {
 
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
 
@@ -72,11 +75,15 @@ pub(crate) struct MaterialCollectedEventSchema {
     pub name: String,
 
     /// The localised name of the material
-    #[serde(rename = "Name_Localised")]
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
     pub name_localised: Option<String>,
 
     /// The number collected
     pub count: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub(crate) extra: ExtraFields,
 }
 
 impl From<MaterialCollectedEvent> for MaterialCollectedEventSchema {
@@ -84,6 +91,7 @@ impl From<MaterialCollectedEvent> for MaterialCollectedEventSchema {
 
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             category: value.category,
             name: value.name.value,
             name_localised: value.name.localised_value,
@@ -92,11 +100,76 @@ impl From<MaterialCollectedEvent> for MaterialCollectedEventSchema {
     }
 }
 
+/// Materials have been discarded from the commander's inventory
+///
+/// ```json
+/// { "timestamp":"2025-01-11T20:05:44Z", "event":"MaterialDiscarded", "Category":"Raw", "Name":"iron", "Count":5 }
+/// ```
+///
+/// ReadTheDocs: [MaterialDiscarded](https://elite-journal.readthedocs.io/en/latest/Exploration/#materialdiscarded)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "MaterialDiscardedEventSchema", into = "MaterialDiscardedEventSchema")]
+pub struct MaterialDiscardedEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The category of material discarded
+    pub category: MaterialCategory,
 
+    /// The name of the material
+    pub name: LocalisedValue,
+
+    /// The number discarded
+    pub count: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<MaterialDiscardedEventSchema> for MaterialDiscardedEvent {
+    fn from(value: MaterialDiscardedEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            category: value.category,
+            name: LocalisedValue::new(value.name, value.name_localised),
+            count: value.count,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct MaterialDiscardedEventSchema {
+    #[serde(flatten)]
+    event_meta: EventMeta,
+    category: MaterialCategory,
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+    count: u64,
+    #[serde(flatten)]
+    extra: ExtraFields,
+}
+
+impl From<MaterialDiscardedEvent> for MaterialDiscardedEventSchema {
+    fn from(value: MaterialDiscardedEvent) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            category: value.category,
+            name,
+            name_localised,
+            count: value.count,
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use crate::events::exploration::material_collected::{MaterialCategory, MaterialCollectedEvent};
+    use crate::events::exploration::material_collected::{MaterialCategory, MaterialCollectedEvent, MaterialDiscardedEvent};
     use crate::test_helper::serde_helpers::create_timestamp;
 
     #[test]
@@ -114,4 +187,19 @@ mod tests {
         assert_eq!(event.name.localised_value.unwrap(), "Imperial Shielding");
         assert_eq!(event.count, 3);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_deserialize_serialize_material_discarded() {
+        let json = r#"{"timestamp":"2025-01-11T20:05:44Z","Category":"Raw","Name":"iron","Count":5}"#;
+
+        let event: MaterialDiscardedEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T20:05:44Z"));
+        assert_eq!(event.category, MaterialCategory::Raw);
+        assert_eq!(event.name.value, "iron");
+        assert_eq!(event.count, 5);
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
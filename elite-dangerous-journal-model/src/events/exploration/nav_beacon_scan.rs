@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// A nav beacon has been scanned, revealing the bodies in the system
+///
+/// ```json
+/// { "timestamp":"2025-01-07T18:40:12Z", "event":"NavBeaconScan", "SystemAddress":3107241104074, "NumBodies":23 }
+/// ```
+///
+/// ReadTheDocs: [NavBeaconScan](https://elite-journal.readthedocs.io/en/latest/Exploration/#navbeaconscan)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct NavBeaconScanEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The number of bodies in the system
+    pub num_bodies: u32,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_nav_beacon_scan_event() {
+        let json = r#"{ "timestamp":"2025-01-07T18:40:12Z", "event":"NavBeaconScan", "SystemAddress":3107241104074, "NumBodies":23 }"#;
+
+        let event: NavBeaconScanEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-07T18:40:12Z"));
+        assert_eq!(event.system_address, 3107241104074);
+        assert_eq!(event.num_bodies, 23);
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// A body has been fully mapped with the detailed surface scanner
+///
+/// ```json
+/// { "timestamp":"2025-01-04T20:49:26Z", "event":"SAAScanComplete", "BodyName":"Praea Euq DC-D d12-79 1", "SystemAddress":2724947036651, "BodyID":14, "ProbesUsed":19, "EfficiencyTarget":6 }
+/// ```
+///
+/// ReadTheDocs: [SAAScanComplete](https://elite-journal.readthedocs.io/en/latest/Exploration/#saascancomplete)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SAAScanCompleteEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the body
+    pub body_name: String,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The id of the body
+    #[serde(rename = "BodyID")]
+    pub body_id: u64,
+
+    /// The number of probes used to map the body
+    pub probes_used: u32,
+
+    /// The number of probes needed to get the efficiency bonus
+    pub efficiency_target: u32,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl SAAScanCompleteEvent {
+
+    /// Was the body mapped within the efficiency target (so the efficiency bonus was awarded)
+    pub fn efficiency_target_met(&self) -> bool {
+        self.probes_used <= self.efficiency_target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_saa_scan_complete_event() {
+        let json = r#"{ "timestamp":"2025-01-04T20:49:26Z", "event":"SAAScanComplete", "BodyName":"Praea Euq DC-D d12-79 1", "SystemAddress":2724947036651, "BodyID":14, "ProbesUsed":19, "EfficiencyTarget":6 }"#;
+
+        let event: SAAScanCompleteEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T20:49:26Z"));
+        assert_eq!(event.body_name, "Praea Euq DC-D d12-79 1");
+        assert_eq!(event.body_id, 14);
+        assert_eq!(event.probes_used, 19);
+        assert_eq!(event.efficiency_target, 6);
+        assert!(!event.efficiency_target_met());
+    }
+}
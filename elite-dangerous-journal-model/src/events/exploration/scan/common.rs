@@ -56,29 +56,29 @@ pub enum AtmosphereType {
 
 /// A struct to represent a composition value
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-#[serde(rename_all="PascalCase", tag="Name")]
+#[serde(rename_all="PascalCase")]
 pub struct CompositionPercentage {
     /// The name of the composition element
     pub name: String,
     /// The percentage of this composition element
-    pub percent: f32,
+    pub percent: f64,
 }
 
 /// A composition of ice, metal, rock
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-#[serde(rename_all="PascalCase", tag="Name")]
+#[serde(rename_all="PascalCase")]
 pub struct Composition {
     /// The ice composition
-    pub ice: f32,
+    pub ice: f64,
     /// The metal composition
-    pub metal: f32,
+    pub metal: f64,
     /// The rock composition
-    pub rock: f32,
+    pub rock: f64,
 }
 
 /// Struct to represent a ring in around a body
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-#[serde(rename_all="PascalCase", tag="Name")]
+#[serde(rename_all="PascalCase")]
 pub struct Ring {
     /// Name of the Ring
     pub name: String,
@@ -86,9 +86,9 @@ pub struct Ring {
     pub ring_class: String,
     /// The mass of the ring
     #[serde(rename = "MassMT")]
-    pub mass_mt: f32,
+    pub mass_mt: f64,
     /// The radius of the inner part of the ring
-    pub inner_rad: f32,
+    pub inner_rad: f64,
     /// The radius of the outer part of the ring
-    pub outer_rad: f32,
+    pub outer_rad: f64,
 }
@@ -2,7 +2,7 @@ use crate::events::exploration::scan::common::{
     AtmosphereType, Composition, CompositionPercentage, ParentType, Ring, ScanType,
 };
 use crate::events::exploration::scan::helper::ScanEventHelper;
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 use serde::{Deserialize, Deserializer, Serialize};
 use tracing::{debug, trace};
 
@@ -20,6 +20,10 @@ pub struct ScanEvent {
     /// Enum to hold the variant of this type of event
     #[serde(flatten)]
     pub scan_data: ScanData,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl<'de> Deserialize<'de> for ScanEvent {
@@ -55,11 +59,13 @@ impl<'de> Deserialize<'de> for ScanEvent {
 
         let event_meta = helper.event_meta;
         let scan_type = helper.scan_type;
+        let extra = helper.extra;
 
         Ok(Self {
             event_meta,
             scan_type,
             scan_data,
+            extra,
         })
     }
 }
@@ -69,8 +75,10 @@ impl ScanEvent {
 }
 
 /// Enum to represent the different types of scan data
+///
+/// This is untagged so that it serializes back to the fields of the event when flattened
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-#[serde(rename_all = "PascalCase")]
+#[serde(untagged)]
 pub enum ScanData {
     /// Scan data represents a star
     Star(Star),
@@ -81,7 +89,6 @@ pub enum ScanData {
     /// Scan data is belt cluster
     BeltCluster(BeltCluster),
     /// Catch all for unknown
-    #[serde(untagged)]
     Unknown(String),
 }
 
@@ -96,18 +103,19 @@ pub struct Star {
     /// The star type
     pub star_type: String,
     /// The subclass of the star
+    #[serde(rename = "Subclass")]
     pub sub_class: u32,
     /// The stellar mass of the star
-    pub stellar_mass: f32,
+    pub stellar_mass: f64,
 
     /// The radius of the star
-    pub radius: f32,
+    pub radius: f64,
 
     /// The surface temperature of the star
-    pub surface_temperature: f32,
+    pub surface_temperature: f64,
 
     /// The absolute magnitude of the star
-    pub absolute_magnitude: f32,
+    pub absolute_magnitude: f64,
 
     /// The age of the star in millions of years
     #[serde(rename = "Age_MY")]
@@ -117,20 +125,23 @@ pub struct Star {
     pub luminosity: String,
 
     /// It's rotational period
-    pub rotation_period: f32,
+    pub rotation_period: f64,
 
     /// The axial tilt of the star
-    pub axial_tilt: f32,
+    pub axial_tilt: f64,
 
     // Optional fields
     /// References to parents (if any)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parents: Option<Vec<ParentType>>,
 
     /// The orbital mechanics data of the star (if None, it does not orbit)
     #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub orbital_mechanics_data: Option<OrbitalMechanicsData>,
 
     /// Any rings for the star
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rings: Option<Vec<Ring>>,
 }
 
@@ -177,6 +188,9 @@ pub struct PlanetMoon {
     /// Is terraform state (all examples have been empty string so far)
     pub terraform_state: String,
 
+    /// The class of the planet, e.g. ```Metal rich body```
+    pub planet_class: String,
+
     /// Atmosphere of the planet
     pub atmosphere: String,
 
@@ -185,25 +199,28 @@ pub struct PlanetMoon {
     #[serde(rename = "MassEM")]
 
     /// Mass of the planet
-    pub mass_em: f32,
+    pub mass_em: f64,
 
     /// Gravity at the surface
-    pub surface_gravity: f32,
+    pub surface_gravity: f64,
 
     /// Pressure at the surface
-    pub surface_pressure: f32,
+    pub surface_pressure: f64,
 
     /// Temperature at the surface
-    pub surface_temperature: f32,
+    pub surface_temperature: f64,
 
     /// Radius of the planet
-    pub radius: f32,
+    pub radius: f64,
 
     /// Can the planet be landed on
     pub landable: bool,
 
     /// The axial tilt of the planet
-    pub axial_tilt: f32,
+    pub axial_tilt: f64,
+
+    /// The rotational period of the planet
+    pub rotation_period: f64,
 
     /// Orbital mechanic data (as a planet it's not optional)
     #[serde(flatten)]
@@ -211,20 +228,26 @@ pub struct PlanetMoon {
 
     // Optional fields
     /// The atmosphere type of the planet
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub atmosphere_type: Option<AtmosphereType>,
     /// Composition of the planet
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub composition: Option<Composition>,
 
     /// Materials of the planet
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub materials: Option<Vec<CompositionPercentage>>,
 
     /// Composition of the atmosphere
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub atmosphere_composition: Option<Vec<CompositionPercentage>>,
 
     /// Details of any rings that the planet has
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rings: Option<Vec<Ring>>,
 
     /// How pristine the resources are
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reserve_level: Option<String>,
 }
 
@@ -237,6 +260,7 @@ impl PlanetMoon {
             parents: helper.parents.clone().unwrap(),
             tidal_lock: helper.tidal_lock.unwrap(),
             terraform_state: helper.terraform_state.clone().unwrap(),
+            planet_class: helper.planet_class.clone().unwrap(),
             atmosphere: helper.atmosphere.clone().unwrap(),
             volcanism: helper.volcanism.clone().unwrap(),
             mass_em: helper.mass_em.unwrap(),
@@ -246,6 +270,7 @@ impl PlanetMoon {
             radius: helper.radius.unwrap(),
             landable: helper.landable.unwrap(),
             axial_tilt: helper.axial_tilt.unwrap(),
+            rotation_period: helper.rotation_period.unwrap(),
             // We know for a planet the orbital data must be present
             orbital_mechanics_data: OrbitalMechanicsData::from_helper(helper).unwrap(),
             atmosphere_type: helper.atmosphere_type.clone(),
@@ -300,7 +325,7 @@ pub struct Body {
 
     /// Distance from the arrival point
     #[serde(rename = "DistanceFromArrivalLS")]
-    pub distance_from_arrival_ls: f32,
+    pub distance_from_arrival_ls: f64,
 
     /// Has the body already been discovered
     #[serde(rename = "WasDiscovered")]
@@ -334,17 +359,17 @@ pub struct OrbitalMechanicsData {
     /// Semi major axis
     pub semi_major_axis: f64,
     /// The eccentricity of the orbit
-    pub eccentricity: f32,
+    pub eccentricity: f64,
     /// The orbital inclination
-    pub orbital_inclination: f32,
+    pub orbital_inclination: f64,
     /// The periapsis of the orbit
-    pub periapsis: f32,
+    pub periapsis: f64,
     /// The period of the orbit
-    pub orbital_period: f32,
+    pub orbital_period: f64,
     /// The ascending node
-    pub ascending_node: f32,
+    pub ascending_node: f64,
     /// The mean anomaly of the orbit
-    pub mean_anomaly: f32,
+    pub mean_anomaly: f64,
 }
 
 impl OrbitalMechanicsData {
@@ -410,17 +435,17 @@ mod tests {
                 let ring1 = Ring {
                     name: "LHS 2522 3 A Ring".to_string(),
                     ring_class: "eRingClass_Rocky".to_string(),
-                    mass_mt: 341280000000.0_f32,
-                    inner_rad: 90118000.0_f32,
-                    outer_rad: 137950000.0_f32,
+                    mass_mt: 341280000000.0_f64,
+                    inner_rad: 90118000.0_f64,
+                    outer_rad: 137950000.0_f64,
                 };
 
                 let ring2 = Ring {
                     name: "LHS 2522 3 B Ring".to_string(),
                     ring_class: "eRingClass_Rocky".to_string(),
-                    mass_mt: 9194400000000.0_f32,
-                    inner_rad: 138050000.0_f32,
-                    outer_rad: 559410000.0_f32,
+                    mass_mt: 9194400000000.0_f64,
+                    inner_rad: 138050000.0_f64,
+                    outer_rad: 559410000.0_f64,
                 };
 
                 let rings = star.rings.unwrap();
@@ -530,17 +555,17 @@ mod tests {
                 let ring1 = Ring {
                     name: "LHS 2522 3 A Ring".to_string(),
                     ring_class: "eRingClass_Rocky".to_string(),
-                    mass_mt: 341280000000.0_f32,
-                    inner_rad: 90118000.0_f32,
-                    outer_rad: 137950000.0_f32,
+                    mass_mt: 341280000000.0_f64,
+                    inner_rad: 90118000.0_f64,
+                    outer_rad: 137950000.0_f64,
                 };
 
                 let ring2 = Ring {
                     name: "LHS 2522 3 B Ring".to_string(),
                     ring_class: "eRingClass_Rocky".to_string(),
-                    mass_mt: 9194400000000.0_f32,
-                    inner_rad: 138050000.0_f32,
-                    outer_rad: 559410000.0_f32,
+                    mass_mt: 9194400000000.0_f64,
+                    inner_rad: 138050000.0_f64,
+                    outer_rad: 559410000.0_f64,
                 };
 
                 let rings = star.rings.unwrap();
@@ -592,17 +617,17 @@ mod tests {
                 let ring1 = Ring {
                     name: "LHS 2522 3 A Ring".to_string(),
                     ring_class: "eRingClass_Rocky".to_string(),
-                    mass_mt: 341280000000.0_f32,
-                    inner_rad: 90118000.0_f32,
-                    outer_rad: 137950000.0_f32,
+                    mass_mt: 341280000000.0_f64,
+                    inner_rad: 90118000.0_f64,
+                    outer_rad: 137950000.0_f64,
                 };
 
                 let ring2 = Ring {
                     name: "LHS 2522 3 B Ring".to_string(),
                     ring_class: "eRingClass_Rocky".to_string(),
-                    mass_mt: 9194400000000.0_f32,
-                    inner_rad: 138050000.0_f32,
-                    outer_rad: 559410000.0_f32,
+                    mass_mt: 9194400000000.0_f64,
+                    inner_rad: 138050000.0_f64,
+                    outer_rad: 559410000.0_f64,
                 };
 
                 let rings = star.rings.unwrap();
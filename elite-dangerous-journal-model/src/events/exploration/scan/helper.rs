@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 use crate::events::exploration::scan::common::{AtmosphereType, Composition, CompositionPercentage, ParentType, Ring, ScanType};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -10,10 +10,10 @@ pub struct ScanEventHelper {
     pub event_meta: EventMeta,
 
     /// The absolute magnitude of the body
-    pub absolute_magnitude: Option<f32>,
+    pub absolute_magnitude: Option<f64>,
 
     /// The ascending node
-    pub ascending_node: Option<f32>,
+    pub ascending_node: Option<f64>,
 
     /// The atmosphere display text
     pub atmosphere: Option<String>,
@@ -29,7 +29,7 @@ pub struct ScanEventHelper {
     pub age: Option<u32>,
 
     /// The axial tilt of the body
-    pub axial_tilt: Option<f32>,
+    pub axial_tilt: Option<f64>,
 
     /// The name of the scanned body
     pub body_name: Option<String>,
@@ -43,10 +43,10 @@ pub struct ScanEventHelper {
 
     /// The distance from the arrival point in Light Seconds
     #[serde(rename = "DistanceFromArrivalLS")]
-    pub distance_from_arrival_ls: Option<f32>,
+    pub distance_from_arrival_ls: Option<f64>,
 
     /// The eccentricity of the body
-    pub eccentricity: Option<f32>,
+    pub eccentricity: Option<f64>,
 
     /// Is the body landable on
     pub landable: Option<bool>,
@@ -56,35 +56,35 @@ pub struct ScanEventHelper {
 
     /// Mass ET ?
     #[serde(rename="MassET")]
-    pub mass_et: Option<f32>,
+    pub mass_et: Option<f64>,
 
     /// Mass EM ?
     #[serde(rename="MassEM")]
-    pub mass_em: Option<f32>,
+    pub mass_em: Option<f64>,
 
     /// Materials
     pub materials: Option<Vec<CompositionPercentage>>,
 
     /// The mean anomaly
-    pub mean_anomaly: Option<f32>,
+    pub mean_anomaly: Option<f64>,
 
     /// The Orbital Inclination of the body
-    pub orbital_inclination: Option<f32>,
+    pub orbital_inclination: Option<f64>,
 
     /// The orbital period
-    pub orbital_period: Option<f32>,
+    pub orbital_period: Option<f64>,
 
     /// Parents of the body
     pub parents: Option<Vec<ParentType>>,
 
     /// The periapsis of the body
-    pub periapsis: Option<f32>,
+    pub periapsis: Option<f64>,
 
     /// The planet class of the body
     pub planet_class: Option<String>,
 
     /// The radius of the object
-    pub radius: Option<f32>,
+    pub radius: Option<f64>,
 
     /// The status of the reserves
     pub reserve_level: Option<String>,
@@ -93,7 +93,7 @@ pub struct ScanEventHelper {
     pub rings: Option<Vec<Ring>>,
 
     /// Period of rotation of the body
-    pub rotation_period: Option<f32>,
+    pub rotation_period: Option<f64>,
 
     /// The type of scan that was performed
     pub scan_type: ScanType,
@@ -108,19 +108,19 @@ pub struct ScanEventHelper {
     pub star_type: Option<String>,
 
     /// The stellar mass of the object
-    pub stellar_mass: Option<f32>,
+    pub stellar_mass: Option<f64>,
 
     /// The subclass of the start
     pub subclass: Option<u32>,
 
     /// The surface Gravity
-    pub surface_gravity: Option<f32>,
+    pub surface_gravity: Option<f64>,
 
     /// The pressure at the surface
-    pub surface_pressure: Option<f32>,
+    pub surface_pressure: Option<f64>,
 
     /// The surface temparatue
-    pub surface_temperature: Option<f32>,
+    pub surface_temperature: Option<f64>,
 
     /// The internal address of the system
     pub system_address: Option<u64>,
@@ -143,4 +143,7 @@ pub struct ScanEventHelper {
     pub mapped: Option<bool>,
 
 
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+use crate::events::exploration::scan::event::OrbitalMechanicsData;
+
+/// The barycentre (the common centre of mass of orbiting bodies) has been scanned, this is written the first time a
+/// body orbiting the barycentre is scanned
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:59:00Z", "event":"ScanBaryCentre", "StarSystem":"Col 285 Sector DR-M d7-108", "SystemAddress":3721345894787, "BodyID":8, "SemiMajorAxis":8919661641.120911, "Eccentricity":0.000028, "OrbitalInclination":-0.000043, "Periapsis":15.233282, "OrbitalPeriod":462966.245413, "AscendingNode":0.241611, "MeanAnomaly":185.753630 }
+/// ```
+///
+/// ReadTheDocs: [ScanBaryCentre](https://elite-journal.readthedocs.io/en/latest/Exploration/#scanbarycentre)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScanBaryCentreEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the system
+    pub star_system: String,
+
+    /// The system address
+    pub system_address: u64,
+
+    /// The id of the barycentre
+    #[serde(rename = "BodyID")]
+    pub body_id: u64,
+
+    /// The orbit of the barycentre
+    #[serde(flatten)]
+    pub orbital_mechanics_data: OrbitalMechanicsData,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_scan_bary_centre_event() {
+        let json = r#"{ "timestamp":"2025-01-04T19:59:00Z", "StarSystem":"Col 285 Sector DR-M d7-108", "SystemAddress":3721345894787, "BodyID":8, "SemiMajorAxis":8919661641.120911, "Eccentricity":0.000028, "OrbitalInclination":-0.000043, "Periapsis":15.233282, "OrbitalPeriod":462966.245413, "AscendingNode":0.241611, "MeanAnomaly":185.753630 }"#;
+
+        let event: ScanBaryCentreEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:59:00Z"));
+        assert_eq!(event.star_system, "Col 285 Sector DR-M d7-108");
+        assert_eq!(event.body_id, 8);
+        assert_eq!(event.orbital_mechanics_data, OrbitalMechanicsData {
+            semi_major_axis: 8919661641.12091,
+            eccentricity: 0.000028,
+            orbital_inclination: -0.000043,
+            periapsis: 15.233282,
+            orbital_period: 462966.245413,
+            ascending_node: 0.241611,
+            mean_anomaly: 185.753630,
+        });
+        assert!(event.extra.is_empty());
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
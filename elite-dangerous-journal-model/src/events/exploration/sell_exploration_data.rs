@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Exploration data has been sold (the old style of selling by system)
+///
+/// ```json
+/// { "timestamp":"2025-01-07T19:12:01Z", "event":"SellExplorationData", "Systems":[ "HIP 78085", "Praea Euq NW-W b1-3" ], "Discovered":[ "HIP 78085 A 1", "Praea Euq NW-W b1-3 3" ], "BaseValue":10822, "Bonus":3959, "TotalEarnings":44343 }
+/// ```
+///
+/// ReadTheDocs: [SellExplorationData](https://elite-journal.readthedocs.io/en/latest/Exploration/#sellexplorationdata)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SellExplorationDataEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The names of the systems the data was sold for
+    pub systems: Vec<String>,
+
+    /// The names of the bodies that were first discovered by the commander
+    pub discovered: Vec<String>,
+
+    /// The base value of the data
+    pub base_value: u64,
+
+    /// The bonus for first discoveries
+    pub bonus: u64,
+
+    /// The total earned, this includes any bonus for the distance travelled
+    pub total_earnings: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The data sold for a system as part of selling exploration data
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DiscoveredSystem {
+    /// The name of the system
+    pub system_name: String,
+    /// The number of bodies the data was sold for
+    pub num_bodies: u32,
+}
+
+/// Exploration data has been sold
+///
+/// ```json
+/// { "timestamp":"2025-01-07T19:12:01Z", "event":"MultiSellExplorationData", "Discovered":[ { "SystemName":"Praea Euq DC-D d12-79", "NumBodies":22 }, { "SystemName":"Col 285 Sector DR-M d7-108", "NumBodies":9 } ], "BaseValue":2107604, "Bonus":540702, "TotalEarnings":2648306 }
+/// ```
+///
+/// ReadTheDocs: [MultiSellExplorationData](https://elite-journal.readthedocs.io/en/latest/Exploration/#multisellexplorationdata)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MultiSellExplorationDataEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The systems the data was sold for
+    pub discovered: Vec<DiscoveredSystem>,
+
+    /// The base value of the data
+    pub base_value: u64,
+
+    /// The bonus for first discoveries
+    pub bonus: u64,
+
+    /// The total earned, this includes any bonus for the distance travelled
+    pub total_earnings: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_sell_exploration_data_event() {
+        let json = r#"{ "timestamp":"2025-01-07T19:12:01Z", "event":"SellExplorationData", "Systems":[ "HIP 78085", "Praea Euq NW-W b1-3" ], "Discovered":[ "HIP 78085 A 1", "Praea Euq NW-W b1-3 3" ], "BaseValue":10822, "Bonus":3959, "TotalEarnings":44343 }"#;
+
+        let event: SellExplorationDataEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-07T19:12:01Z"));
+        assert_eq!(event.systems, vec!["HIP 78085".to_string(), "Praea Euq NW-W b1-3".to_string()]);
+        assert_eq!(event.discovered.len(), 2);
+        assert_eq!(event.base_value, 10822);
+        assert_eq!(event.bonus, 3959);
+        assert_eq!(event.total_earnings, 44343);
+    }
+
+    #[test]
+    fn test_deserialize_multi_sell_exploration_data_event() {
+        let json = r#"{ "timestamp":"2025-01-07T19:12:01Z", "event":"MultiSellExplorationData", "Discovered":[ { "SystemName":"Praea Euq DC-D d12-79", "NumBodies":22 }, { "SystemName":"Col 285 Sector DR-M d7-108", "NumBodies":9 } ], "BaseValue":2107604, "Bonus":540702, "TotalEarnings":2648306 }"#;
+
+        let event: MultiSellExplorationDataEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-07T19:12:01Z"));
+        assert_eq!(event.discovered, vec![
+            DiscoveredSystem { system_name: "Praea Euq DC-D d12-79".to_string(), num_bodies: 22 },
+            DiscoveredSystem { system_name: "Col 285 Sector DR-M d7-108".to_string(), num_bodies: 9 },
+        ]);
+        assert_eq!(event.total_earnings, 2648306);
+    }
+}
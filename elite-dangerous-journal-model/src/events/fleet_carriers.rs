@@ -0,0 +1,11 @@
+/// Contains structs for CarrierStats events
+pub mod stats;
+/// Contains structs for CarrierJumpRequest and CarrierJumpCancelled events, the CarrierJump event itself is a
+/// [LocationEvent](crate::events::travel::location::LocationEvent)
+pub mod jump;
+/// Contains structs for CarrierBuy, CarrierNameChanged and CarrierDecommission events
+pub mod ownership;
+/// Contains structs for fuel, bank, finance and trade order events
+pub mod finance;
+/// Contains structs for crew service, docking permission and ship/module pack events
+pub mod services;
\ No newline at end of file
@@ -0,0 +1,251 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Tritium has been donated to the carrier's fuel depot
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:10:04Z", "event":"CarrierDepositFuel", "CarrierID":3700005632, "Amount":56, "Total":943 }
+/// ```
+///
+/// ReadTheDocs: [CarrierDepositFuel](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carrierdepositfuel)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierDepositFuelEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The tritium deposited in tons
+    pub amount: u32,
+
+    /// The tritium in the fuel depot after the deposit
+    pub total: u32,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Credits have been moved between the commander and the carrier's bank
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:12:40Z", "event":"CarrierBankTransfer", "CarrierID":3700005632, "Deposit":100000000, "PlayerBalance":1261544323, "CarrierBalance":2100000000 }
+/// ```
+///
+/// ReadTheDocs: [CarrierBankTransfer](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carrierbanktransfer)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierBankTransferEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The credits moved into the carrier's bank
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposit: Option<u64>,
+
+    /// The credits taken out of the carrier's bank
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdraw: Option<u64>,
+
+    /// The commander's balance after the transfer
+    pub player_balance: u64,
+
+    /// The carrier's balance after the transfer
+    pub carrier_balance: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The reserve or tax rate of the carrier has been changed
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:14:22Z", "event":"CarrierFinance", "CarrierID":3700005632, "TaxRate":23, "CarrierBalance":2100000000, "ReserveBalance":882000000, "AvailableBalance":1218000000, "ReservePercent":42 }
+/// ```
+///
+/// ReadTheDocs: [CarrierFinance](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carrierfinance)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierFinanceEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The tax rate charged for services, only present when the tax rate was changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate: Option<u8>,
+
+    /// The credits held by the carrier
+    pub carrier_balance: u64,
+
+    /// The credits reserved for upkeep
+    pub reserve_balance: u64,
+
+    /// The credits available to spend
+    pub available_balance: u64,
+
+    /// The percentage of the balance held in reserve, only present when the reserve was changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserve_percent: Option<u8>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A buy or sell order has been set or cancelled on the carrier's market
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:16:51Z", "event":"CarrierTradeOrder", "CarrierID":3700005632, "BlackMarket":false, "Commodity":"tritium", "PurchaseOrder":500, "Price":51294 }
+/// ```
+///
+/// ReadTheDocs: [CarrierTradeOrder](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carriertradeorder)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "CarrierTradeOrderEventSchema", into = "CarrierTradeOrderEventSchema")]
+pub struct CarrierTradeOrderEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    pub carrier_id: u64,
+
+    /// Is the order on the black market
+    pub black_market: bool,
+
+    /// The commodity
+    pub commodity: LocalisedValue,
+
+    /// The quantity to buy, only present for buy orders
+    pub purchase_order: Option<u32>,
+
+    /// The quantity to sell, only present for sell orders
+    pub sale_order: Option<u32>,
+
+    /// Has the order been cancelled
+    pub cancel_trade: Option<bool>,
+
+    /// The price per unit, not present when the order is cancelled
+    pub price: Option<u64>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl CarrierTradeOrderEvent {
+
+    /// Has the order been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_trade.unwrap_or(false)
+    }
+}
+
+impl From<CarrierTradeOrderEventSchema> for CarrierTradeOrderEvent {
+    fn from(value: CarrierTradeOrderEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            carrier_id: value.carrier_id,
+            black_market: value.black_market,
+            commodity: LocalisedValue::new(value.commodity, value.commodity_localised),
+            purchase_order: value.purchase_order,
+            sale_order: value.sale_order,
+            cancel_trade: value.cancel_trade,
+            price: value.price,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierTradeOrderEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+    pub black_market: bool,
+    pub commodity: String,
+    #[serde(rename = "Commodity_Localised", skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchase_order: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sale_order: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_trade: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<u64>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<CarrierTradeOrderEvent> for CarrierTradeOrderEventSchema {
+    fn from(value: CarrierTradeOrderEvent) -> Self {
+        let (commodity, commodity_localised) = deconstruct_localised_value(value.commodity);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            carrier_id: value.carrier_id,
+            black_market: value.black_market,
+            commodity,
+            commodity_localised,
+            purchase_order: value.purchase_order,
+            sale_order: value.sale_order,
+            cancel_trade: value.cancel_trade,
+            price: value.price,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_carrier_bank_transfer_event() {
+        let deposit: CarrierBankTransferEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-12T18:12:40Z", "CarrierID":3700005632, "Deposit":100000000, "PlayerBalance":1261544323, "CarrierBalance":2100000000 }"#).unwrap();
+        let withdraw: CarrierBankTransferEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-12T18:13:02Z", "CarrierID":3700005632, "Withdraw":5000000, "PlayerBalance":1266544323, "CarrierBalance":2095000000 }"#).unwrap();
+
+        assert_eq!(deposit.event_meta.timestamp, create_timestamp("2025-01-12T18:12:40Z"));
+        assert_eq!(deposit.deposit, Some(100000000));
+        assert_eq!(deposit.withdraw, None);
+        assert_eq!(withdraw.withdraw, Some(5000000));
+        assert_eq!(withdraw.carrier_balance, 2095000000);
+        assert!(withdraw.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_serialize_carrier_trade_order_event() {
+        let json = r#"{"timestamp":"2025-01-12T18:16:51Z","CarrierID":3700005632,"BlackMarket":false,"Commodity":"tritium","PurchaseOrder":500,"Price":51294}"#;
+        let cancelled: CarrierTradeOrderEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-12T18:17:30Z", "CarrierID":3700005632, "BlackMarket":false, "Commodity":"gold", "Commodity_Localised":"Gold", "CancelTrade":true }"#).unwrap();
+
+        let event: CarrierTradeOrderEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.commodity.value, "tritium");
+        assert_eq!(event.purchase_order, Some(500));
+        assert_eq!(event.price, Some(51294));
+        assert!(!event.is_cancelled());
+        assert!(cancelled.is_cancelled());
+        assert_eq!(cancelled.commodity.localised_value, Some("Gold".to_string()));
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// A jump has been scheduled for the commander's carrier, the arrival is written as a
+/// [CarrierJump](crate::events::travel::location::LocationEvent) event if the commander is aboard
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:05:11Z", "event":"CarrierJumpRequest", "CarrierID":3700005632, "SystemName":"Paesui Xena", "Body":"Paesui Xena A", "SystemAddress":7269634680555, "BodyID":1, "DepartureTime":"2025-01-12T18:20:10Z" }
+/// ```
+///
+/// ReadTheDocs: [CarrierJumpRequest](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carrierjumprequest)
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierJumpRequestEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The destination system
+    pub system_name: String,
+
+    /// The address of the destination system
+    pub system_address: u64,
+
+    /// The body the carrier will orbit, not present if jumping to the star
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// The id of the body within the system
+    #[serde(rename = "BodyID", skip_serializing_if = "Option::is_none")]
+    pub body_id: Option<u32>,
+
+    /// The time the carrier will jump, not present in older journals
+    #[serde_as(as = "Option<chrono::DateTime<chrono::Utc>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub departure_time: Option<NaiveDateTime>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A scheduled jump of the commander's carrier has been cancelled
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:07:45Z", "event":"CarrierJumpCancelled", "CarrierID":3700005632 }
+/// ```
+///
+/// ReadTheDocs: [CarrierJumpCancelled](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carrierjumpcancelled)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierJumpCancelledEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_carrier_jump_request_event() {
+        let json = r#"{"timestamp":"2025-01-12T18:05:11Z","CarrierID":3700005632,"SystemName":"Paesui Xena","Body":"Paesui Xena A","SystemAddress":7269634680555,"BodyID":1,"DepartureTime":"2025-01-12T18:20:10Z"}"#;
+
+        let event: CarrierJumpRequestEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-12T18:05:11Z"));
+        assert_eq!(event.system_name, "Paesui Xena");
+        assert_eq!(event.body, Some("Paesui Xena A".to_string()));
+        assert_eq!(event.departure_time, Some(create_timestamp("2025-01-12T18:20:10Z")));
+        assert!(event.extra.is_empty());
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -0,0 +1,138 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, TimestampSeconds};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// A fleet carrier has been bought
+///
+/// ```json
+/// { "timestamp":"2025-01-12T17:45:02Z", "event":"CarrierBuy", "CarrierID":3700005632, "BoughtAtMarket":3221301504, "Location":"Sol", "SystemAddress":10477373803, "Price":4990000000, "Variant":"CarrierDockB", "Callsign":"Q2K-BHB" }
+/// ```
+///
+/// ReadTheDocs: [CarrierBuy](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carrierbuy)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierBuyEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The market id of the station the carrier was bought at
+    pub bought_at_market: u64,
+
+    /// The system the carrier was bought in
+    pub location: String,
+
+    /// The address of the system
+    pub system_address: u64,
+
+    /// The price paid
+    pub price: u64,
+
+    /// The model of the carrier e.g. ```CarrierDockB```
+    pub variant: String,
+
+    /// The callsign of the carrier e.g. ```Q2K-BHB```
+    pub callsign: String,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The commander's carrier has been renamed
+///
+/// ```json
+/// { "timestamp":"2025-01-12T17:50:31Z", "event":"CarrierNameChanged", "CarrierID":3700005632, "Callsign":"Q2K-BHB", "Name":"BOUNDLESS HORIZON" }
+/// ```
+///
+/// ReadTheDocs: [CarrierNameChanged](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carriernamechanged)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierNameChangedEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The callsign of the carrier
+    pub callsign: String,
+
+    /// The new name of the carrier
+    pub name: String,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The commander's carrier has been scheduled for decommission
+///
+/// ```json
+/// { "timestamp":"2025-01-12T19:10:00Z", "event":"CarrierDecommission", "CarrierID":3700005632, "ScrapRefund":4850000000, "ScrapTime":1737313800 }
+/// ```
+///
+/// ReadTheDocs: [CarrierDecommission](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carrierdecommission)
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierDecommissionEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The credits that will be refunded
+    pub scrap_refund: u64,
+
+    /// The time the carrier will be scrapped, written to the journal as a unix timestamp
+    #[serde_as(as = "TimestampSeconds<i64>")]
+    pub scrap_time: NaiveDateTime,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_carrier_buy_event() {
+        let json = r#"{ "timestamp":"2025-01-12T17:45:02Z", "CarrierID":3700005632, "BoughtAtMarket":3221301504, "Location":"Sol", "SystemAddress":10477373803, "Price":4990000000, "Variant":"CarrierDockB", "Callsign":"Q2K-BHB" }"#;
+
+        let event: CarrierBuyEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-12T17:45:02Z"));
+        assert_eq!(event.carrier_id, 3700005632);
+        assert_eq!(event.location, "Sol");
+        assert_eq!(event.price, 4990000000);
+        assert_eq!(event.callsign, "Q2K-BHB");
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_serialize_carrier_decommission_event() {
+        let json = r#"{"timestamp":"2025-01-12T19:10:00Z","CarrierID":3700005632,"ScrapRefund":4850000000,"ScrapTime":1737313800}"#;
+
+        let event: CarrierDecommissionEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.scrap_refund, 4850000000);
+        assert_eq!(event.scrap_time, create_timestamp("2025-01-19T19:10:00Z"));
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+use crate::events::fleet_carriers::stats::DockingAccess;
+
+/// The change made to a carrier service
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum CrewServiceOperation {
+    /// The service has been installed
+    Activate,
+    /// The service has been removed
+    Deactivate,
+    /// The service has been suspended
+    Pause,
+    /// The suspended service has been restarted
+    Resume,
+    /// The crew member running the service has been replaced
+    Replace,
+    /// An operation that is not modelled
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A service on the commander's carrier has been changed
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:20:45Z", "event":"CarrierCrewServices", "CarrierID":3700005632, "CrewRole":"Refuel", "Operation":"Activate", "CrewName":"Donna Moon" }
+/// ```
+///
+/// ReadTheDocs: [CarrierCrewServices](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carriercrewservices)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierCrewServicesEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The service changed e.g. ```Refuel``` or ```BlackMarket```
+    pub crew_role: String,
+
+    /// The change made
+    pub operation: CrewServiceOperation,
+
+    /// The name of the crew member running the service
+    pub crew_name: String,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The docking permissions of the commander's carrier have been changed
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:22:13Z", "event":"CarrierDockingPermission", "CarrierID":3700005632, "DockingAccess":"squadronfriends", "AllowNotorious":false }
+/// ```
+///
+/// ReadTheDocs: [CarrierDockingPermission](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carrierdockingpermission)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierDockingPermissionEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// Who is allowed to dock
+    pub docking_access: DockingAccess,
+
+    /// Can commanders with a notorious status dock
+    pub allow_notorious: bool,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The change made to a ship or module pack
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum CarrierPackOperation {
+    /// The pack has been bought
+    BuyPack,
+    /// The pack has been sold
+    SellPack,
+    /// The pack has been restocked
+    RestockPack,
+    /// An operation that is not modelled
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A ship pack (CarrierShipPack) or module pack (CarrierModulePack) on the commander's carrier has been changed
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:25:37Z", "event":"CarrierModulePack", "CarrierID":3700005632, "Operation":"BuyPack", "PackTheme":"VehicleSupport", "PackTier":1, "Cost":24000000 }
+/// ```
+///
+/// ReadTheDocs: [CarrierModulePack](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carriermodulepack)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierPackEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The change made
+    pub operation: CarrierPackOperation,
+
+    /// The theme of the pack
+    pub pack_theme: String,
+
+    /// The tier of the pack
+    pub pack_tier: u8,
+
+    /// The credits paid, present when buying or restocking
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<u64>,
+
+    /// The credits refunded, present when selling
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund: Option<u64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_carrier_crew_services_event() {
+        let json = r#"{ "timestamp":"2025-01-12T18:20:45Z", "CarrierID":3700005632, "CrewRole":"Refuel", "Operation":"Activate", "CrewName":"Donna Moon" }"#;
+
+        let event: CarrierCrewServicesEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-12T18:20:45Z"));
+        assert_eq!(event.crew_role, "Refuel");
+        assert_eq!(event.operation, CrewServiceOperation::Activate);
+        assert_eq!(event.crew_name, "Donna Moon");
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_carrier_pack_event() {
+        let bought: CarrierPackEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-12T18:25:37Z", "CarrierID":3700005632, "Operation":"BuyPack", "PackTheme":"VehicleSupport", "PackTier":1, "Cost":24000000 }"#).unwrap();
+        let sold: CarrierPackEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-12T18:28:02Z", "CarrierID":3700005632, "Operation":"SellPack", "PackTheme":"Zorgon Peterson - Trading Tier", "PackTier":1, "Refund":4000000 }"#).unwrap();
+
+        assert_eq!(bought.operation, CarrierPackOperation::BuyPack);
+        assert_eq!(bought.cost, Some(24000000));
+        assert_eq!(sold.operation, CarrierPackOperation::SellPack);
+        assert_eq!(sold.refund, Some(4000000));
+        assert!(sold.extra.is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Who is allowed to dock at the carrier
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum DockingAccess {
+    /// Anyone can dock
+    All,
+    /// Only the owner can dock
+    None,
+    /// The owner's friends can dock
+    Friends,
+    /// The owner's squadron can dock
+    Squadron,
+    /// The owner's squadron and friends can dock
+    SquadronFriends,
+    /// An access level that is not modelled
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// How the space on the carrier is used, in tons
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierSpaceUsage {
+    /// The total capacity of the carrier
+    pub total_capacity: u32,
+    /// The space used by crew for the active services
+    pub crew: u32,
+    /// The space used by cargo
+    pub cargo: u32,
+    /// The space reserved for buy orders
+    pub cargo_space_reserved: u32,
+    /// The space used by ship packs
+    pub ship_packs: u32,
+    /// The space used by module packs
+    pub module_packs: u32,
+    /// The unused space
+    pub free_space: u32,
+}
+
+/// The finances of the carrier
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierFinances {
+    /// The credits held by the carrier
+    pub carrier_balance: u64,
+    /// The credits reserved for upkeep
+    pub reserve_balance: u64,
+    /// The credits available to spend
+    pub available_balance: u64,
+    /// The percentage of the balance held in reserve
+    pub reserve_percent: u8,
+    /// The tax rate charged for rearming
+    #[serde(rename = "TaxRate_rearm", skip_serializing_if = "Option::is_none")]
+    pub tax_rate_rearm: Option<u8>,
+    /// The tax rate charged for refuelling
+    #[serde(rename = "TaxRate_refuel", skip_serializing_if = "Option::is_none")]
+    pub tax_rate_refuel: Option<u8>,
+    /// The tax rate charged for repairs
+    #[serde(rename = "TaxRate_repair", skip_serializing_if = "Option::is_none")]
+    pub tax_rate_repair: Option<u8>,
+    /// The tax rate charged at the shipyard
+    #[serde(rename = "TaxRate_shipyard", skip_serializing_if = "Option::is_none")]
+    pub tax_rate_shipyard: Option<u8>,
+    /// The tax rate charged at outfitting
+    #[serde(rename = "TaxRate_outfitting", skip_serializing_if = "Option::is_none")]
+    pub tax_rate_outfitting: Option<u8>,
+}
+
+/// A crew member (service) of the carrier
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierCrewMember {
+    /// The service the crew member provides e.g. ```Refuel``` or ```BlackMarket```
+    pub crew_role: String,
+    /// Has the service been installed
+    pub activated: bool,
+    /// Is the service running, not present if the service has not been installed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The name of the crew member, not present if the service has not been installed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crew_name: Option<String>,
+}
+
+/// A ship or module pack installed on the carrier
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierPack {
+    /// The theme of the pack
+    pub pack_theme: String,
+    /// The tier of the pack
+    pub pack_tier: u8,
+}
+
+/// The current state of the commander's carrier, written when the carrier management screen is opened
+///
+/// ```json
+/// { "timestamp":"2025-01-12T18:01:20Z", "event":"CarrierStats", "CarrierID":3700005632, "Callsign":"Q2K-BHB", "Name":"BOUNDLESS HORIZON", "DockingAccess":"squadronfriends", "AllowNotorious":false, "FuelLevel":287, "JumpRangeCurr":500.000000, "JumpRangeMax":500.000000, "PendingDecommission":false, "SpaceUsage":{ "TotalCapacity":25000, "Crew":6370, "Cargo":1010, "CargoSpaceReserved":0, "ShipPacks":0, "ModulePacks":4620, "FreeSpace":13000 }, "Finance":{ "CarrierBalance":2000000000, "ReserveBalance":10000000, "AvailableBalance":1990000000, "ReservePercent":0, "TaxRate_rearm":25, "TaxRate_refuel":25, "TaxRate_repair":25 }, "Crew":[ { "CrewRole":"BlackMarket", "Activated":false }, { "CrewRole":"Captain", "Activated":true, "Enabled":true, "CrewName":"Vada Cannon" } ], "ShipPacks":[  ], "ModulePacks":[ { "PackTheme":"VehicleSupport", "PackTier":1 } ] }
+/// ```
+///
+/// ReadTheDocs: [CarrierStats](https://elite-journal.readthedocs.io/en/latest/Fleet%20Carriers/#carrierstats)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierStatsEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the carrier
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: u64,
+
+    /// The callsign of the carrier e.g. ```Q2K-BHB```
+    pub callsign: String,
+
+    /// The name of the carrier
+    pub name: String,
+
+    /// Who is allowed to dock
+    pub docking_access: DockingAccess,
+
+    /// Can commanders with a notorious status dock
+    pub allow_notorious: bool,
+
+    /// The tritium in the fuel depot
+    pub fuel_level: u32,
+
+    /// The current jump range in light years
+    pub jump_range_curr: f64,
+
+    /// The maximum jump range in light years
+    pub jump_range_max: f64,
+
+    /// Has the carrier been scheduled for decommission
+    pub pending_decommission: bool,
+
+    /// How the space on the carrier is used
+    pub space_usage: CarrierSpaceUsage,
+
+    /// The finances of the carrier
+    pub finance: CarrierFinances,
+
+    /// The crew, one per service
+    pub crew: Vec<CarrierCrewMember>,
+
+    /// The ship packs installed
+    pub ship_packs: Vec<CarrierPack>,
+
+    /// The module packs installed
+    pub module_packs: Vec<CarrierPack>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl CarrierStatsEvent {
+
+    /// The services that are installed and running
+    pub fn active_services(&self) -> Vec<&str> {
+        self.crew.iter()
+            .filter(|crew| crew.activated && crew.enabled.unwrap_or(false))
+            .map(|crew| crew.crew_role.as_str())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_carrier_stats_event() {
+        let json = r#"{ "timestamp":"2025-01-12T18:01:20Z", "CarrierID":3700005632, "Callsign":"Q2K-BHB", "Name":"BOUNDLESS HORIZON", "DockingAccess":"squadronfriends", "AllowNotorious":false, "FuelLevel":287, "JumpRangeCurr":500.000000, "JumpRangeMax":500.000000, "PendingDecommission":false, "SpaceUsage":{ "TotalCapacity":25000, "Crew":6370, "Cargo":1010, "CargoSpaceReserved":0, "ShipPacks":0, "ModulePacks":4620, "FreeSpace":13000 }, "Finance":{ "CarrierBalance":2000000000, "ReserveBalance":10000000, "AvailableBalance":1990000000, "ReservePercent":0, "TaxRate_rearm":25, "TaxRate_refuel":25, "TaxRate_repair":25 }, "Crew":[ { "CrewRole":"BlackMarket", "Activated":false }, { "CrewRole":"Captain", "Activated":true, "Enabled":true, "CrewName":"Vada Cannon" }, { "CrewRole":"Refuel", "Activated":true, "Enabled":false, "CrewName":"Donna Moon" } ], "ShipPacks":[  ], "ModulePacks":[ { "PackTheme":"VehicleSupport", "PackTier":1 } ] }"#;
+
+        let event: CarrierStatsEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-12T18:01:20Z"));
+        assert_eq!(event.callsign, "Q2K-BHB");
+        assert_eq!(event.docking_access, DockingAccess::SquadronFriends);
+        assert_eq!(event.fuel_level, 287);
+        assert_eq!(event.space_usage.free_space, 13000);
+        assert_eq!(event.finance.available_balance, 1990000000);
+        assert_eq!(event.finance.tax_rate_refuel, Some(25));
+        assert_eq!(event.finance.tax_rate_shipyard, None);
+        assert_eq!(event.active_services(), vec!["Captain"]);
+        assert_eq!(event.module_packs, vec![CarrierPack { pack_theme: "VehicleSupport".to_string(), pack_tier: 1 }]);
+        assert!(event.extra.is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// The category of a journal event, this follows the module layout of the events (which in turn follows the
+/// sections of the ReadTheDocs documentation)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventCategory {
+    /// Events emitted at start up - see [startup](crate::events::startup)
+    Startup,
+    /// Events related to travel - see [travel](crate::events::travel)
+    Travel,
+    /// Events related to combat - see [combat](crate::events::combat)
+    Combat,
+    /// Events related to exploration - see [exploration](crate::events::exploration)
+    Exploration,
+    /// Events related to trade - see [trade](crate::events::trade)
+    Trade,
+    /// Events related to station services - see [station_services](crate::events::station_services)
+    StationServices,
+    /// Events related to powerplay - see [powerplay](crate::events::powerplay)
+    Powerplay,
+    /// Events related to squadrons - see [squadrons](crate::events::squadrons)
+    Squadrons,
+    /// Events related to fleet carriers - see [fleet_carriers](crate::events::fleet_carriers)
+    FleetCarriers,
+    /// Events added in odyssey - see [odyssey](crate::events::odyssey)
+    Odyssey,
+    /// Events that have no other home - see [other](crate::events::other), this is also the category of
+    /// [Unknown](crate::events::JournalEvent::Unknown) events
+    Other,
+}
+
+/// Uniform accessors for the data every journal event has, regardless of its variant
+///
+/// ```
+/// use elite_dangerous_journal_model::events::JournalEvent;
+/// use elite_dangerous_journal_model::events::journal_event_ext::{EventCategory, JournalEventExt};
+///
+/// let event: JournalEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-04T19:31:02Z", "event":"Shutdown" }"#).unwrap();
+///
+/// assert_eq!(event.event_name(), "Shutdown");
+/// assert_eq!(event.category(), EventCategory::Other);
+/// assert_eq!(event.timestamp().to_string(), "2025-01-04 19:31:02");
+/// ```
+pub trait JournalEventExt {
+    /// The time the event was written to the journal
+    fn timestamp(&self) -> NaiveDateTime;
+
+    /// The name of the event as written in the ```event``` field of the journal
+    fn event_name(&self) -> &str;
+
+    /// The category of the event
+    fn category(&self) -> EventCategory;
+}
@@ -1,4 +1,12 @@
 /// Contains structs for ShipLocker event
 pub mod ship_locker;
 /// Contains common Odyssey structs
-pub mod common;
\ No newline at end of file
+pub mod common;
+/// Contains structs for Backpack and BackpackChange events
+pub mod backpack;
+/// Contains structs for buying, selling and trading micro resources
+pub mod micro_resources;
+/// Contains structs for ScanOrganic and SellOrganicData events
+pub mod organic;
+/// Contains structs for suit and hand weapon events
+pub mod suits;
\ No newline at end of file
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+use crate::events::odyssey::common::{ChangedItem, Item};
+
+/// The contents of the backpack, the journal entry is usually empty and the contents are written to Backpack.json
+///
+/// ```json
+/// { "timestamp":"2025-01-11T21:56:34Z", "event":"Backpack", "Items":[  ], "Components":[ { "Name":"graphene", "OwnerID":0, "Count":2 } ], "Consumables":[ { "Name":"healthpack", "Name_Localised":"Medkit", "OwnerID":0, "Count":2 } ], "Data":[  ] }
+/// ```
+///
+/// ReadTheDocs: [Backpack](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#backpack)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BackpackEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// List of items in the backpack
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<Item>>,
+
+    /// List of components in the backpack
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Item>>,
+
+    /// List of consumables in the backpack
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consumables: Option<Vec<Item>>,
+
+    /// List of data in the backpack
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<Item>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Items have been added to or removed from the backpack
+///
+/// ```json
+/// { "timestamp":"2025-01-11T22:03:12Z", "event":"BackpackChange", "Removed":[ { "Name":"healthpack", "Name_Localised":"Medkit", "OwnerID":0, "Count":1, "Type":"Consumable" } ] }
+/// ```
+///
+/// ReadTheDocs: [BackpackChange](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#backpackchange)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BackpackChangeEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The items added to the backpack
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<ChangedItem>,
+
+    /// The items removed from the backpack
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<ChangedItem>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::odyssey::common::ItemCategory;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_backpack_event() {
+        let json = r#"{ "timestamp":"2025-01-11T21:56:34Z", "Items":[  ], "Components":[ { "Name":"graphene", "OwnerID":0, "Count":2 } ], "Consumables":[ { "Name":"healthpack", "Name_Localised":"Medkit", "OwnerID":0, "Count":2 } ], "Data":[  ] }"#;
+
+        let event: BackpackEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T21:56:34Z"));
+        assert_eq!(event.items, Some(vec![]));
+        assert_eq!(event.components.as_ref().map(Vec::len), Some(1));
+        assert_eq!(event.consumables.as_ref().unwrap()[0].count, 2);
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_serialize_backpack_change_event() {
+        let json = r#"{"timestamp":"2025-01-11T22:03:12Z","Removed":[{"Name":"healthpack","Name_Localised":"Medkit","OwnerID":0,"Count":1,"Type":"Consumable"}]}"#;
+
+        let event: BackpackChangeEvent = serde_json::from_str(json).unwrap();
+
+        assert!(event.added.is_empty());
+        assert_eq!(event.removed.len(), 1);
+        assert_eq!(event.removed[0].category, ItemCategory::Consumable);
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
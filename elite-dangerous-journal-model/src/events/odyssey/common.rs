@@ -1,18 +1,147 @@
 use serde::{Deserialize, Serialize};
-// TODO: Confirm actual field data types
+use crate::events::common::{deconstruct_localised_value, LocalisedValue};
+
+/// The category of an on foot item, the backpack and ship locker are split into these categories
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum ItemCategory {
+    /// Goods e.g. ```weaponschematic```
+    Item,
+    /// Crafting components e.g. ```graphene```
+    Component,
+    /// Consumables e.g. ```healthpack```
+    Consumable,
+    /// Data e.g. ```employeerecords```
+    Data,
+    /// A category that is not modelled
+    #[serde(untagged)]
+    Other(String),
+}
+
 /// Used in new odyssey events - Represents an item in a container (Backpack, ShipLocker etc)
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
-#[serde(rename_all = "PascalCase")]
+#[serde(from = "ItemSchema", into = "ItemSchema")]
 pub struct Item {
     /// The name of the item
-    pub name: String,
-    /// The owner of the item - API Docs does not state if this is string or number, assuming it's commander id for now TODO
-    #[serde(rename="OwnerID")]
-    pub owner_id: String,
-    /// The mission id (if relevant) - MissionID has been used in other fields and is an int so using that for now TODO
-    #[serde(rename = "MissionID")]
-    pub mission_id: Option<u32>,
+    pub name: LocalisedValue,
+    /// The id of the commander that owns the item, zero for the commander's own items
+    pub owner_id: u64,
+    /// The mission the item is for (if relevant)
+    pub mission_id: Option<u64>,
     /// The number in the container
     pub count: u32,
+}
+
+impl From<ItemSchema> for Item {
+    fn from(value: ItemSchema) -> Self {
+        Self {
+            name: LocalisedValue::new(value.name, value.name_localised),
+            owner_id: value.owner_id,
+            mission_id: value.mission_id,
+            count: value.count,
+        }
+    }
+}
+
+/// Schema for the item due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ItemSchema {
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+    #[serde(rename = "OwnerID")]
+    owner_id: u64,
+    #[serde(rename = "MissionID", skip_serializing_if = "Option::is_none")]
+    mission_id: Option<u64>,
+    count: u32,
+}
+
+impl From<Item> for ItemSchema {
+    fn from(value: Item) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            name,
+            name_localised,
+            owner_id: value.owner_id,
+            mission_id: value.mission_id,
+            count: value.count,
+        }
+    }
+}
+
+/// An item added to or removed from the backpack
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChangedItem {
+    /// The item
+    #[serde(flatten)]
+    pub item: Item,
+    /// The category of the item
+    #[serde(rename = "Type")]
+    pub category: ItemCategory,
+}
+
+/// A quantity of micro resources bought, sold or traded
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "MicroResourceSchema", into = "MicroResourceSchema")]
+pub struct MicroResource {
+    /// The name of the micro resource
+    pub name: LocalisedValue,
+    /// The category of the micro resource
+    pub category: ItemCategory,
+    /// The number of the micro resource
+    pub count: u32,
+}
+
+impl From<MicroResourceSchema> for MicroResource {
+    fn from(value: MicroResourceSchema) -> Self {
+        Self {
+            name: LocalisedValue::new(value.name, value.name_localised),
+            category: value.category,
+            count: value.count,
+        }
+    }
+}
+
+/// Schema for the micro resource due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct MicroResourceSchema {
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+    category: ItemCategory,
+    count: u32,
+}
+
+impl From<MicroResource> for MicroResourceSchema {
+    fn from(value: MicroResource) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            name,
+            name_localised,
+            category: value.category,
+            count: value.count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_serialize_changed_item() {
+        let json = r#"{"Name":"healthpack","Name_Localised":"Medkit","OwnerID":0,"Count":1,"Type":"Consumable"}"#;
+
+        let changed: ChangedItem = serde_json::from_str(json).unwrap();
+
+        assert_eq!(changed.item.name, LocalisedValue::new("healthpack".to_string(), Some("Medkit".to_string())));
+        assert_eq!(changed.item.owner_id, 0);
+        assert_eq!(changed.item.mission_id, None);
+        assert_eq!(changed.category, ItemCategory::Consumable);
 
-}
\ No newline at end of file
+        let serialized = serde_json::to_value(&changed).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields, LocalisedValue};
+use crate::events::odyssey::common::{ItemCategory, MicroResource};
+
+/// Micro resources have been bought from a bar tender or shop
+///
+/// ```json
+/// { "timestamp":"2025-01-11T22:05:40Z", "event":"BuyMicroResources", "TotalCount":2, "MicroResources":[ { "Name":"healthpack", "Name_Localised":"Medkit", "Category":"Consumable", "Count":2 } ], "Price":2000, "MarketID":3221524992 }
+/// ```
+///
+/// Older journals give a single ```Name```, ```Category``` and ```Count``` instead of the list of ```MicroResources```
+///
+/// ReadTheDocs: [BuyMicroResources](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#buymicroresources)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuyMicroResourcesEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The micro resource bought, older journals only
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub micro_resource: Option<MicroResource>,
+
+    /// The total number bought
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u32>,
+
+    /// The micro resources bought
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub micro_resources: Option<Vec<MicroResource>>,
+
+    /// The total price paid
+    pub price: u64,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl BuyMicroResourcesEvent {
+
+    /// The micro resources bought regardless of which journal format was used
+    pub fn bought(&self) -> Vec<&MicroResource> {
+        self.micro_resources.iter().flatten().chain(self.micro_resource.iter()).collect()
+    }
+}
+
+/// Micro resources have been sold to a bar tender
+///
+/// ```json
+/// { "timestamp":"2025-01-11T22:07:02Z", "event":"SellMicroResources", "TotalCount":3, "MicroResources":[ { "Name":"employeerecords", "Name_Localised":"Employee Records", "Category":"Data", "Count":3 } ], "Price":1200, "MarketID":3221524992 }
+/// ```
+///
+/// ReadTheDocs: [SellMicroResources](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#sellmicroresources)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SellMicroResourcesEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The total number sold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u32>,
+
+    /// The micro resources sold
+    pub micro_resources: Vec<MicroResource>,
+
+    /// The total price received
+    pub price: u64,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Micro resources have been exchanged at a bar tender
+///
+/// ```json
+/// { "timestamp":"2025-01-11T22:09:15Z", "event":"TradeMicroResources", "Offered":[ { "Name":"chemicalsample", "Name_Localised":"Chemical Sample", "Category":"Item", "Count":10 } ], "TotalCount":10, "Received":"graphene", "Received_Localised":"Graphene", "Category":"Component", "Count":1, "MarketID":3221524992 }
+/// ```
+///
+/// ReadTheDocs: [TradeMicroResources](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#trademicroresources)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "TradeMicroResourcesEventSchema", into = "TradeMicroResourcesEventSchema")]
+pub struct TradeMicroResourcesEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The micro resources given to the bar tender
+    pub offered: Vec<MicroResource>,
+
+    /// The total number given
+    pub total_count: Option<u32>,
+
+    /// The micro resource received
+    pub received: MicroResource,
+
+    /// The market id of the station
+    pub market_id: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<TradeMicroResourcesEventSchema> for TradeMicroResourcesEvent {
+    fn from(value: TradeMicroResourcesEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            offered: value.offered,
+            total_count: value.total_count,
+            received: MicroResource {
+                name: LocalisedValue::new(value.received, value.received_localised),
+                category: value.category,
+                count: value.count,
+            },
+            market_id: value.market_id,
+        }
+    }
+}
+
+/// The received micro resource is spread over the top level fields so we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct TradeMicroResourcesEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub offered: Vec<MicroResource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u32>,
+    pub received: String,
+    #[serde(rename = "Received_Localised", skip_serializing_if = "Option::is_none")]
+    pub received_localised: Option<String>,
+    pub category: ItemCategory,
+    pub count: u32,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<TradeMicroResourcesEvent> for TradeMicroResourcesEventSchema {
+    fn from(value: TradeMicroResourcesEvent) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            offered: value.offered,
+            total_count: value.total_count,
+            received: value.received.name.value,
+            received_localised: value.received.name.localised_value,
+            category: value.received.category,
+            count: value.received.count,
+            market_id: value.market_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_bought_micro_resources() {
+        let old: BuyMicroResourcesEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-11T22:05:40Z", "Name":"healthpack", "Name_Localised":"Medkit", "Category":"Consumable", "Count":2, "Price":2000, "MarketID":3221524992 }"#).unwrap();
+        let new: BuyMicroResourcesEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-11T22:05:40Z", "TotalCount":2, "MicroResources":[ { "Name":"healthpack", "Name_Localised":"Medkit", "Category":"Consumable", "Count":2 } ], "Price":2000, "MarketID":3221524992 }"#).unwrap();
+
+        assert_eq!(old.bought(), new.bought());
+        assert_eq!(new.bought()[0].category, ItemCategory::Consumable);
+        assert_eq!(old.price, 2000);
+        assert!(old.extra.is_empty());
+        assert!(new.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_serialize_trade_micro_resources_event() {
+        let json = r#"{"timestamp":"2025-01-11T22:09:15Z","Offered":[{"Name":"chemicalsample","Name_Localised":"Chemical Sample","Category":"Item","Count":10}],"TotalCount":10,"Received":"graphene","Received_Localised":"Graphene","Category":"Component","Count":1,"MarketID":3221524992}"#;
+
+        let event: TradeMicroResourcesEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T22:09:15Z"));
+        assert_eq!(event.offered[0].category, ItemCategory::Item);
+        assert_eq!(event.received.name.value, "graphene");
+        assert_eq!(event.received.category, ItemCategory::Component);
+        assert_eq!(event.received.count, 1);
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
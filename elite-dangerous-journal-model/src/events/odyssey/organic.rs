@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// The stage of an organic scan, a species is logged once then sampled three times
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum OrganicScanType {
+    /// The first scan of the species
+    Log,
+    /// A further sample of the species
+    Sample,
+    /// The final sample, the species has been analysed and can be sold
+    Analyse,
+    /// A scan type that is not modelled
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The classification of an organism
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "OrganismSchema", into = "OrganismSchema")]
+pub struct Organism {
+    /// The genus e.g. ```$Codex_Ent_Bacterial_Genus_Name;```
+    pub genus: LocalisedValue,
+    /// The species e.g. ```$Codex_Ent_Bacterial_04_Name;```
+    pub species: LocalisedValue,
+    /// The colour variant of the species, not present in older journals
+    pub variant: Option<LocalisedValue>,
+}
+
+impl From<OrganismSchema> for Organism {
+    fn from(value: OrganismSchema) -> Self {
+        Self {
+            genus: LocalisedValue::new(value.genus, value.genus_localised),
+            species: LocalisedValue::new(value.species, value.species_localised),
+            variant: LocalisedValue::new_optional(value.variant, value.variant_localised),
+        }
+    }
+}
+
+/// Schema for the organism due to the localised values
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct OrganismSchema {
+    genus: String,
+    #[serde(rename = "Genus_Localised", skip_serializing_if = "Option::is_none")]
+    genus_localised: Option<String>,
+    species: String,
+    #[serde(rename = "Species_Localised", skip_serializing_if = "Option::is_none")]
+    species_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<String>,
+    #[serde(rename = "Variant_Localised", skip_serializing_if = "Option::is_none")]
+    variant_localised: Option<String>,
+}
+
+impl From<Organism> for OrganismSchema {
+    fn from(value: Organism) -> Self {
+        let (genus, genus_localised) = deconstruct_localised_value(value.genus);
+        let (species, species_localised) = deconstruct_localised_value(value.species);
+        let (variant, variant_localised) = deconstruct_optional_localised_value(value.variant);
+        Self {
+            genus,
+            genus_localised,
+            species,
+            species_localised,
+            variant,
+            variant_localised,
+        }
+    }
+}
+
+/// An organism has been scanned with the genetic sampler
+///
+/// ```json
+/// { "timestamp":"2025-01-12T15:20:44Z", "event":"ScanOrganic", "ScanType":"Log", "Genus":"$Codex_Ent_Bacterial_Genus_Name;", "Genus_Localised":"Bacterium", "Species":"$Codex_Ent_Bacterial_04_Name;", "Species_Localised":"Bacterium Informem", "Variant":"$Codex_Ent_Bacterial_04_Tin_Name;", "Variant_Localised":"Bacterium Informem - Yellow", "SystemAddress":3107509474002, "Body":12 }
+/// ```
+///
+/// ReadTheDocs: [ScanOrganic](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#scanorganic)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScanOrganicEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The stage of the scan
+    pub scan_type: OrganicScanType,
+
+    /// The organism scanned
+    #[serde(flatten)]
+    pub organism: Organism,
+
+    /// The address of the system
+    pub system_address: u64,
+
+    /// The id of the body within the system
+    pub body: u32,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The organic data sold for a species
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BioData {
+    /// The organism
+    #[serde(flatten)]
+    pub organism: Organism,
+    /// The value of the data
+    pub value: u64,
+    /// The bonus paid for being the first to log the species
+    pub bonus: u64,
+}
+
+impl BioData {
+
+    /// The total paid including the first logged bonus
+    pub fn total(&self) -> u64 {
+        self.value + self.bonus
+    }
+}
+
+/// Organic data has been sold to Vista Genomics
+///
+/// ```json
+/// { "timestamp":"2025-01-12T16:02:10Z", "event":"SellOrganicData", "MarketID":3221524992, "BioData":[ { "Genus":"$Codex_Ent_Bacterial_Genus_Name;", "Genus_Localised":"Bacterium", "Species":"$Codex_Ent_Bacterial_04_Name;", "Species_Localised":"Bacterium Informem", "Variant":"$Codex_Ent_Bacterial_04_Tin_Name;", "Variant_Localised":"Bacterium Informem - Yellow", "Value":8418000, "Bonus":33672000 } ] }
+/// ```
+///
+/// ReadTheDocs: [SellOrganicData](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#sellorganicdata)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SellOrganicDataEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The data sold
+    pub bio_data: Vec<BioData>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl SellOrganicDataEvent {
+
+    /// The total paid for all the data including bonuses
+    pub fn total(&self) -> u64 {
+        self.bio_data.iter().map(BioData::total).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_scan_organic_event() {
+        let json = r#"{"timestamp":"2025-01-12T15:20:44Z","ScanType":"Log","Genus":"$Codex_Ent_Bacterial_Genus_Name;","Genus_Localised":"Bacterium","Species":"$Codex_Ent_Bacterial_04_Name;","Species_Localised":"Bacterium Informem","Variant":"$Codex_Ent_Bacterial_04_Tin_Name;","Variant_Localised":"Bacterium Informem - Yellow","SystemAddress":3107509474002,"Body":12}"#;
+
+        let event: ScanOrganicEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-12T15:20:44Z"));
+        assert_eq!(event.scan_type, OrganicScanType::Log);
+        assert_eq!(event.organism.genus.localised_value, Some("Bacterium".to_string()));
+        assert_eq!(event.organism.species.value, "$Codex_Ent_Bacterial_04_Name;");
+        assert_eq!(event.organism.variant, Some(LocalisedValue::new("$Codex_Ent_Bacterial_04_Tin_Name;".to_string(), Some("Bacterium Informem - Yellow".to_string()))));
+        assert_eq!(event.body, 12);
+        assert!(event.extra.is_empty());
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_sell_organic_data_event() {
+        let json = r#"{ "timestamp":"2025-01-12T16:02:10Z", "MarketID":3221524992, "BioData":[ { "Genus":"$Codex_Ent_Bacterial_Genus_Name;", "Genus_Localised":"Bacterium", "Species":"$Codex_Ent_Bacterial_04_Name;", "Species_Localised":"Bacterium Informem", "Variant":"$Codex_Ent_Bacterial_04_Tin_Name;", "Variant_Localised":"Bacterium Informem - Yellow", "Value":8418000, "Bonus":33672000 }, { "Genus":"$Codex_Ent_Stratum_Genus_Name;", "Genus_Localised":"Stratum", "Species":"$Codex_Ent_Stratum_07_Name;", "Species_Localised":"Stratum Tectonicas", "Value":19010800, "Bonus":0 } ] }"#;
+
+        let event: SellOrganicDataEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.bio_data.len(), 2);
+        assert_eq!(event.bio_data[0].total(), 42090000);
+        assert_eq!(event.bio_data[1].organism.variant, None);
+        assert_eq!(event.total(), 61100800);
+        assert!(event.extra.is_empty());
+    }
+}
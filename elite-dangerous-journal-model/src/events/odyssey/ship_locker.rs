@@ -1,31 +1,40 @@
 use serde::Deserialize;
 use serde_with::serde_derive::Serialize;
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 use crate::events::odyssey::common::Item;
 
 /// Struct to represent data in a ShipLocker event
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct ShipLockerEvent {
-    /// The common event metadata
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
 
     /// List of items in the ShipLocker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<Item>>,
 
     /// List of components in the ship locker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub components: Option<Vec<Item>>,
 
     /// List of consumables in the ship locker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub consumables: Option<Vec<Item>>,
 
     /// List of data in the ship locker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<Vec<Item>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::events::common::LocalisedValue;
     use crate::events::odyssey::common::Item;
     use crate::events::odyssey::ship_locker::ShipLockerEvent;
     use crate::test_helper::serde_helpers::create_timestamp;
@@ -66,27 +75,27 @@ mod tests {
     fn test_ship_locker_event_with_items() {
         let timestamp_str = "2025-01-11T21:56:34Z";
         let timestamp = create_timestamp(timestamp_str);
-        let json = format!(r#"{{ "timestamp":"{timestamp_str}", "event":"ShipLocker", "Items":[ {{ "Name":"item1", "OwnerID": "F1111111", "MissionID":111111, "Count":3 }}, {{ "Name":"item2", "OwnerID": "F1111111", "MissionID":111111, "Count":2 }} ], "Components":[ {{ "Name":"component1", "OwnerID": "F1111111", "MissionID":111111, "Count":2 }} ], "Consumables":[  ], "Data":[  ] }}"#);
+        let json = format!(r#"{{ "timestamp":"{timestamp_str}", "event":"ShipLocker", "Items":[ {{ "Name":"weaponschematic", "Name_Localised":"Weapon Schematic", "OwnerID":0, "Count":3 }}, {{ "Name":"chemicalsample", "Name_Localised":"Chemical Sample", "OwnerID":0, "MissionID":998877665, "Count":2 }} ], "Components":[ {{ "Name":"graphene", "OwnerID":0, "Count":2 }} ], "Consumables":[  ], "Data":[  ] }}"#);
 
         let event: ShipLockerEvent = serde_json::from_str(&json).unwrap();
         println!("{:#?}", event);
 
         let item1 = Item{
-            name: "item1".to_string(),
-            owner_id: "F1111111".to_string(),
-            mission_id: Some(111111),
+            name: LocalisedValue::new("weaponschematic".to_string(), Some("Weapon Schematic".to_string())),
+            owner_id: 0,
+            mission_id: None,
             count: 3,
         };
         let item2 = Item{
-            name: "item2".to_string(),
-            owner_id: "F1111111".to_string(),
-            mission_id: Some(111111),
+            name: LocalisedValue::new("chemicalsample".to_string(), Some("Chemical Sample".to_string())),
+            owner_id: 0,
+            mission_id: Some(998877665),
             count: 2,
         };
         let component1 = Item{
-            name: "component1".to_string(),
-            owner_id: "F1111111".to_string(),
-            mission_id: Some(111111),
+            name: LocalisedValue::new("graphene".to_string(), None),
+            owner_id: 0,
+            mission_id: None,
             count: 2,
         };
 
@@ -0,0 +1,525 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+use crate::events::common::engineering::Ingredient;
+
+/// A weapon or tool fitted to a suit loadout
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "SuitLoadoutModuleSchema", into = "SuitLoadoutModuleSchema")]
+pub struct SuitLoadoutModule {
+    /// The slot the weapon is in e.g. ```PrimaryWeapon1```
+    pub slot_name: String,
+    /// The id of the weapon
+    pub suit_module_id: u64,
+    /// The weapon
+    pub module_name: LocalisedValue,
+    /// The grade of the weapon
+    pub class: u8,
+    /// The modifications fitted to the weapon
+    pub weapon_mods: Vec<String>,
+}
+
+impl From<SuitLoadoutModuleSchema> for SuitLoadoutModule {
+    fn from(value: SuitLoadoutModuleSchema) -> Self {
+        Self {
+            slot_name: value.slot_name,
+            suit_module_id: value.suit_module_id,
+            module_name: LocalisedValue::new(value.module_name, value.module_name_localised),
+            class: value.class,
+            weapon_mods: value.weapon_mods,
+        }
+    }
+}
+
+/// Schema for the suit loadout module due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct SuitLoadoutModuleSchema {
+    slot_name: String,
+    #[serde(rename = "SuitModuleID")]
+    suit_module_id: u64,
+    module_name: String,
+    #[serde(rename = "ModuleName_Localised", skip_serializing_if = "Option::is_none")]
+    module_name_localised: Option<String>,
+    class: u8,
+    #[serde(default)]
+    weapon_mods: Vec<String>,
+}
+
+impl From<SuitLoadoutModule> for SuitLoadoutModuleSchema {
+    fn from(value: SuitLoadoutModule) -> Self {
+        let (module_name, module_name_localised) = deconstruct_localised_value(value.module_name);
+        Self {
+            slot_name: value.slot_name,
+            suit_module_id: value.suit_module_id,
+            module_name,
+            module_name_localised,
+            class: value.class,
+            weapon_mods: value.weapon_mods,
+        }
+    }
+}
+
+/// A suit loadout has been selected (SuitLoadout) or created (CreateSuitLoadout)
+///
+/// ```json
+/// { "timestamp":"2025-01-11T21:58:02Z", "event":"SuitLoadout", "SuitID":1700217809818876, "SuitName":"utilitysuit_class1", "SuitName_Localised":"Maverick Suit", "SuitMods":[  ], "LoadoutID":4293000001, "LoadoutName":"Scavenger", "Modules":[ { "SlotName":"PrimaryWeapon1", "SuitModuleID":1700217863661544, "ModuleName":"wpn_m_assaultrifle_kinetic_fauto", "ModuleName_Localised":"Karma AR-50", "Class":1, "WeaponMods":[  ] } ] }
+/// ```
+///
+/// ReadTheDocs: [SuitLoadout](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#suitloadout)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "SuitLoadoutEventSchema", into = "SuitLoadoutEventSchema")]
+pub struct SuitLoadoutEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the suit
+    pub suit_id: u64,
+
+    /// The suit
+    pub suit_name: LocalisedValue,
+
+    /// The modifications fitted to the suit
+    pub suit_mods: Vec<String>,
+
+    /// The id of the loadout
+    pub loadout_id: u64,
+
+    /// The name given to the loadout
+    pub loadout_name: String,
+
+    /// The weapons in the loadout
+    pub modules: Vec<SuitLoadoutModule>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<SuitLoadoutEventSchema> for SuitLoadoutEvent {
+    fn from(value: SuitLoadoutEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            suit_id: value.suit_id,
+            suit_name: LocalisedValue::new(value.suit_name, value.suit_name_localised),
+            suit_mods: value.suit_mods,
+            loadout_id: value.loadout_id,
+            loadout_name: value.loadout_name,
+            modules: value.modules,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuitLoadoutEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    #[serde(rename = "SuitID")]
+    pub suit_id: u64,
+    pub suit_name: String,
+    #[serde(rename = "SuitName_Localised", skip_serializing_if = "Option::is_none")]
+    pub suit_name_localised: Option<String>,
+    #[serde(default)]
+    pub suit_mods: Vec<String>,
+    #[serde(rename = "LoadoutID")]
+    pub loadout_id: u64,
+    pub loadout_name: String,
+    #[serde(default)]
+    pub modules: Vec<SuitLoadoutModule>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<SuitLoadoutEvent> for SuitLoadoutEventSchema {
+    fn from(value: SuitLoadoutEvent) -> Self {
+        let (suit_name, suit_name_localised) = deconstruct_localised_value(value.suit_name);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            suit_id: value.suit_id,
+            suit_name,
+            suit_name_localised,
+            suit_mods: value.suit_mods,
+            loadout_id: value.loadout_id,
+            loadout_name: value.loadout_name,
+            modules: value.modules,
+        }
+    }
+}
+
+/// A suit has been bought
+///
+/// ```json
+/// { "timestamp":"2025-01-11T21:50:12Z", "event":"BuySuit", "Name":"UtilitySuit_Class1", "Name_Localised":"Maverick Suit", "Price":150000, "SuitID":1700217809818876, "SuitMods":[  ] }
+/// ```
+///
+/// ReadTheDocs: [BuySuit](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#buysuit)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "BuySuitEventSchema", into = "BuySuitEventSchema")]
+pub struct BuySuitEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The suit bought
+    pub name: LocalisedValue,
+
+    /// The price paid
+    pub price: u64,
+
+    /// The id of the suit
+    pub suit_id: u64,
+
+    /// The modifications fitted to the suit
+    pub suit_mods: Vec<String>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<BuySuitEventSchema> for BuySuitEvent {
+    fn from(value: BuySuitEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name: LocalisedValue::new(value.name, value.name_localised),
+            price: value.price,
+            suit_id: value.suit_id,
+            suit_mods: value.suit_mods,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuySuitEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub price: u64,
+    #[serde(rename = "SuitID")]
+    pub suit_id: u64,
+    #[serde(default)]
+    pub suit_mods: Vec<String>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<BuySuitEvent> for BuySuitEventSchema {
+    fn from(value: BuySuitEvent) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name,
+            name_localised,
+            price: value.price,
+            suit_id: value.suit_id,
+            suit_mods: value.suit_mods,
+        }
+    }
+}
+
+/// A hand weapon has been bought
+///
+/// ```json
+/// { "timestamp":"2025-01-11T21:51:30Z", "event":"BuyWeapon", "Name":"Wpn_M_AssaultRifle_Laser_FAuto", "Name_Localised":"TK Aphelion", "Price":125000, "SuitModuleID":1700217863661544, "Class":1, "WeaponMods":[  ] }
+/// ```
+///
+/// ReadTheDocs: [BuyWeapon](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#buyweapon)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "BuyWeaponEventSchema", into = "BuyWeaponEventSchema")]
+pub struct BuyWeaponEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The weapon bought
+    pub name: LocalisedValue,
+
+    /// The price paid
+    pub price: u64,
+
+    /// The id of the weapon
+    pub suit_module_id: u64,
+
+    /// The grade of the weapon
+    pub class: u8,
+
+    /// The modifications fitted to the weapon
+    pub weapon_mods: Vec<String>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<BuyWeaponEventSchema> for BuyWeaponEvent {
+    fn from(value: BuyWeaponEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name: LocalisedValue::new(value.name, value.name_localised),
+            price: value.price,
+            suit_module_id: value.suit_module_id,
+            class: value.class,
+            weapon_mods: value.weapon_mods,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuyWeaponEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub price: u64,
+    #[serde(rename = "SuitModuleID")]
+    pub suit_module_id: u64,
+    pub class: u8,
+    #[serde(default)]
+    pub weapon_mods: Vec<String>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<BuyWeaponEvent> for BuyWeaponEventSchema {
+    fn from(value: BuyWeaponEvent) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name,
+            name_localised,
+            price: value.price,
+            suit_module_id: value.suit_module_id,
+            class: value.class,
+            weapon_mods: value.weapon_mods,
+        }
+    }
+}
+
+/// A suit has been upgraded to the next grade
+///
+/// ```json
+/// { "timestamp":"2025-01-11T22:12:48Z", "event":"UpgradeSuit", "Name":"utilitysuit_class1", "Name_Localised":"Maverick Suit", "SuitID":1700217809818876, "Class":2, "Cost":0, "Resources":[ { "Name":"graphene", "Count":4 } ] }
+/// ```
+///
+/// ReadTheDocs: [UpgradeSuit](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#upgradesuit)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "UpgradeSuitEventSchema", into = "UpgradeSuitEventSchema")]
+pub struct UpgradeSuitEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The suit upgraded
+    pub name: LocalisedValue,
+
+    /// The id of the suit
+    pub suit_id: u64,
+
+    /// The grade of the suit after the upgrade
+    pub class: u8,
+
+    /// The credits paid
+    pub cost: u64,
+
+    /// The micro resources used
+    pub resources: Vec<Ingredient>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<UpgradeSuitEventSchema> for UpgradeSuitEvent {
+    fn from(value: UpgradeSuitEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name: LocalisedValue::new(value.name, value.name_localised),
+            suit_id: value.suit_id,
+            class: value.class,
+            cost: value.cost,
+            resources: value.resources,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpgradeSuitEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    #[serde(rename = "SuitID")]
+    pub suit_id: u64,
+    pub class: u8,
+    pub cost: u64,
+    #[serde(default)]
+    pub resources: Vec<Ingredient>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<UpgradeSuitEvent> for UpgradeSuitEventSchema {
+    fn from(value: UpgradeSuitEvent) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name,
+            name_localised,
+            suit_id: value.suit_id,
+            class: value.class,
+            cost: value.cost,
+            resources: value.resources,
+        }
+    }
+}
+
+/// A hand weapon has been upgraded to the next grade
+///
+/// ```json
+/// { "timestamp":"2025-01-11T22:14:05Z", "event":"UpgradeWeapon", "Name":"wpn_m_assaultrifle_kinetic_fauto", "Name_Localised":"Karma AR-50", "SuitModuleID":1700217863661544, "Class":2, "Cost":0, "Resources":[ { "Name":"weaponschematic", "Name_Localised":"Weapon Schematic", "Count":1 } ] }
+/// ```
+///
+/// ReadTheDocs: [UpgradeWeapon](https://elite-journal.readthedocs.io/en/latest/New%20in%20Odyssey/#upgradeweapon)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "UpgradeWeaponEventSchema", into = "UpgradeWeaponEventSchema")]
+pub struct UpgradeWeaponEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The weapon upgraded
+    pub name: LocalisedValue,
+
+    /// The id of the weapon
+    pub suit_module_id: u64,
+
+    /// The grade of the weapon after the upgrade
+    pub class: u8,
+
+    /// The credits paid
+    pub cost: u64,
+
+    /// The micro resources used
+    pub resources: Vec<Ingredient>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<UpgradeWeaponEventSchema> for UpgradeWeaponEvent {
+    fn from(value: UpgradeWeaponEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name: LocalisedValue::new(value.name, value.name_localised),
+            suit_module_id: value.suit_module_id,
+            class: value.class,
+            cost: value.cost,
+            resources: value.resources,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpgradeWeaponEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    #[serde(rename = "SuitModuleID")]
+    pub suit_module_id: u64,
+    pub class: u8,
+    pub cost: u64,
+    #[serde(default)]
+    pub resources: Vec<Ingredient>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<UpgradeWeaponEvent> for UpgradeWeaponEventSchema {
+    fn from(value: UpgradeWeaponEvent) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name,
+            name_localised,
+            suit_module_id: value.suit_module_id,
+            class: value.class,
+            cost: value.cost,
+            resources: value.resources,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_suit_loadout_event() {
+        let json = r#"{"timestamp":"2025-01-11T21:58:02Z","SuitID":1700217809818876,"SuitName":"utilitysuit_class1","SuitName_Localised":"Maverick Suit","SuitMods":[],"LoadoutID":4293000001,"LoadoutName":"Scavenger","Modules":[{"SlotName":"PrimaryWeapon1","SuitModuleID":1700217863661544,"ModuleName":"wpn_m_assaultrifle_kinetic_fauto","ModuleName_Localised":"Karma AR-50","Class":1,"WeaponMods":[]}]}"#;
+
+        let event: SuitLoadoutEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T21:58:02Z"));
+        assert_eq!(event.suit_name, LocalisedValue::new("utilitysuit_class1".to_string(), Some("Maverick Suit".to_string())));
+        assert_eq!(event.loadout_name, "Scavenger");
+        assert_eq!(event.modules[0].slot_name, "PrimaryWeapon1");
+        assert_eq!(event.modules[0].module_name.localised_value, Some("Karma AR-50".to_string()));
+        assert!(event.extra.is_empty());
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_buy_suit_and_weapon_events() {
+        let suit: BuySuitEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-11T21:50:12Z", "Name":"UtilitySuit_Class1", "Name_Localised":"Maverick Suit", "Price":150000, "SuitID":1700217809818876, "SuitMods":[  ] }"#).unwrap();
+        let weapon: BuyWeaponEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-11T21:51:30Z", "Name":"Wpn_M_AssaultRifle_Laser_FAuto", "Name_Localised":"TK Aphelion", "Price":125000, "SuitModuleID":1700217863661544, "Class":1, "WeaponMods":[  ] }"#).unwrap();
+
+        assert_eq!(suit.price, 150000);
+        assert_eq!(suit.suit_id, 1700217809818876);
+        assert!(suit.extra.is_empty());
+        assert_eq!(weapon.name.localised_value, Some("TK Aphelion".to_string()));
+        assert_eq!(weapon.class, 1);
+        assert!(weapon.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_upgrade_weapon_event() {
+        let json = r#"{ "timestamp":"2025-01-11T22:14:05Z", "Name":"wpn_m_assaultrifle_kinetic_fauto", "Name_Localised":"Karma AR-50", "SuitModuleID":1700217863661544, "Class":2, "Cost":0, "Resources":[ { "Name":"weaponschematic", "Name_Localised":"Weapon Schematic", "Count":1 } ] }"#;
+
+        let event: UpgradeWeaponEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.class, 2);
+        assert_eq!(event.resources[0].name.value, "weaponschematic");
+        assert_eq!(event.resources[0].count, 1);
+        assert!(event.extra.is_empty());
+    }
+}
@@ -10,4 +10,10 @@ pub mod receive_text;
 pub mod drone;
 
 /// Fuel resevoir replennished event
-pub mod reservoir_replenished;
\ No newline at end of file
+pub mod reservoir_replenished;
+
+/// Module info event and ModulesInfo.json file
+pub mod module_info;
+
+/// Synthesis event
+pub mod synthesis;
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 
 
 /// The type of drone/limpet
@@ -21,13 +21,16 @@ pub enum DroneType {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct LaunchDroneEvent {
     
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
     
     /// The type of drone/limpet launched
     #[serde(rename="Type")]
-    pub drone_type: DroneType
+    pub drone_type: DroneType,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 
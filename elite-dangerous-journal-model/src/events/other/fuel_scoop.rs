@@ -1,6 +1,6 @@
 
 use serde::{Deserialize, Serialize};
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 
 /// Emitted when scooping fuel from a star
 ///
@@ -19,14 +19,18 @@ use crate::events::common::EventMeta;
 #[serde(rename_all = "PascalCase")]
 pub struct FuelScoopEvent {
 
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
 
     /// The amount of fuel scooped
-    pub scooped: f32,
+    pub scooped: f64,
     /// The total fuel after scooping
-    pub total: f32,
+    pub total: f64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 #[cfg(test)]
 mod tests {
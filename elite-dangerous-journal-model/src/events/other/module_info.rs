@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// A module fitted to the ship with its power usage
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleInfoEntry {
+    /// The slot the module is fitted in
+    pub slot: String,
+    /// The internal name of the module
+    pub item: String,
+    /// The power draw of the module in MW
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<f64>,
+    /// The power priority group of the module
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+}
+
+/// Emitted when looking at the modules panel, the journal only contains the event metadata but the modules
+/// are written to the ModulesInfo.json file
+///
+/// Example (ModulesInfo.json):
+/// ```json
+/// {
+///   "timestamp": "2025-01-04T19:28:10Z",
+///   "event": "ModuleInfo",
+///   "Modules": [
+///     { "Slot": "MainEngines", "Item": "int_engine_size6_class5", "Power": 7.92, "Priority": 0 },
+///     { "Slot": "CargoHatch", "Item": "modularcargobaydoor", "Power": 0.6, "Priority": 2 }
+///   ]
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Other%20Events/#moduleinfo
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleInfoEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The modules fitted to the ship (only present in the ModulesInfo.json file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modules: Option<Vec<ModuleInfoEntry>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_modules_info_file() {
+        let json = r#"{ "timestamp":"2025-01-04T19:28:10Z", "event":"ModuleInfo", "Modules":[ { "Slot":"MainEngines", "Item":"int_engine_size6_class5", "Power":7.92, "Priority":0 }, { "Slot":"CargoHatch", "Item":"modularcargobaydoor", "Power":0.6, "Priority":2 } ] }"#;
+
+        let event: ModuleInfoEvent = serde_json::from_str(json).expect("Failed to deserialize ModuleInfoEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:28:10Z"));
+        let modules = event.modules.expect("Expected modules");
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[0].slot, "MainEngines");
+        assert_eq!(modules[0].item, "int_engine_size6_class5");
+        assert_eq!(modules[0].power, Some(7.92));
+        assert_eq!(modules[1].priority, Some(2));
+    }
+}
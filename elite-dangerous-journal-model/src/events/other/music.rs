@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 
 /// What this music event is for
 #[derive(Debug,Serialize, Deserialize, Clone, PartialEq)]
@@ -53,6 +53,9 @@ pub struct MusicEvent {
     /// The track that has been played
     pub music_track: Music,
 
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
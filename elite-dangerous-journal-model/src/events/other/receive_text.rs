@@ -1,11 +1,11 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{EventMeta, ExtraFields, LocalisedValue};
 
 /// Data for a recieve text event
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 #[serde(from = "ReceiveTextEventSchema", into = "ReceiveTextEventSchema")]
 pub struct ReceiveTextEvent {
-    /// The event meta data
+    /// The event metadata
     pub event_meta: EventMeta,
     /// The sender of the message
     pub from: LocalisedValue,
@@ -13,6 +13,8 @@ pub struct ReceiveTextEvent {
     pub message: LocalisedValue,
     /// The channel that received this message
     pub channel: Channel,
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<ReceiveTextEventSchema> for ReceiveTextEvent {
@@ -28,6 +30,7 @@ impl From<ReceiveTextEventSchema> for ReceiveTextEvent {
         };
         Self{
             event_meta: value.event_meta,
+            extra: value.extra,
             from,
             message,
             channel: value.channel,
@@ -54,27 +57,31 @@ impl From<ReceiveTextEventSchema> for ReceiveTextEvent {
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 #[serde(rename_all="PascalCase")]
 pub(crate) struct ReceiveTextEventSchema {
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
     /// Raw from string (could be a localised placeholder)
     pub from: String,
     /// If present gives the localised value for the from field
-    #[serde(rename = "From_Localised")]
+    #[serde(rename = "From_Localised", skip_serializing_if = "Option::is_none")]
     pub localised_from: Option<String>,
     /// Raw message string (could be a localised placeholder)
     pub message: String,
     /// If present gives the localised value for the message field
-    #[serde(rename = "Message_Localised")]
+    #[serde(rename = "Message_Localised", skip_serializing_if = "Option::is_none")]
     pub localised_message: Option<String>,
     /// The channel that received this message
     pub channel: Channel,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub(crate) extra: ExtraFields,
 }
 
 impl From<ReceiveTextEvent> for ReceiveTextEventSchema {
     fn from(value: ReceiveTextEvent) -> Self {
         Self{
             event_meta: value.event_meta,
+            extra: value.extra,
             from: value.from.value,
             localised_from: value.from.localised_value,
             message: value.message.value,
@@ -1,22 +1,25 @@
 use serde::Deserialize;
 use serde_with::serde_derive::Serialize;
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 
 /// The reservoir replenished event
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct ReservoirReplenishedEvent {
     
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
     
     /// The main fuel supply
-    pub fuel_main: f32,
+    pub fuel_main: f64,
     
     /// The reservoir supply
-    pub fuel_reservoir: f32,
+    pub fuel_reservoir: f64,
     
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 
@@ -37,6 +40,7 @@ mod tests {
             },
             fuel_main: 50.0,
             fuel_reservoir: 10.0,
+            extra: ExtraFields::new(),
         };
 
 
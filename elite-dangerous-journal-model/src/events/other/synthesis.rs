@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+use crate::events::common::engineering::Ingredient;
+
+/// Materials have been used to synthesise ammunition, fuel or a temporary boost
+///
+/// ```json
+/// { "timestamp":"2025-01-11T20:14:37Z", "event":"Synthesis", "Name":"FSD Basic", "Materials":[ { "Name":"vanadium", "Count":1 }, { "Name":"germanium", "Count":1 }, { "Name":"carbon", "Count":1 } ] }
+/// ```
+///
+/// ReadTheDocs: [Synthesis](https://elite-journal.readthedocs.io/en/latest/Other%20Events/#synthesis)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SynthesisEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the recipe synthesised
+    pub name: String,
+
+    /// The materials used
+    pub materials: Vec<Ingredient>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_synthesis_event() {
+        let json = r#"{ "timestamp":"2025-01-11T20:14:37Z", "Name":"FSD Basic", "Materials":[ { "Name":"vanadium", "Count":1 }, { "Name":"germanium", "Count":1 }, { "Name":"carbon", "Count":1 } ] }"#;
+
+        let event: SynthesisEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T20:14:37Z"));
+        assert_eq!(event.name, "FSD Basic");
+        assert_eq!(event.materials.len(), 3);
+        assert_eq!(event.materials[0].name.value, "vanadium");
+        assert_eq!(event.materials[0].name.localised_value, None);
+        assert!(event.extra.is_empty());
+    }
+}
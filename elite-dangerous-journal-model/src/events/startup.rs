@@ -6,4 +6,6 @@ pub mod game;
 pub mod material;
 
 /// Cargo startup event
-pub mod cargo;
\ No newline at end of file
+pub mod cargo;
+/// Loadout startup event
+pub mod loadout;
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{EventMeta, ExtraFields, LocalisedValue};
 
 /// The cargo item in the inventory
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -32,11 +32,11 @@ impl From<CargoItemSchema> for CargoItem {
 #[serde(rename_all = "PascalCase")]
 pub struct CargoItemSchema {
     pub name: String,
-    #[serde(rename = "Name_Localised")]
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
     pub name_localized: Option<String>,
     pub count: u32,
     pub stolen: u32,
-    #[serde(rename = "MissionID")]
+    #[serde(rename = "MissionID", skip_serializing_if = "Option::is_none")]
     pub mission_id: Option<u32>,
 }
 
@@ -67,7 +67,7 @@ pub enum CargoVessel {
 #[serde(rename_all = "PascalCase")]
 pub struct CargoEvent {
 
-    /// The meta data for the event
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
 
@@ -78,7 +78,12 @@ pub struct CargoEvent {
     pub count: u32,
 
     /// The Optional inventory (only supplied when inventory changes other than count of item)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inventory: Option<Vec<CargoItem>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 
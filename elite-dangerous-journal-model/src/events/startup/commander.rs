@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 
 /// Struct to represent the commander details, used in multiple events
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -34,6 +34,10 @@ pub struct CommanderEvent {
     /// Details about the commander
     #[serde(flatten)]
     pub commander: Commander,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Gives details about the current percent progress towards next ranks for the commander
@@ -80,6 +84,10 @@ pub struct CommanderProgressEvent {
     /// The current close quarter combat (PVP) ranking of the commander
     #[serde(rename = "CQC")]
     pub cqc: u8,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Gives details about the current reputation with the "superpowers"
@@ -103,13 +111,16 @@ pub struct CommanderReputationEvent {
     #[serde(flatten)]
     pub event_meta: EventMeta,
     /// The current reputation with the empire faction
-    pub empire: f32,
+    pub empire: f64,
     /// The current reputation with the federation faction
-    pub federation: f32,
+    pub federation: f64,
     /// The current reputation with the independent faction
-    pub independent: f32,
+    pub independent: f64,
     /// The current reputation with the alliance faction
-    pub alliance: f32
+    pub alliance: f64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Gives details about the current ranks for the commander
@@ -155,6 +166,9 @@ pub struct CommanderRankEvent {
     /// The current close quarter combat (PVP) ranking of the commander
     #[serde(rename = "CQC")]
     pub cqc: u8,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 
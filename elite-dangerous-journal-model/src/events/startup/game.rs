@@ -1,10 +1,10 @@
 
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 use crate::events::common::ShipMeta;
 
-/// A struct to provide game meta data
+/// A struct to provide game metadata
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct GameMeta {
     pub(crate) language: String,
@@ -54,7 +54,7 @@ pub enum GameMode {
 /// }
 /// ```
 ///
-/// **note**: Group is only specified if GameMode is ```Group```. Not currently validated
+/// **note**: Group is only specified if GameMode is ```Group```, this is validated in strict mode
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -81,15 +81,16 @@ pub struct LoadGameEvent {
     pub ship: ShipMeta,
 
     /// The current fuel level of the ship
-    pub fuel_level: f32,
+    pub fuel_level: f64,
 
     /// The fuel capacity of the ship
-    pub fuel_capacity: f32,
+    pub fuel_capacity: f64,
 
     /// Game mode type started
     pub game_mode: GameMode,
 
     /// Group name (only specified if game mode is ```Group```
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<String>,
 
     /// Credit user has
@@ -98,10 +99,13 @@ pub struct LoadGameEvent {
     /// Any loan user has
     pub loan: u64,
 
-    /// Game meta data
+    /// Game metadata
     #[serde(flatten)]
     pub game_meta: GameMeta,
 
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 
@@ -133,6 +137,10 @@ pub struct FileHeaderEvent {
     /// Game client meta dat
     #[serde(flatten)]
     pub game_meta: GameMeta,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
@@ -274,4 +282,14 @@ mod tests {
         assert_eq!(event.game_meta.build, "r308767/r0 ");
         assert_eq!(event.event_meta.timestamp, timestamp);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_deserialize_load_game_event_large_ship_id() {
+        let json = r#"{ "timestamp":"2025-01-04T19:27:09Z", "event":"LoadGame", "FID":"F00000000", "Commander":"ANON", "Horizons":true, "Odyssey":true, "Ship":"Anaconda", "ShipID":1024, "ShipName":"", "ShipIdent":"", "FuelLevel":32.000000, "FuelCapacity":32.000000, "GameMode":"Solo", "Credits":53038302, "Loan":0, "language":"English/UK", "gameversion":"4.0.0.1904", "build":"r308767/r0 " }"#;
+
+        let event: LoadGameEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.ship.ship_id, 1024);
+    }
+}
+
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields, ShipMeta};
+use crate::events::common::engineering::Engineering;
+use crate::events::common::module_id::ModuleId;
+
+/// The fuel tank capacities of the ship
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FuelCapacity {
+    /// The capacity of the main tank(s) in tons
+    pub main: f64,
+    /// The capacity of the reserve tank (used by the systems) in tons
+    pub reserve: f64,
+}
+
+/// A module fitted to the ship
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct LoadoutModule {
+    /// The slot the module is fitted in
+    pub slot: String,
+    /// The internal name of the module
+    pub item: ModuleId,
+    /// Is the module powered on
+    pub on: bool,
+    /// The power priority group of the module
+    pub priority: u8,
+    /// The health of the module, between 0 and 1
+    pub health: f64,
+    /// The value of the module, not present for modules that can't be sold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<u64>,
+    /// The ammo loaded in the clip
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ammo_in_clip: Option<u32>,
+    /// The ammo in reserve
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ammo_in_hopper: Option<u32>,
+    /// The engineering applied to the module
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engineering: Option<Engineering>,
+}
+
+/// The loadout of the current ship, written at start up and whenever the loadout changes
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:27:38Z", "event":"Loadout", "Ship":"anaconda", "ShipID":5, "ShipName":" ", "ShipIdent":"CO-28A", "HullValue":121080228, "ModulesValue":232814073, "HullHealth":1.000000, "UnladenMass":1090.399902, "CargoCapacity":192, "MaxJumpRange":45.689568, "FuelCapacity":{ "Main":32.000000, "Reserve":1.070000 }, "Rebuy":17694717, "Modules":[ { "Slot":"HugeHardpoint1", "Item":"hpt_beamlaser_gimbal_huge", "On":true, "Priority":0, "Health":1.000000, "Value":7434236 }, { "Slot":"TinyHardpoint4", "Item":"hpt_shieldbooster_size0_class5", "On":true, "Priority":0, "Health":1.000000, "Value":238850, "Engineering":{ "Engineer":"Felicity Farseer", "EngineerID":300100, "BlueprintID":128673780, "BlueprintName":"ShieldBooster_HeavyDuty", "Level":1, "Quality":1.000000, "Modifiers":[ { "Label":"Mass", "Value":7.000000, "OriginalValue":3.500000, "LessIsGood":1 } ] } } ] }
+/// ```
+///
+/// ReadTheDocs: [Loadout](https://elite-journal.readthedocs.io/en/latest/Startup/#loadout)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct LoadoutEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The ship the loadout is for
+    #[serde(flatten)]
+    pub ship: ShipMeta,
+
+    /// The value of the hull
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hull_value: Option<u64>,
+
+    /// The value of the fitted modules
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modules_value: Option<u64>,
+
+    /// The health of the hull, between 0 and 1
+    pub hull_health: f64,
+
+    /// The mass of the ship with no cargo or fuel
+    pub unladen_mass: f64,
+
+    /// The cargo capacity in tons
+    pub cargo_capacity: u32,
+
+    /// The maximum jump range with full fuel and no cargo
+    pub max_jump_range: f64,
+
+    /// The fuel tank capacities
+    pub fuel_capacity: FuelCapacity,
+
+    /// The cost of the rebuy
+    pub rebuy: u64,
+
+    /// Is the ship hot (wanted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hot: Option<bool>,
+
+    /// The modules fitted to the ship
+    pub modules: Vec<LoadoutModule>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_loadout_event() {
+        let json = r#"{ "timestamp":"2025-01-04T19:27:38Z", "ShipID":5, "Ship":"anaconda", "ShipName":" ", "ShipIdent":"CO-28A", "HullValue":121080228, "ModulesValue":232814073, "HullHealth":1.000000, "UnladenMass":1090.399902, "CargoCapacity":192, "MaxJumpRange":45.689568, "FuelCapacity":{ "Main":32.000000, "Reserve":1.070000 }, "Rebuy":17694717, "Modules":[ { "Slot":"LargeHardpoint1", "Item":"hpt_cannon_gimbal_medium", "On":true, "Priority":0, "AmmoInClip":5, "AmmoInHopper":100, "Health":1.000000, "Value":286960 }, { "Slot":"TinyHardpoint4", "Item":"hpt_shieldbooster_size0_class5", "On":true, "Priority":0, "Health":1.000000, "Value":238850, "Engineering":{ "Engineer":"Felicity Farseer", "EngineerID":300100, "BlueprintID":128673780, "BlueprintName":"ShieldBooster_HeavyDuty", "Level":1, "Quality":1.000000, "Modifiers":[ { "Label":"Mass", "Value":7.000000, "OriginalValue":3.500000, "LessIsGood":1 }, { "Label":"ShieldBoost", "Value":110.262009, "OriginalValue":60.000002, "LessIsGood":0 } ] } } ] }"#;
+
+        let event: LoadoutEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:27:38Z"));
+        assert_eq!(event.ship.ship, "anaconda");
+        assert_eq!(event.ship.ship_id, 5);
+        assert_eq!(event.hull_value, Some(121080228));
+        assert_eq!(event.unladen_mass, 1090.399902);
+        assert_eq!(event.cargo_capacity, 192);
+        assert_eq!(event.fuel_capacity, FuelCapacity { main: 32.0, reserve: 1.07 });
+        assert_eq!(event.rebuy, 17694717);
+        assert_eq!(event.modules.len(), 2);
+        assert_eq!(event.modules[0].item.display_name(), "Medium Gimballed Cannon");
+        assert_eq!(event.modules[0].ammo_in_clip, Some(5));
+        assert_eq!(event.modules[0].engineering, None);
+
+        let engineering = event.modules[1].engineering.as_ref().unwrap();
+        assert_eq!(engineering.engineer, Some("Felicity Farseer".to_string()));
+        assert_eq!(engineering.blueprint_name, "ShieldBooster_HeavyDuty");
+        assert_eq!(engineering.modifiers[0].label, "Mass");
+        assert_eq!(engineering.modifiers[0].original_value, Some(3.5));
+        assert_eq!(engineering.modifiers[1].value, Some(110.262009));
+        assert!(event.extra.is_empty());
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_loadout_event_large_ship_id() {
+        let json = r#"{ "timestamp":"2025-01-04T19:27:38Z", "ShipID":300, "Ship":"python_nx", "ShipName":"", "ShipIdent":"", "HullHealth":1.000000, "UnladenMass":450.000000, "CargoCapacity":0, "MaxJumpRange":20.185766, "FuelCapacity":{ "Main":32.000000, "Reserve":0.830000 }, "Rebuy":2994800, "Modules":[  ] }"#;
+
+        let event: LoadoutEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.ship.ship_id, 300);
+    }
+}
+
@@ -1,7 +1,7 @@
 
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{EventMeta, ExtraFields, LocalisedValue};
 
 /// The materials event give the current materials that the commander has in their inventory
 ///
@@ -67,6 +67,9 @@ pub struct MaterialsEvent {
     /// List of all the [Encoded Materials](https://elite-dangerous.fandom.com/wiki/Encoded_Materials) in the commanders inventory
     #[serde(default)]
     pub encoded: Vec<LocalisedMaterial>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// A [Raw Material](https://elite-dangerous.fandom.com/wiki/Raw_Materials) inventory count
@@ -1,4 +1,26 @@
 /// Contains structs to represent engineer events
 pub mod engineer;
 /// Contains structs to represent mission events
-pub mod missions;
\ No newline at end of file
+pub mod missions;
+/// Contains structs to represent the market event and Market.json file
+pub mod market;
+/// Contains structs to represent the outfitting event and Outfitting.json file
+pub mod outfitting;
+/// Contains structs to represent the shipyard event and Shipyard.json file
+pub mod shipyard;
+/// Contains structs to represent module buy, sell, storage and swap events
+pub mod modules;
+/// Contains structs to represent ship buy, sell, swap, transfer and storage events
+pub mod ships;
+/// Contains structs to represent repair, refuel and restock events
+pub mod restock;
+/// Contains structs to represent fine, bounty and voucher payment events
+pub mod payments;
+/// Contains structs to represent NPC crew events
+pub mod crew;
+/// Contains structs to represent engineer craft and contribution events
+pub mod engineer_craft;
+/// Contains structs to represent material trader events
+pub mod material_trade;
+/// Contains structs to represent technology broker events
+pub mod technology_broker;
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// An NPC crew member has been hired
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:25:03Z", "event":"CrewHire", "Name":"Margaret Parrish", "CrewID":236064708, "Faction":"Ngalinn Jet Boys", "Cost":15000, "CombatRank":1 }
+/// ```
+///
+/// ReadTheDocs: [CrewHire](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#crewhire)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CrewHireEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the crew member
+    pub name: String,
+
+    /// The id of the crew member
+    #[serde(rename = "CrewID")]
+    pub crew_id: u64,
+
+    /// The faction the crew member was hired from
+    pub faction: String,
+
+    /// The cost of hiring the crew member
+    pub cost: u64,
+
+    /// The combat rank of the crew member
+    pub combat_rank: u8,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// An NPC crew member has been fired
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:26:44Z", "event":"CrewFire", "Name":"Margaret Parrish", "CrewID":236064708 }
+/// ```
+///
+/// ReadTheDocs: [CrewFire](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#crewfire)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CrewFireEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the crew member
+    pub name: String,
+
+    /// The id of the crew member
+    #[serde(rename = "CrewID")]
+    pub crew_id: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// An NPC crew member has been given a role
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:25:30Z", "event":"CrewAssign", "Name":"Margaret Parrish", "CrewID":236064708, "Role":"Active" }
+/// ```
+///
+/// ReadTheDocs: [CrewAssign](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#crewassign)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CrewAssignEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the crew member
+    pub name: String,
+
+    /// The id of the crew member
+    #[serde(rename = "CrewID")]
+    pub crew_id: u64,
+
+    /// The role assigned e.g. ```Active``` or ```OnShoreLeave```
+    pub role: String,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_crew_hire_event() {
+        let json = r#"{ "timestamp":"2025-01-08T20:25:03Z", "Name":"Margaret Parrish", "CrewID":236064708, "Faction":"Ngalinn Jet Boys", "Cost":15000, "CombatRank":1 }"#;
+
+        let event: CrewHireEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-08T20:25:03Z"));
+        assert_eq!(event.name, "Margaret Parrish");
+        assert_eq!(event.crew_id, 236064708);
+        assert_eq!(event.cost, 15000);
+        assert_eq!(event.combat_rank, 1);
+        assert!(event.extra.is_empty());
+    }
+}
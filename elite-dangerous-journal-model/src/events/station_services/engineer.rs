@@ -4,7 +4,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::serde_as;
 use tracing::debug;
 use crate::events::station_services::engineer::EngineerData::{Multiple, Single};
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 
 /// The current state of the relation ship of the commander with this engineer
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -48,7 +48,7 @@ pub enum EngineerRank {
 ///       "Rank": 4
 ///     },
 ///
-/// **Note**: `Rank` and `RankProgress` are both only present when progress is unlocked, currently this is not validated
+/// **Note**: `Rank` and `RankProgress` are both only present when progress is unlocked, this is validated in strict mode
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Engineer {
@@ -58,23 +58,50 @@ pub struct Engineer {
     #[serde(rename = "EngineerID")]
     pub engineer_id: u32,
     /// Current progress with engineer
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub progress: Option<EngineerProgress>,
     /// Progress in current rank
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rank_progress: Option<u32>,
     /// Rank with enginner
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rank: Option<EngineerRank>,
 }
 
 /// The Engineer Event can be either a single engineer or a list of engineers, we therefore, use
 /// this enum to allow those differences
+///
+/// This is untagged so that it serializes back to the fields of the event when flattened
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(untagged)]
 pub enum EngineerData {
     /// This event contains a single engineer
     Single(Engineer),
     /// this event contains multiple engineers
+    #[serde(with = "engineers_field")]
     Multiple(Vec<Engineer>),
 }
 
+/// The multiple engineers are held in the ```Engineers``` field of the event
+mod engineers_field {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use crate::events::station_services::engineer::Engineer;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct EngineersField<T> {
+        engineers: T,
+    }
+
+    pub fn serialize<S: Serializer>(engineers: &[Engineer], serializer: S) -> Result<S::Ok, S::Error> {
+        EngineersField { engineers }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Engineer>, D::Error> {
+        Ok(EngineersField::<Vec<Engineer>>::deserialize(deserializer)?.engineers)
+    }
+}
+
 /// An event that represents the current relationship status with the engineers
 ///
 /// Example:
@@ -121,6 +148,9 @@ pub struct EngineerProgressEvent {
     /// The engineer data
     #[serde(flatten)]
     pub data: EngineerData,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl<'de> Deserialize<'de> for EngineerProgressEvent {
@@ -140,6 +170,8 @@ impl<'de> Deserialize<'de> for EngineerProgressEvent {
             rank_progress: Option<u32>,
             rank: Option<EngineerRank>,
             engineers: Option<Vec<Engineer>>,
+            #[serde(flatten)]
+            extra: ExtraFields,
         }
 
         let helper = EngineerHelper::deserialize(deserializer)?;
@@ -153,6 +185,7 @@ impl<'de> Deserialize<'de> for EngineerProgressEvent {
                 Ok(Self{
                     event_meta: helper.event_meta,
                     data: Multiple(helper.engineers.unwrap().clone()),
+                    extra: helper.extra,
                 })
             }
         } else {
@@ -167,7 +200,8 @@ impl<'de> Deserialize<'de> for EngineerProgressEvent {
                 };
                 Ok(Self{
                     event_meta: helper.event_meta,
-                    data: Single(engineer)
+                    data: Single(engineer),
+                    extra: helper.extra,
                 })
             } else {
                 Err(serde::de::Error::custom("Engineers not present and one of engineer_id, engineer or progress is not supplied"))
@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
+use crate::events::common::engineering::{Engineering, Ingredient};
+use crate::events::common::module_id::ModuleId;
+
+/// A blueprint has been applied to a module by an engineer
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:28:20Z", "event":"EngineerCraft", "Slot":"FrameShiftDrive", "Module":"int_hyperdrive_overcharge_size6_class5", "ApplyExperimentalEffect":"special_fsd_heavy", "Ingredients":[ { "Name":"disruptedwakeechoes", "Name_Localised":"Atypical Disrupted Wake Echoes", "Count":5 } ], "Engineer":"Elvira Martuuk", "EngineerID":300160, "BlueprintID":128673694, "BlueprintName":"FSD_LongRange", "Level":5, "Quality":1.000000, "ExperimentalEffect":"special_fsd_heavy", "ExperimentalEffect_Localised":"Mass Manager", "Modifiers":[ { "Label":"Mass", "Value":52.000000, "OriginalValue":40.000000, "LessIsGood":1 } ] }
+/// ```
+///
+/// ReadTheDocs: [EngineerCraft](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#engineercraft)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct EngineerCraftEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The slot of the module
+    pub slot: String,
+
+    /// The module engineered
+    pub module: ModuleId,
+
+    /// The experimental effect applied by this craft, only present when an experimental effect is being applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apply_experimental_effect: Option<String>,
+
+    /// The materials and commodities used
+    pub ingredients: Vec<Ingredient>,
+
+    /// The engineering of the module after the craft
+    #[serde(flatten)]
+    pub engineering: Engineering,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The type of contribution made to an engineer
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum ContributionType {
+    /// Commodities were given
+    Commodity,
+    /// Materials were given
+    Materials,
+    /// Credits were given
+    Credits,
+    /// Combat bonds were given
+    Bond,
+    /// Bounty vouchers were given
+    Bounty,
+    /// A contribution type that is not modelled
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Commodities, materials or credits have been given to an engineer to unlock them
+///
+/// ```json
+/// { "timestamp":"2025-01-11T19:59:14Z", "event":"EngineerContribution", "Engineer":"Elvira Martuuk", "EngineerID":300160, "Type":"Commodity", "Commodity":"soontillrelics", "Commodity_Localised":"Soontill Relics", "Quantity":2, "TotalQuantity":3 }
+/// ```
+///
+/// ReadTheDocs: [EngineerContribution](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#engineercontribution)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "EngineerContributionEventSchema", into = "EngineerContributionEventSchema")]
+pub struct EngineerContributionEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The name of the engineer
+    pub engineer: String,
+
+    /// The id of the engineer
+    pub engineer_id: u64,
+
+    /// The type of contribution
+    pub contribution_type: ContributionType,
+
+    /// The commodity given, only present for commodity contributions
+    pub commodity: Option<LocalisedValue>,
+
+    /// The material given, only present for material contributions
+    pub material: Option<LocalisedValue>,
+
+    /// The quantity given
+    pub quantity: u64,
+
+    /// The total quantity given to the engineer so far
+    pub total_quantity: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<EngineerContributionEventSchema> for EngineerContributionEvent {
+    fn from(value: EngineerContributionEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            engineer: value.engineer,
+            engineer_id: value.engineer_id,
+            contribution_type: value.contribution_type,
+            commodity: LocalisedValue::new_optional(value.commodity, value.commodity_localised),
+            material: LocalisedValue::new_optional(value.material, value.material_localised),
+            quantity: value.quantity,
+            total_quantity: value.total_quantity,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct EngineerContributionEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub engineer: String,
+    #[serde(rename = "EngineerID")]
+    pub engineer_id: u64,
+    #[serde(rename = "Type")]
+    pub contribution_type: ContributionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity: Option<String>,
+    #[serde(rename = "Commodity_Localised", skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub material: Option<String>,
+    #[serde(rename = "Material_Localised", skip_serializing_if = "Option::is_none")]
+    pub material_localised: Option<String>,
+    pub quantity: u64,
+    pub total_quantity: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<EngineerContributionEvent> for EngineerContributionEventSchema {
+    fn from(value: EngineerContributionEvent) -> Self {
+        let (commodity, commodity_localised) = deconstruct_optional_localised_value(value.commodity);
+        let (material, material_localised) = deconstruct_optional_localised_value(value.material);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            engineer: value.engineer,
+            engineer_id: value.engineer_id,
+            contribution_type: value.contribution_type,
+            commodity,
+            commodity_localised,
+            material,
+            material_localised,
+            quantity: value.quantity,
+            total_quantity: value.total_quantity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::common::module_id::ModuleCategory;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_engineer_craft_event() {
+        let json = r#"{"timestamp":"2025-01-04T19:28:20Z","Slot":"FrameShiftDrive","Module":"int_hyperdrive_overcharge_size6_class5","ApplyExperimentalEffect":"special_fsd_heavy","Ingredients":[{"Name":"disruptedwakeechoes","Name_Localised":"Atypical Disrupted Wake Echoes","Count":5},{"Name":"iron","Count":2}],"Engineer":"Elvira Martuuk","EngineerID":300160,"BlueprintID":128673694,"BlueprintName":"FSD_LongRange","Level":5,"Quality":1.0,"ExperimentalEffect":"special_fsd_heavy","ExperimentalEffect_Localised":"Mass Manager","Modifiers":[{"Label":"Mass","Value":52.0,"OriginalValue":40.0,"LessIsGood":1}]}"#;
+
+        let event: EngineerCraftEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:28:20Z"));
+        assert_eq!(event.slot, "FrameShiftDrive");
+        assert_eq!(event.module.category(), ModuleCategory::Internal);
+        assert_eq!(event.apply_experimental_effect, Some("special_fsd_heavy".to_string()));
+        assert_eq!(event.ingredients[0], Ingredient { name: LocalisedValue::new("disruptedwakeechoes".to_string(), Some("Atypical Disrupted Wake Echoes".to_string())), count: 5 });
+        assert_eq!(event.ingredients[1].name.value, "iron");
+        assert_eq!(event.engineering.blueprint_name, "FSD_LongRange");
+        assert_eq!(event.engineering.level, 5);
+        assert_eq!(event.engineering.modifiers.len(), 1);
+        assert!(event.extra.is_empty());
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_engineer_contribution_event() {
+        let json = r#"{ "timestamp":"2025-01-11T19:59:14Z", "Engineer":"Elvira Martuuk", "EngineerID":300160, "Type":"Commodity", "Commodity":"soontillrelics", "Commodity_Localised":"Soontill Relics", "Quantity":2, "TotalQuantity":3 }"#;
+
+        let event: EngineerContributionEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.engineer_id, 300160);
+        assert_eq!(event.contribution_type, ContributionType::Commodity);
+        assert_eq!(event.commodity, Some(LocalisedValue::new("soontillrelics".to_string(), Some("Soontill Relics".to_string()))));
+        assert_eq!(event.material, None);
+        assert_eq!(event.total_quantity, 3);
+        assert!(event.extra.is_empty());
+    }
+}
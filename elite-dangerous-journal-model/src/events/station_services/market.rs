@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// A commodity that is available in the market
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "MarketItemSchema", into = "MarketItemSchema")]
+pub struct MarketItem {
+    /// The internal id of the commodity
+    pub id: u64,
+    /// The name of the commodity
+    pub name: LocalisedValue,
+    /// The category of the commodity
+    pub category: LocalisedValue,
+    /// The price to buy from the market
+    pub buy_price: u64,
+    /// The price when selling to the market
+    pub sell_price: u64,
+    /// The galactic average price
+    pub mean_price: u64,
+    /// The stock level (0 - 3)
+    pub stock_bracket: u8,
+    /// The demand level (0 - 3)
+    pub demand_bracket: u8,
+    /// The amount in stock
+    pub stock: u64,
+    /// The amount in demand
+    pub demand: u64,
+    /// Is the station a consumer of this commodity
+    pub consumer: bool,
+    /// Is the station a producer of this commodity
+    pub producer: bool,
+    /// Is the commodity a rare good
+    pub rare: bool,
+}
+
+impl From<MarketItemSchema> for MarketItem {
+    /// Convert from MarketItemSchema
+    fn from(value: MarketItemSchema) -> Self {
+        Self {
+            id: value.id,
+            name: LocalisedValue::new(value.name, value.name_localised),
+            category: LocalisedValue::new(value.category, value.category_localised),
+            buy_price: value.buy_price,
+            sell_price: value.sell_price,
+            mean_price: value.mean_price,
+            stock_bracket: value.stock_bracket,
+            demand_bracket: value.demand_bracket,
+            stock: value.stock,
+            demand: value.demand,
+            consumer: value.consumer,
+            producer: value.producer,
+            rare: value.rare,
+        }
+    }
+}
+
+/// As the market item contains localised values we need an intermediary struct
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MarketItemSchema {
+    #[serde(rename = "id")]
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub category: String,
+    #[serde(rename = "Category_Localised", skip_serializing_if = "Option::is_none")]
+    pub category_localised: Option<String>,
+    pub buy_price: u64,
+    pub sell_price: u64,
+    pub mean_price: u64,
+    pub stock_bracket: u8,
+    pub demand_bracket: u8,
+    pub stock: u64,
+    pub demand: u64,
+    pub consumer: bool,
+    pub producer: bool,
+    pub rare: bool,
+}
+
+impl From<MarketItem> for MarketItemSchema {
+    /// Convert from MarketItem
+    fn from(value: MarketItem) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        let (category, category_localised) = deconstruct_localised_value(value.category);
+        Self {
+            id: value.id,
+            name,
+            name_localised,
+            category,
+            category_localised,
+            buy_price: value.buy_price,
+            sell_price: value.sell_price,
+            mean_price: value.mean_price,
+            stock_bracket: value.stock_bracket,
+            demand_bracket: value.demand_bracket,
+            stock: value.stock,
+            demand: value.demand,
+            consumer: value.consumer,
+            producer: value.producer,
+            rare: value.rare,
+        }
+    }
+}
+
+/// Emitted when accessing the commodity market, the journal only contains the station details but the full
+/// list of commodities is written to the Market.json file
+///
+/// Example (Market.json):
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T16:05:12Z",
+///   "event": "Market",
+///   "MarketID": 3228342528,
+///   "StationName": "Hickam Survey",
+///   "StationType": "Outpost",
+///   "StarSystem": "Ngalinn",
+///   "Items": [
+///     {
+///       "id": 128049152,
+///       "Name": "$platinum_name;",
+///       "Name_Localised": "Platinum",
+///       "Category": "$MARKET_category_metals;",
+///       "Category_Localised": "Metals",
+///       "BuyPrice": 0,
+///       "SellPrice": 54320,
+///       "MeanPrice": 58263,
+///       "StockBracket": 0,
+///       "DemandBracket": 2,
+///       "Stock": 0,
+///       "Demand": 412,
+///       "Consumer": true,
+///       "Producer": false,
+///       "Rare": false
+///     }
+///   ]
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Station%20Services/#market
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MarketEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The type of the station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station_type: Option<String>,
+
+    /// The name of the system
+    pub star_system: String,
+
+    /// Who can dock if the station is a fleet carrier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier_docking_access: Option<String>,
+
+    /// The commodities in the market (only present in the Market.json file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<MarketItem>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_market_file_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T16:05:12Z", "MarketID":3228342528, "StationName":"Hickam Survey", "StationType":"Outpost", "StarSystem":"Ngalinn", "Items":[ { "id":128049152, "Name":"$platinum_name;", "Name_Localised":"Platinum", "Category":"$MARKET_category_metals;", "Category_Localised":"Metals", "BuyPrice":0, "SellPrice":54320, "MeanPrice":58263, "StockBracket":0, "DemandBracket":2, "Stock":0, "Demand":412, "Consumer":true, "Producer":false, "Rare":false } ] }"#;
+
+        let event: MarketEvent = serde_json::from_str(json).expect("Failed to deserialize MarketEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T16:05:12Z"));
+        assert_eq!(event.market_id, 3228342528);
+        assert_eq!(event.station_type, Some("Outpost".to_string()));
+        let items = event.items.clone().expect("Expected market items");
+        assert_eq!(items[0].name, LocalisedValue::new("$platinum_name;".to_string(), Some("Platinum".to_string())));
+        assert_eq!(items[0].sell_price, 54320);
+        assert_eq!(items[0].demand, 412);
+        assert!(items[0].consumer);
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize MarketEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
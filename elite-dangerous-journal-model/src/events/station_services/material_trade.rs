@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+use crate::events::exploration::material_collected::MaterialCategory;
+
+/// The type of material trader
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum MaterialTraderType {
+    /// Raw material trader
+    Raw,
+    /// Manufactured material trader
+    Manufactured,
+    /// Encoded data trader
+    Encoded,
+    /// A trader type that is not modelled
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// One side of a material trade
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "TradedMaterialSchema", into = "TradedMaterialSchema")]
+pub struct TradedMaterial {
+    /// The material
+    pub material: LocalisedValue,
+    /// The category of the material
+    pub category: MaterialCategory,
+    /// The quantity of the material
+    pub quantity: u32,
+}
+
+impl From<TradedMaterialSchema> for TradedMaterial {
+    fn from(value: TradedMaterialSchema) -> Self {
+        Self {
+            material: LocalisedValue::new(value.material, value.material_localised),
+            category: value.category,
+            quantity: value.quantity,
+        }
+    }
+}
+
+/// Schema for the traded material due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct TradedMaterialSchema {
+    material: String,
+    #[serde(rename = "Material_Localised", skip_serializing_if = "Option::is_none")]
+    material_localised: Option<String>,
+    category: MaterialCategory,
+    quantity: u32,
+}
+
+impl From<TradedMaterial> for TradedMaterialSchema {
+    fn from(value: TradedMaterial) -> Self {
+        let (material, material_localised) = deconstruct_localised_value(value.material);
+        Self {
+            material,
+            material_localised,
+            category: value.category,
+            quantity: value.quantity,
+        }
+    }
+}
+
+/// Materials have been exchanged at a material trader
+///
+/// ```json
+/// { "timestamp":"2025-01-11T20:02:11Z", "event":"MaterialTrade", "MarketID":3221636096, "TraderType":"manufactured", "Paid":{ "Material":"heatresistantceramics", "Material_Localised":"Heat Resistant Ceramics", "Category":"Manufactured", "Quantity":6 }, "Received":{ "Material":"fedcorecomposites", "Material_Localised":"Core Dynamics Composites", "Category":"Manufactured", "Quantity":1 } }
+/// ```
+///
+/// ReadTheDocs: [MaterialTrade](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#materialtrade)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MaterialTradeEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The type of trader
+    pub trader_type: MaterialTraderType,
+
+    /// The material given to the trader
+    pub paid: TradedMaterial,
+
+    /// The material received from the trader
+    pub received: TradedMaterial,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_material_trade_event() {
+        let json = r#"{"timestamp":"2025-01-11T20:02:11Z","MarketID":3221636096,"TraderType":"manufactured","Paid":{"Material":"heatresistantceramics","Material_Localised":"Heat Resistant Ceramics","Category":"Manufactured","Quantity":6},"Received":{"Material":"fedcorecomposites","Material_Localised":"Core Dynamics Composites","Category":"Manufactured","Quantity":1}}"#;
+
+        let event: MaterialTradeEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T20:02:11Z"));
+        assert_eq!(event.trader_type, MaterialTraderType::Manufactured);
+        assert_eq!(event.paid.material.value, "heatresistantceramics");
+        assert_eq!(event.paid.quantity, 6);
+        assert_eq!(event.received.category, MaterialCategory::Manufactured);
+        assert_eq!(event.received.quantity, 1);
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
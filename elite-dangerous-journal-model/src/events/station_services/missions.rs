@@ -12,4 +12,6 @@ pub mod mission_completed;
 pub mod common;
 
 /// Mission failed event
-pub mod mission_failed;
\ No newline at end of file
+pub mod mission_failed;
+/// The list of missions written at startup
+pub mod mission_list;
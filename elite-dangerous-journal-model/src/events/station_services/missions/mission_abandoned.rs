@@ -1,11 +1,11 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{EventMeta, ExtraFields, LocalisedValue};
 
 /// Event raised when fmission is abandoned
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(from = "MissionAbandonedEventSchema", into = "MissionAbandonedEventSchema")]
 pub struct MissionAbandonedEvent {
-    /// Event meta data
+    /// The event metadata
     pub event_meta: EventMeta,
     
     /// The name of the mission
@@ -13,6 +13,8 @@ pub struct MissionAbandonedEvent {
     
     /// The mission id
     pub mission_id: u64,
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<MissionAbandonedEventSchema> for MissionAbandonedEvent {
@@ -21,6 +23,7 @@ impl From<MissionAbandonedEventSchema> for MissionAbandonedEvent {
         let name = LocalisedValue::new(value.name, Some(value.localised_name));
         MissionAbandonedEvent {
             event_meta: value.event_meta,
+            extra: value.extra,
             name,
             mission_id: value.mission_id,
         }
@@ -31,7 +34,7 @@ impl From<MissionAbandonedEventSchema> for MissionAbandonedEvent {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct MissionAbandonedEventSchema {
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
     
@@ -44,12 +47,16 @@ pub struct MissionAbandonedEventSchema {
     /// The mission id
     #[serde(rename = "MissionID")]
     pub mission_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl From<MissionAbandonedEvent> for MissionAbandonedEventSchema {
     fn from(value: MissionAbandonedEvent) -> Self {
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             name: value.name.value,
             localised_name: value.name.localised_value.unwrap_or_default(),
             mission_id: value.mission_id,
@@ -83,6 +90,7 @@ mod tests {
             },
             name: LocalisedValue::new("Mission_Name".to_string(), Some("Localised Mission Name".to_string())),
             mission_id: 123456789,
+            extra: ExtraFields::new(),
         };
 
         let json = serde_json::to_string(&event).expect("Failed to serialize MissionAbandonedEvent");
@@ -1,6 +1,6 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
-use crate::events::common::{deconstruct_optional_localised_value, EventMeta, LocalisedValue};
+use crate::events::common::{deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
 use crate::events::station_services::missions::common::MissionEffect;
 
 /// Enum for passenger type
@@ -21,7 +21,7 @@ pub enum PassengerType {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(from = "MissionAcceptedEventSchema", into = "MissionAcceptedEventSchema")]
 pub struct MissionAcceptedEvent {
-    /// The event meta data
+    /// The event metadata
     pub event_meta: EventMeta,
 
     /// The issuing faction
@@ -92,6 +92,15 @@ pub struct MissionAcceptedEvent {
 
     /// The type of passenger
     pub passenger_type: Option<PassengerType>,
+
+    /// Is this a wing mission
+    pub wing: Option<bool>,
+
+    /// The reward for completing the mission
+    pub reward: Option<u64>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<MissionAcceptedEventSchema> for MissionAcceptedEvent {
@@ -105,6 +114,7 @@ impl From<MissionAcceptedEventSchema> for MissionAcceptedEvent {
         let target_type = LocalisedValue::new_optional(value.target_type, value.target_type_localised);
         Self {
             event_meta: EventMeta { timestamp: value.event_meta.timestamp },
+            extra: value.extra,
             faction: value.faction,
             name: LocalisedValue { value: value.name, localised_value: Some(value.localised_name)},
             mission_id: value.mission_id,
@@ -128,6 +138,8 @@ impl From<MissionAcceptedEventSchema> for MissionAcceptedEvent {
             passenger_vips: value.passenger_vips,
             passenger_wanted: value.passenger_wanted,
             passenger_type: value.passenger_type,
+            wing: value.wing,
+            reward: value.reward,
         }
     }
 }
@@ -137,7 +149,7 @@ impl From<MissionAcceptedEventSchema> for MissionAcceptedEvent {
 #[serde(rename_all = "PascalCase")]
 pub struct MissionAcceptedEventSchema {
 
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
 
@@ -240,6 +252,17 @@ pub struct MissionAcceptedEventSchema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub passenger_type: Option<PassengerType>,
 
+    /// Is this a wing mission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wing: Option<bool>,
+
+    /// The reward for completing the mission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reward: Option<u64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl From<MissionAcceptedEvent> for MissionAcceptedEventSchema {
@@ -254,6 +277,7 @@ impl From<MissionAcceptedEvent> for MissionAcceptedEventSchema {
 
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             faction: value.faction,
             name: value.name.value,
             localised_name: value.name.localised_value.unwrap_or_default(),
@@ -280,6 +304,8 @@ impl From<MissionAcceptedEvent> for MissionAcceptedEventSchema {
             passenger_vips: value.passenger_vips,
             passenger_wanted: value.passenger_wanted,
             passenger_type: value.passenger_type,
+            wing: value.wing,
+            reward: value.reward,
         }
     }
 }
@@ -324,6 +350,9 @@ mod tests {
             passenger_vips: Some(true),
             passenger_wanted: Some(false),
             passenger_type: Some(PassengerType::Tourist),
+            wing: Some(false),
+            reward: Some(50000),
+            extra: ExtraFields::new(),
         };
 
         // Act
@@ -391,6 +420,9 @@ mod tests {
             passenger_vips: None,
             passenger_wanted: None,
             passenger_type: None,
+            wing: None,
+            reward: None,
+            extra: ExtraFields::new(),
         };
         
         // Act
@@ -2,14 +2,14 @@
 pub mod components;
 
 use serde::{Deserialize, Serialize};
-use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, EventMeta, LocalisedValue};
+use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
 use crate::events::station_services::missions::mission_completed::components::{CommodityReward, FactionEffectEntry, MaterialReward};
 
 /// Mission completed event
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(from = "MissionCompletedEventSchema", into = "MissionCompletedEventSchema")]
 pub struct MissionCompletedEvent {
-    /// Event meta data
+    /// The event metadata
     pub event_meta: EventMeta,
 
     /// The faction of the mission giver
@@ -65,6 +65,9 @@ pub struct MissionCompletedEvent {
 
     /// Faction effects
     pub faction_effects: Option<Vec<FactionEffectEntry>>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<MissionCompletedEventSchema> for MissionCompletedEvent {
@@ -72,6 +75,7 @@ impl From<MissionCompletedEventSchema> for MissionCompletedEvent {
     fn from(value: MissionCompletedEventSchema) -> Self {
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             faction: value.faction,
             name: LocalisedValue::new(value.name, Some(value.localised_name)),
             mission_id: value.mission_id,
@@ -99,7 +103,7 @@ impl From<MissionCompletedEventSchema> for MissionCompletedEvent {
 #[serde(rename_all = "PascalCase")]
 pub struct MissionCompletedEventSchema {
     
-    /// Event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
     
@@ -117,58 +121,76 @@ pub struct MissionCompletedEventSchema {
     pub mission_id: u64,
     
     /// The commodity involved 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub commodity: Option<String>,
     
     /// Localised commodity name
-    #[serde(rename = "Commodity_Localised")]
+    #[serde(rename = "Commodity_Localised", skip_serializing_if = "Option::is_none")]
     pub localised_commodity: Option<String>,
     
     /// The commodity count
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<u64>,
     
     /// The destination system
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub destination_system: Option<String>,
     
     /// The destination station
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub destination_station: Option<String>,
     
     /// The destination settlement
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub destination_settlement: Option<String>,
     
     /// The target
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
     
     /// The target type
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target_type: Option<String>,
     
     /// The target type localised
-    #[serde(rename = "TargetType_Localised")]
+    #[serde(rename = "TargetType_Localised", skip_serializing_if = "Option::is_none")]
     pub target_type_localised: Option<String>,
     
     /// The target faction
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target_faction: Option<String>,
     
     /// The reward
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reward: Option<u64>,
     
     /// The donation
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub donation: Option<String>,
     
     /// The donated amount
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub donated: Option<u64>,
     
     /// Permits awarded
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub permits_awarded: Option<Vec<String>>,
     
     /// Material rewards from the mission
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub materials_reward: Option<Vec<MaterialReward>>,
     
     /// Comodity rewards from the mission
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub commodity_reward: Option<Vec<CommodityReward>>,
     
     /// Faction effects
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub faction_effects: Option<Vec<FactionEffectEntry>>,
     
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl From<MissionCompletedEvent> for MissionCompletedEventSchema {
@@ -179,6 +201,7 @@ impl From<MissionCompletedEvent> for MissionCompletedEventSchema {
         let (target_type, target_type_localised) = deconstruct_optional_localised_value(value.target_type);
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             faction: value.faction,
             name,
             localised_name: localised_name.unwrap_or_default(),
@@ -1,11 +1,11 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{EventMeta, ExtraFields, LocalisedValue};
 
 /// The event raised for mission failed
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(from = "MissionFailedEventSchema", into = "MissionFailedEventSchema")]
 pub struct MissionFailedEvent {
-    /// The event meta data
+    /// The event metadata
     pub event_meta: EventMeta,
 
     /// TGhe name of the mission
@@ -16,6 +16,9 @@ pub struct MissionFailedEvent {
     
     /// Optional fine
     pub fine: Option<u64>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<MissionFailedEventSchema> for MissionFailedEvent {
@@ -24,6 +27,7 @@ impl From<MissionFailedEventSchema> for MissionFailedEvent {
     fn from(value: MissionFailedEventSchema) -> Self {
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             name: LocalisedValue::new(value.name, Some(value.localised_name)),
             mission_id: value.mission_id,
             fine: value.fine,
@@ -36,7 +40,7 @@ impl From<MissionFailedEventSchema> for MissionFailedEvent {
 #[serde(rename_all = "PascalCase")]
 pub struct MissionFailedEventSchema {
     
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
     
@@ -51,14 +55,19 @@ pub struct MissionFailedEventSchema {
     pub mission_id: u64,
     
     /// Optional fine
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fine: Option<u64>,
     
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl From<MissionFailedEvent> for MissionFailedEventSchema {
     fn from(value: MissionFailedEvent) -> Self {
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             name: value.name.value,
             localised_name: value.name.localised_value.unwrap(),
             mission_id: value.mission_id,
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// A mission in the mission list
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MissionSummary {
+    /// The id of the mission
+    #[serde(rename = "MissionID")]
+    pub mission_id: u64,
+    /// The internal name of the mission
+    pub name: String,
+    /// Is this a passenger mission
+    pub passenger_mission: bool,
+    /// The number of seconds remaining until the mission expires, zero for missions that are no longer active
+    pub expires: u64,
+}
+
+/// The missions the commander has, written at startup
+///
+/// ```json
+/// { "timestamp":"2025-01-04T18:58:53Z", "event":"Missions", "Active":[ { "MissionID":998877665, "Name":"Mission_Courier_name", "PassengerMission":false, "Expires":82040 } ], "Failed":[  ], "Complete":[  ] }
+/// ```
+///
+/// ReadTheDocs: [Missions](https://elite-journal.readthedocs.io/en/latest/Startup/#missions)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MissionsEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The missions in progress
+    pub active: Vec<MissionSummary>,
+
+    /// The missions that have failed
+    pub failed: Vec<MissionSummary>,
+
+    /// The missions that are complete but have not been handed in
+    pub complete: Vec<MissionSummary>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_missions_event() {
+        let json = r#"{ "timestamp":"2025-01-04T18:58:53Z", "Active":[ { "MissionID":998877665, "Name":"Mission_Courier_name", "PassengerMission":false, "Expires":82040 } ], "Failed":[  ], "Complete":[  ] }"#;
+
+        let event: MissionsEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T18:58:53Z"));
+        assert_eq!(event.active, vec![MissionSummary { mission_id: 998877665, name: "Mission_Courier_name".to_string(), passenger_mission: false, expires: 82040 }]);
+        assert!(event.failed.is_empty());
+        assert!(event.complete.is_empty());
+        assert!(event.extra.is_empty());
+    }
+}
@@ -1,12 +1,12 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{EventMeta, ExtraFields, LocalisedValue};
 
 /// The mission redirected event
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(from = "MissionRedirectedEventSchema", into = "MissionRedirectedEventSchema")]
 pub struct MissionRedirectedEvent {
     
-    /// The event meta data
+    /// The event metadata
     pub event_meta: EventMeta,
     
     /// The mission id
@@ -26,6 +26,8 @@ pub struct MissionRedirectedEvent {
     
     /// The old destination station
     pub old_destination_station: String,
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<MissionRedirectedEventSchema> for MissionRedirectedEvent {
@@ -34,6 +36,7 @@ impl From<MissionRedirectedEventSchema> for MissionRedirectedEvent {
     fn from(schema: MissionRedirectedEventSchema) -> Self {
         Self {
             event_meta: schema.event_meta,
+            extra: schema.extra,
             mission_id: schema.mission_id,
             name: LocalisedValue::new(schema.name, Some(schema.localised_name)),
             new_destination_system: schema.new_destination_system,
@@ -49,7 +52,7 @@ impl From<MissionRedirectedEventSchema> for MissionRedirectedEvent {
 #[serde(rename_all = "PascalCase")]
 pub struct MissionRedirectedEventSchema {
     
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
 
@@ -74,6 +77,10 @@ pub struct MissionRedirectedEventSchema {
 
     /// The old destination station
     pub old_destination_station: String,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl From<MissionRedirectedEvent> for MissionRedirectedEventSchema {
@@ -84,6 +91,7 @@ impl From<MissionRedirectedEvent> for MissionRedirectedEventSchema {
         
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             mission_id: value.mission_id,
             name: value.name.value,
             localised_name: value.name.localised_value.unwrap(),
@@ -126,6 +134,7 @@ mod tests {
             new_destination_station: "New Destination Station".to_string(),
             old_destination_system: "Old Destination System".to_string(),
             old_destination_station: "Old Destination Station".to_string(),
+            extra: ExtraFields::new(),
         };
         
         let json = serde_json::to_string(&event).expect("Failed to serialize MissionRedirectedEvent");
@@ -0,0 +1,896 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// A module has been bought in outfitting, the module it replaced is either sold or stored
+///
+/// ```json
+/// { "timestamp":"2025-01-08T19:21:37Z", "event":"ModuleBuy", "Slot":"MediumHardpoint2", "SellItem":"$hpt_pulselaser_fixed_medium_name;", "SellItem_Localised":"Pulse Laser", "SellPrice":17600, "BuyItem":"$hpt_multicannon_gimbal_medium_name;", "BuyItem_Localised":"Multi-Cannon", "MarketID":3223343616, "BuyPrice":57000, "Ship":"krait_mkii", "ShipID":7 }
+/// ```
+///
+/// ReadTheDocs: [ModuleBuy](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#modulebuy)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "ModuleBuyEventSchema", into = "ModuleBuyEventSchema")]
+pub struct ModuleBuyEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The slot the module was fitted in
+    pub slot: String,
+
+    /// The module bought
+    pub buy_item: LocalisedValue,
+
+    /// The price paid for the module
+    pub buy_price: u64,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The module that was sold to make way for the new one
+    pub sell_item: Option<LocalisedValue>,
+
+    /// The price the replaced module was sold for
+    pub sell_price: Option<u64>,
+
+    /// The module that was stored to make way for the new one
+    pub stored_item: Option<LocalisedValue>,
+
+    /// The ship type
+    pub ship: String,
+
+    /// The id of the ship
+    pub ship_id: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleBuyEventSchema> for ModuleBuyEvent {
+    fn from(value: ModuleBuyEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            slot: value.slot,
+            buy_item: LocalisedValue::new(value.buy_item, value.buy_item_localised),
+            buy_price: value.buy_price,
+            market_id: value.market_id,
+            sell_item: LocalisedValue::new_optional(value.sell_item, value.sell_item_localised),
+            sell_price: value.sell_price,
+            stored_item: LocalisedValue::new_optional(value.stored_item, value.stored_item_localised),
+            ship: value.ship,
+            ship_id: value.ship_id,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleBuyEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub slot: String,
+    pub buy_item: String,
+    #[serde(rename = "BuyItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub buy_item_localised: Option<String>,
+    pub buy_price: u64,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_item: Option<String>,
+    #[serde(rename = "SellItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub sell_item_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_price: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stored_item: Option<String>,
+    #[serde(rename = "StoredItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub stored_item_localised: Option<String>,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleBuyEvent> for ModuleBuyEventSchema {
+    fn from(value: ModuleBuyEvent) -> Self {
+        let (buy_item, buy_item_localised) = deconstruct_localised_value(value.buy_item);
+        let (sell_item, sell_item_localised) = deconstruct_optional_localised_value(value.sell_item);
+        let (stored_item, stored_item_localised) = deconstruct_optional_localised_value(value.stored_item);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            slot: value.slot,
+            buy_item,
+            buy_item_localised,
+            buy_price: value.buy_price,
+            market_id: value.market_id,
+            sell_item,
+            sell_item_localised,
+            sell_price: value.sell_price,
+            stored_item,
+            stored_item_localised,
+            ship: value.ship,
+            ship_id: value.ship_id,
+        }
+    }
+}
+
+/// A fitted module has been sold in outfitting
+///
+/// ```json
+/// { "timestamp":"2025-01-08T19:23:02Z", "event":"ModuleSell", "MarketID":3223343616, "Slot":"Slot06_Size2", "SellItem":"$int_detailedsurfacescanner_tiny_name;", "SellItem_Localised":"Detailed Surface Scanner", "SellPrice":250000, "Ship":"krait_mkii", "ShipID":7 }
+/// ```
+///
+/// ReadTheDocs: [ModuleSell](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#modulesell)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "ModuleSellEventSchema", into = "ModuleSellEventSchema")]
+pub struct ModuleSellEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The slot the module was fitted in
+    pub slot: String,
+
+    /// The module sold
+    pub sell_item: LocalisedValue,
+
+    /// The price the module was sold for
+    pub sell_price: u64,
+
+    /// The ship type
+    pub ship: String,
+
+    /// The id of the ship
+    pub ship_id: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleSellEventSchema> for ModuleSellEvent {
+    fn from(value: ModuleSellEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            slot: value.slot,
+            sell_item: LocalisedValue::new(value.sell_item, value.sell_item_localised),
+            sell_price: value.sell_price,
+            ship: value.ship,
+            ship_id: value.ship_id,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleSellEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    pub slot: String,
+    pub sell_item: String,
+    #[serde(rename = "SellItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub sell_item_localised: Option<String>,
+    pub sell_price: u64,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleSellEvent> for ModuleSellEventSchema {
+    fn from(value: ModuleSellEvent) -> Self {
+        let (sell_item, sell_item_localised) = deconstruct_localised_value(value.sell_item);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            slot: value.slot,
+            sell_item,
+            sell_item_localised,
+            sell_price: value.sell_price,
+            ship: value.ship,
+            ship_id: value.ship_id,
+        }
+    }
+}
+
+/// The engineering of a module that is stored
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct StoredModuleEngineering {
+    /// The name of the blueprint
+    pub engineer_modifications: String,
+    /// The grade of the blueprint
+    pub level: u8,
+    /// The progress through the grade, between 0 and 1
+    pub quality: f64,
+}
+
+/// A fitted module has been put into storage
+///
+/// ```json
+/// { "timestamp":"2025-01-08T19:24:40Z", "event":"ModuleStore", "MarketID":3223343616, "Slot":"Slot03_Size4", "StoredItem":"$int_shieldgenerator_size4_class5_name;", "StoredItem_Localised":"Shield Generator", "Ship":"krait_mkii", "ShipID":7, "Hot":false, "EngineerModifications":"ShieldGenerator_Reinforced", "Level":5, "Quality":1.000000 }
+/// ```
+///
+/// ReadTheDocs: [ModuleStore](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#modulestore)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "ModuleStoreEventSchema", into = "ModuleStoreEventSchema")]
+pub struct ModuleStoreEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The slot the module was fitted in
+    pub slot: String,
+
+    /// The module stored
+    pub stored_item: LocalisedValue,
+
+    /// The module fitted in its place
+    pub replacement_item: Option<LocalisedValue>,
+
+    /// The cost of the replacement module
+    pub cost: Option<u64>,
+
+    /// The ship type
+    pub ship: String,
+
+    /// The id of the ship
+    pub ship_id: u64,
+
+    /// Is the module hot (stolen)
+    pub hot: bool,
+
+    /// The engineering applied to the module
+    pub engineering: Option<StoredModuleEngineering>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleStoreEventSchema> for ModuleStoreEvent {
+    fn from(value: ModuleStoreEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            slot: value.slot,
+            stored_item: LocalisedValue::new(value.stored_item, value.stored_item_localised),
+            replacement_item: LocalisedValue::new_optional(value.replacement_item, value.replacement_item_localised),
+            cost: value.cost,
+            ship: value.ship,
+            ship_id: value.ship_id,
+            hot: value.hot,
+            engineering: value.engineering,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleStoreEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    pub slot: String,
+    pub stored_item: String,
+    #[serde(rename = "StoredItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub stored_item_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_item: Option<String>,
+    #[serde(rename = "ReplacementItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub replacement_item_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<u64>,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    pub hot: bool,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub engineering: Option<StoredModuleEngineering>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleStoreEvent> for ModuleStoreEventSchema {
+    fn from(value: ModuleStoreEvent) -> Self {
+        let (stored_item, stored_item_localised) = deconstruct_localised_value(value.stored_item);
+        let (replacement_item, replacement_item_localised) = deconstruct_optional_localised_value(value.replacement_item);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            slot: value.slot,
+            stored_item,
+            stored_item_localised,
+            replacement_item,
+            replacement_item_localised,
+            cost: value.cost,
+            ship: value.ship,
+            ship_id: value.ship_id,
+            hot: value.hot,
+            engineering: value.engineering,
+        }
+    }
+}
+
+/// A module has been retrieved from storage and fitted
+///
+/// ```json
+/// { "timestamp":"2025-01-08T19:26:12Z", "event":"ModuleRetrieve", "MarketID":3223343616, "Slot":"Slot03_Size4", "RetrievedItem":"$int_shieldgenerator_size4_class5_name;", "RetrievedItem_Localised":"Shield Generator", "Ship":"krait_mkii", "ShipID":7, "Hot":false, "EngineerModifications":"ShieldGenerator_Reinforced", "Level":5, "Quality":1.000000, "SwapOutItem":"$int_cargorack_size4_class1_name;", "SwapOutItem_Localised":"Cargo Rack" }
+/// ```
+///
+/// ReadTheDocs: [ModuleRetrieve](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#moduleretrieve)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "ModuleRetrieveEventSchema", into = "ModuleRetrieveEventSchema")]
+pub struct ModuleRetrieveEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The slot the module is fitted in
+    pub slot: String,
+
+    /// The module retrieved
+    pub retrieved_item: LocalisedValue,
+
+    /// The module that was in the slot and has been stored
+    pub swap_out_item: Option<LocalisedValue>,
+
+    /// The cost of retrieving the module
+    pub cost: Option<u64>,
+
+    /// The ship type
+    pub ship: String,
+
+    /// The id of the ship
+    pub ship_id: u64,
+
+    /// Is the module hot (stolen)
+    pub hot: bool,
+
+    /// The engineering applied to the module
+    pub engineering: Option<StoredModuleEngineering>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleRetrieveEventSchema> for ModuleRetrieveEvent {
+    fn from(value: ModuleRetrieveEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            slot: value.slot,
+            retrieved_item: LocalisedValue::new(value.retrieved_item, value.retrieved_item_localised),
+            swap_out_item: LocalisedValue::new_optional(value.swap_out_item, value.swap_out_item_localised),
+            cost: value.cost,
+            ship: value.ship,
+            ship_id: value.ship_id,
+            hot: value.hot,
+            engineering: value.engineering,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleRetrieveEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    pub slot: String,
+    pub retrieved_item: String,
+    #[serde(rename = "RetrievedItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub retrieved_item_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swap_out_item: Option<String>,
+    #[serde(rename = "SwapOutItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub swap_out_item_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<u64>,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    pub hot: bool,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub engineering: Option<StoredModuleEngineering>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleRetrieveEvent> for ModuleRetrieveEventSchema {
+    fn from(value: ModuleRetrieveEvent) -> Self {
+        let (retrieved_item, retrieved_item_localised) = deconstruct_localised_value(value.retrieved_item);
+        let (swap_out_item, swap_out_item_localised) = deconstruct_optional_localised_value(value.swap_out_item);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            slot: value.slot,
+            retrieved_item,
+            retrieved_item_localised,
+            swap_out_item,
+            swap_out_item_localised,
+            cost: value.cost,
+            ship: value.ship,
+            ship_id: value.ship_id,
+            hot: value.hot,
+            engineering: value.engineering,
+        }
+    }
+}
+
+/// Two modules have been swapped between slots, the ```to_item``` is ```Null``` if the destination slot was empty
+///
+/// ```json
+/// { "timestamp":"2025-01-08T19:27:55Z", "event":"ModuleSwap", "MarketID":3223343616, "FromSlot":"MediumHardpoint1", "ToSlot":"MediumHardpoint2", "FromItem":"hpt_pulselaser_fixed_medium", "FromItem_Localised":"Pulse Laser", "ToItem":"hpt_multicannon_gimbal_medium", "ToItem_Localised":"Multi-Cannon", "Ship":"krait_mkii", "ShipID":7 }
+/// ```
+///
+/// ReadTheDocs: [ModuleSwap](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#moduleswap)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "ModuleSwapEventSchema", into = "ModuleSwapEventSchema")]
+pub struct ModuleSwapEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The slot the module was moved from
+    pub from_slot: String,
+
+    /// The slot the module was moved to
+    pub to_slot: String,
+
+    /// The module moved from the from slot
+    pub from_item: LocalisedValue,
+
+    /// The module moved from the to slot
+    pub to_item: LocalisedValue,
+
+    /// The ship type
+    pub ship: String,
+
+    /// The id of the ship
+    pub ship_id: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleSwapEventSchema> for ModuleSwapEvent {
+    fn from(value: ModuleSwapEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            from_slot: value.from_slot,
+            to_slot: value.to_slot,
+            from_item: LocalisedValue::new(value.from_item, value.from_item_localised),
+            to_item: LocalisedValue::new(value.to_item, value.to_item_localised),
+            ship: value.ship,
+            ship_id: value.ship_id,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleSwapEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    pub from_slot: String,
+    pub to_slot: String,
+    pub from_item: String,
+    #[serde(rename = "FromItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub from_item_localised: Option<String>,
+    pub to_item: String,
+    #[serde(rename = "ToItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub to_item_localised: Option<String>,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ModuleSwapEvent> for ModuleSwapEventSchema {
+    fn from(value: ModuleSwapEvent) -> Self {
+        let (from_item, from_item_localised) = deconstruct_localised_value(value.from_item);
+        let (to_item, to_item_localised) = deconstruct_localised_value(value.to_item);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            from_slot: value.from_slot,
+            to_slot: value.to_slot,
+            from_item,
+            from_item_localised,
+            to_item,
+            to_item_localised,
+            ship: value.ship,
+            ship_id: value.ship_id,
+        }
+    }
+}
+
+/// A stored module has been requested to be transferred from another station
+///
+/// ```json
+/// { "timestamp":"2025-01-08T19:29:31Z", "event":"FetchRemoteModule", "StorageSlot":12, "StoredItem":"$hpt_beamlaser_gimbal_huge_name;", "StoredItem_Localised":"Beam Laser", "ServerId":128681994, "TransferCost":79680, "TransferTime":1737, "Ship":"anaconda", "ShipID":5 }
+/// ```
+///
+/// ReadTheDocs: [FetchRemoteModule](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#fetchremotemodule)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "FetchRemoteModuleEventSchema", into = "FetchRemoteModuleEventSchema")]
+pub struct FetchRemoteModuleEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The storage slot of the module
+    pub storage_slot: u32,
+
+    /// The module being transferred
+    pub stored_item: LocalisedValue,
+
+    /// The internal id of the module type
+    pub server_id: u64,
+
+    /// The cost of the transfer
+    pub transfer_cost: u64,
+
+    /// The time the transfer will take in seconds
+    pub transfer_time: u64,
+
+    /// The ship type
+    pub ship: String,
+
+    /// The id of the ship
+    pub ship_id: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<FetchRemoteModuleEventSchema> for FetchRemoteModuleEvent {
+    fn from(value: FetchRemoteModuleEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            storage_slot: value.storage_slot,
+            stored_item: LocalisedValue::new(value.stored_item, value.stored_item_localised),
+            server_id: value.server_id,
+            transfer_cost: value.transfer_cost,
+            transfer_time: value.transfer_time,
+            ship: value.ship,
+            ship_id: value.ship_id,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct FetchRemoteModuleEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub storage_slot: u32,
+    pub stored_item: String,
+    #[serde(rename = "StoredItem_Localised", skip_serializing_if = "Option::is_none")]
+    pub stored_item_localised: Option<String>,
+    pub server_id: u64,
+    pub transfer_cost: u64,
+    pub transfer_time: u64,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<FetchRemoteModuleEvent> for FetchRemoteModuleEventSchema {
+    fn from(value: FetchRemoteModuleEvent) -> Self {
+        let (stored_item, stored_item_localised) = deconstruct_localised_value(value.stored_item);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            storage_slot: value.storage_slot,
+            stored_item,
+            stored_item_localised,
+            server_id: value.server_id,
+            transfer_cost: value.transfer_cost,
+            transfer_time: value.transfer_time,
+            ship: value.ship,
+            ship_id: value.ship_id,
+        }
+    }
+}
+
+/// A module stored as part of storing all the modules of a ship
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "MassStoredModuleSchema", into = "MassStoredModuleSchema")]
+pub struct MassStoredModule {
+    /// The slot the module was fitted in
+    pub slot: String,
+    /// The module stored
+    pub name: LocalisedValue,
+    /// Is the module hot (stolen)
+    pub hot: bool,
+    /// The engineering applied to the module
+    pub engineering: Option<StoredModuleEngineering>,
+}
+
+impl From<MassStoredModuleSchema> for MassStoredModule {
+    fn from(value: MassStoredModuleSchema) -> Self {
+        Self {
+            slot: value.slot,
+            name: LocalisedValue::new(value.name, value.name_localised),
+            hot: value.hot,
+            engineering: value.engineering,
+        }
+    }
+}
+
+/// Schema for the stored module due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct MassStoredModuleSchema {
+    slot: String,
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+    hot: bool,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    engineering: Option<StoredModuleEngineering>,
+}
+
+impl From<MassStoredModule> for MassStoredModuleSchema {
+    fn from(value: MassStoredModule) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            slot: value.slot,
+            name,
+            name_localised,
+            hot: value.hot,
+            engineering: value.engineering,
+        }
+    }
+}
+
+/// All the modules of a ship have been put into storage
+///
+/// ```json
+/// { "timestamp":"2025-01-08T19:31:08Z", "event":"MassModuleStore", "MarketID":3223343616, "Ship":"krait_mkii", "ShipID":7, "Items":[ { "Slot":"MediumHardpoint1", "Name":"$hpt_pulselaser_fixed_medium_name;", "Name_Localised":"Pulse Laser", "Hot":false } ] }
+/// ```
+///
+/// ReadTheDocs: [MassModuleStore](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#massmodulestore)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MassModuleStoreEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The ship type
+    pub ship: String,
+
+    /// The id of the ship
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+
+    /// The modules stored
+    pub items: Vec<MassStoredModule>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A module held in storage, modules held at another station have the location and transfer details
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "StoredModuleSchema", into = "StoredModuleSchema")]
+pub struct StoredModule {
+    /// The module
+    pub name: LocalisedValue,
+    /// The storage slot of the module
+    pub storage_slot: u32,
+    /// The system the module is stored in, not present if stored at the current station
+    pub star_system: Option<String>,
+    /// The market the module is stored at, not present if stored at the current station
+    pub market_id: Option<u64>,
+    /// The cost to transfer the module to the current station
+    pub transfer_cost: Option<u64>,
+    /// The time to transfer the module to the current station in seconds
+    pub transfer_time: Option<u64>,
+    /// The price paid for the module
+    pub buy_price: u64,
+    /// Is the module hot (stolen)
+    pub hot: bool,
+    /// Is the module being transferred
+    pub in_transit: Option<bool>,
+    /// The engineering applied to the module
+    pub engineering: Option<StoredModuleEngineering>,
+}
+
+impl From<StoredModuleSchema> for StoredModule {
+    fn from(value: StoredModuleSchema) -> Self {
+        Self {
+            name: LocalisedValue::new(value.name, value.name_localised),
+            storage_slot: value.storage_slot,
+            star_system: value.star_system,
+            market_id: value.market_id,
+            transfer_cost: value.transfer_cost,
+            transfer_time: value.transfer_time,
+            buy_price: value.buy_price,
+            hot: value.hot,
+            in_transit: value.in_transit,
+            engineering: value.engineering,
+        }
+    }
+}
+
+/// Schema for the stored module due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct StoredModuleSchema {
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+    storage_slot: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    star_system: Option<String>,
+    #[serde(rename = "MarketID", skip_serializing_if = "Option::is_none")]
+    market_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transfer_cost: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transfer_time: Option<u64>,
+    buy_price: u64,
+    hot: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_transit: Option<bool>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    engineering: Option<StoredModuleEngineering>,
+}
+
+impl From<StoredModule> for StoredModuleSchema {
+    fn from(value: StoredModule) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            name,
+            name_localised,
+            storage_slot: value.storage_slot,
+            star_system: value.star_system,
+            market_id: value.market_id,
+            transfer_cost: value.transfer_cost,
+            transfer_time: value.transfer_time,
+            buy_price: value.buy_price,
+            hot: value.hot,
+            in_transit: value.in_transit,
+            engineering: value.engineering,
+        }
+    }
+}
+
+/// The modules held in storage, written when accessing outfitting
+///
+/// ```json
+/// { "timestamp":"2025-01-08T19:20:01Z", "event":"StoredModules", "MarketID":3223343616, "StationName":"Jameson Memorial", "StarSystem":"Shinrarta Dezhra", "Items":[ { "Name":"$int_shieldgenerator_size4_class5_name;", "Name_Localised":"Shield Generator", "StorageSlot":8, "BuyPrice":281000, "Hot":false, "EngineerModifications":"ShieldGenerator_Reinforced", "Level":5, "Quality":1.000000 }, { "Name":"$hpt_beamlaser_gimbal_huge_name;", "Name_Localised":"Beam Laser", "StorageSlot":12, "StarSystem":"Sol", "MarketID":128016640, "TransferCost":79680, "TransferTime":1737, "BuyPrice":7434236, "Hot":false } ] }
+/// ```
+///
+/// ReadTheDocs: [StoredModules](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#storedmodules)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct StoredModulesEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The name of the system
+    pub star_system: String,
+
+    /// The modules in storage
+    pub items: Vec<StoredModule>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::common::module_id::ModuleId;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_module_buy_event() {
+        let json = r#"{"timestamp":"2025-01-08T19:21:37Z","Slot":"MediumHardpoint2","SellItem":"$hpt_pulselaser_fixed_medium_name;","SellItem_Localised":"Pulse Laser","SellPrice":17600,"BuyItem":"$hpt_multicannon_gimbal_medium_name;","BuyItem_Localised":"Multi-Cannon","MarketID":3223343616,"BuyPrice":57000,"Ship":"krait_mkii","ShipID":7}"#;
+
+        let event: ModuleBuyEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-08T19:21:37Z"));
+        assert_eq!(event.buy_item.localised_value, Some("Multi-Cannon".to_string()));
+        assert_eq!(event.buy_item.value.parse::<ModuleId>().unwrap().display_name(), "Medium Gimballed Multi-Cannon");
+        assert_eq!(event.sell_price, Some(17600));
+        assert_eq!(event.stored_item, None);
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_serialize_module_store_event() {
+        let json = r#"{"timestamp":"2025-01-08T19:24:40Z","MarketID":3223343616,"Slot":"Slot03_Size4","StoredItem":"$int_shieldgenerator_size4_class5_name;","StoredItem_Localised":"Shield Generator","Ship":"krait_mkii","ShipID":7,"Hot":false,"EngineerModifications":"ShieldGenerator_Reinforced","Level":5,"Quality":0.5}"#;
+
+        let event: ModuleStoreEvent = serde_json::from_str(json).unwrap();
+
+        assert!(!event.hot);
+        assert_eq!(event.engineering, Some(StoredModuleEngineering { engineer_modifications: "ShieldGenerator_Reinforced".to_string(), level: 5, quality: 0.5 }));
+        assert!(event.extra.is_empty());
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_stored_modules_event() {
+        let json = r#"{ "timestamp":"2025-01-08T19:20:01Z", "MarketID":3223343616, "StationName":"Jameson Memorial", "StarSystem":"Shinrarta Dezhra", "Items":[ { "Name":"$int_shieldgenerator_size4_class5_name;", "Name_Localised":"Shield Generator", "StorageSlot":8, "BuyPrice":281000, "Hot":false, "EngineerModifications":"ShieldGenerator_Reinforced", "Level":5, "Quality":1.000000 }, { "Name":"$hpt_beamlaser_gimbal_huge_name;", "Name_Localised":"Beam Laser", "StorageSlot":12, "StarSystem":"Sol", "MarketID":128016640, "TransferCost":79680, "TransferTime":1737, "BuyPrice":7434236, "Hot":false } ] }"#;
+
+        let event: StoredModulesEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.items.len(), 2);
+        assert_eq!(event.items[0].star_system, None);
+        assert_eq!(event.items[0].engineering.as_ref().map(|e| e.level), Some(5));
+        assert_eq!(event.items[1].star_system, Some("Sol".to_string()));
+        assert_eq!(event.items[1].transfer_cost, Some(79680));
+        assert_eq!(event.items[1].engineering, None);
+        assert!(event.extra.is_empty());
+    }
+}
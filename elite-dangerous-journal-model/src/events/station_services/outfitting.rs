@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// A module that is available to buy in outfitting
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct OutfittingItem {
+    /// The internal id of the module
+    #[serde(rename = "id")]
+    pub id: u64,
+    /// The internal name of the module
+    pub name: String,
+    /// The price of the module
+    pub buy_price: u64,
+}
+
+/// Emitted when accessing outfitting, the journal only contains the station details but the full list of
+/// modules is written to the Outfitting.json file
+///
+/// Example (Outfitting.json):
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T16:05:12Z",
+///   "event": "Outfitting",
+///   "MarketID": 3228342528,
+///   "StationName": "Hickam Survey",
+///   "StarSystem": "Ngalinn",
+///   "Horizons": true,
+///   "Items": [
+///     { "id": 128049382, "Name": "hpt_pulselaser_fixed_medium", "BuyPrice": 17600 }
+///   ]
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Station%20Services/#outfitting
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct OutfittingEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The name of the system
+    pub star_system: String,
+
+    /// Is horizons enabled (only present in the Outfitting.json file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizons: Option<bool>,
+
+    /// The modules available (only present in the Outfitting.json file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<OutfittingItem>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_outfitting_file_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T16:05:12Z", "MarketID":3228342528, "StationName":"Hickam Survey", "StarSystem":"Ngalinn", "Horizons":true, "Items":[ { "id":128049382, "Name":"hpt_pulselaser_fixed_medium", "BuyPrice":17600 } ] }"#;
+
+        let event: OutfittingEvent = serde_json::from_str(json).expect("Failed to deserialize OutfittingEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T16:05:12Z"));
+        assert_eq!(event.station_name, "Hickam Survey");
+        assert_eq!(event.horizons, Some(true));
+        assert_eq!(event.items, Some(vec![OutfittingItem { id: 128049382, name: "hpt_pulselaser_fixed_medium".to_string(), buy_price: 17600 }]));
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize OutfittingEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
@@ -0,0 +1,231 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Fines have been paid
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:20:11Z", "event":"PayFines", "Amount":400, "AllFines":true, "ShipID":5 }
+/// ```
+///
+/// ReadTheDocs: [PayFines](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#payfines)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayFinesEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The amount paid
+    pub amount: u64,
+
+    /// Were all the fines paid
+    pub all_fines: bool,
+
+    /// The faction the fines were paid to, not present if all fines were paid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+
+    /// The id of the ship
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+
+    /// The percentage taken by an interstellar factor if one was used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broker_percentage: Option<f64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Bounties on the commander have been paid off
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:20:30Z", "event":"PayBounties", "Amount":1200, "Faction":"$faction_Federation;", "Faction_Localised":"Federation", "ShipID":5, "BrokerPercentage":25.000000 }
+/// ```
+///
+/// ReadTheDocs: [PayBounties](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#paybounties)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "PayBountiesEventSchema", into = "PayBountiesEventSchema")]
+pub struct PayBountiesEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The amount paid
+    pub amount: u64,
+
+    /// Were all the bounties paid
+    pub all_fines: Option<bool>,
+
+    /// The faction the bounties were paid to
+    pub faction: Option<LocalisedValue>,
+
+    /// The id of the ship
+    pub ship_id: u64,
+
+    /// The percentage taken by an interstellar factor if one was used
+    pub broker_percentage: Option<f64>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<PayBountiesEventSchema> for PayBountiesEvent {
+    fn from(value: PayBountiesEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            amount: value.amount,
+            all_fines: value.all_fines,
+            faction: LocalisedValue::new_optional(value.faction, value.faction_localised),
+            ship_id: value.ship_id,
+            broker_percentage: value.broker_percentage,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayBountiesEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub amount: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_fines: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+    #[serde(rename = "Faction_Localised", skip_serializing_if = "Option::is_none")]
+    pub faction_localised: Option<String>,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broker_percentage: Option<f64>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<PayBountiesEvent> for PayBountiesEventSchema {
+    fn from(value: PayBountiesEvent) -> Self {
+        let (faction, faction_localised) = deconstruct_optional_localised_value(value.faction);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            amount: value.amount,
+            all_fines: value.all_fines,
+            faction,
+            faction_localised,
+            ship_id: value.ship_id,
+            broker_percentage: value.broker_percentage,
+        }
+    }
+}
+
+/// The type of voucher redeemed
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum VoucherType {
+    /// Bounty vouchers
+    #[serde(rename = "bounty")]
+    Bounty,
+    /// Combat bonds
+    #[serde(rename = "CombatBond")]
+    CombatBond,
+    /// Trade vouchers
+    #[serde(rename = "trade")]
+    Trade,
+    /// Settlement vouchers
+    #[serde(rename = "settlement")]
+    Settlement,
+    /// Data scanned from data points etc
+    #[serde(rename = "scannable")]
+    Scannable,
+    /// Codex vouchers
+    #[serde(rename = "codex")]
+    Codex,
+    /// A voucher type that is not modelled
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The amount redeemed for a faction
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct VoucherFaction {
+    /// The name of the faction, empty for bounties issued by the pilots federation
+    pub faction: String,
+    /// The amount redeemed
+    pub amount: u64,
+}
+
+/// Vouchers have been redeemed, bounty vouchers give the amount per faction
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:21:12Z", "event":"RedeemVoucher", "Type":"bounty", "Amount":1214582, "Factions":[ { "Faction":"Bureau of Ngalinn Partners", "Amount":1154582 }, { "Faction":"", "Amount":60000 } ] }
+/// ```
+///
+/// ReadTheDocs: [RedeemVoucher](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#redeemvoucher)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RedeemVoucherEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The type of voucher
+    #[serde(rename = "Type")]
+    pub voucher_type: VoucherType,
+
+    /// The total amount redeemed
+    pub amount: u64,
+
+    /// The faction the vouchers were redeemed with (not present for bounties)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+
+    /// The amount redeemed per faction (only present for bounties)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub factions: Option<Vec<VoucherFaction>>,
+
+    /// The percentage taken by an interstellar factor if one was used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broker_percentage: Option<f64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_pay_bounties_event() {
+        let json = r#"{"timestamp":"2025-01-08T20:20:30Z","Amount":1200,"Faction":"$faction_Federation;","Faction_Localised":"Federation","ShipID":5,"BrokerPercentage":25.0}"#;
+
+        let event: PayBountiesEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-08T20:20:30Z"));
+        assert_eq!(event.amount, 1200);
+        assert_eq!(event.faction, Some(LocalisedValue::new("$faction_Federation;".to_string(), Some("Federation".to_string()))));
+        assert_eq!(event.broker_percentage, Some(25.0));
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_redeem_voucher_event() {
+        let bounty: RedeemVoucherEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-08T20:21:12Z", "Type":"bounty", "Amount":1214582, "Factions":[ { "Faction":"Bureau of Ngalinn Partners", "Amount":1154582 }, { "Faction":"", "Amount":60000 } ] }"#).unwrap();
+        let bond: RedeemVoucherEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-08T20:21:40Z", "Type":"CombatBond", "Amount":80000, "Faction":"Alliance" }"#).unwrap();
+
+        assert_eq!(bounty.voucher_type, VoucherType::Bounty);
+        assert_eq!(bounty.factions.as_ref().map(Vec::len), Some(2));
+        assert_eq!(bond.voucher_type, VoucherType::CombatBond);
+        assert_eq!(bond.faction, Some("Alliance".to_string()));
+        assert!(bond.extra.is_empty());
+    }
+}
@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Ammunition has been bought to restock the weapons
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:15:02Z", "event":"BuyAmmo", "Cost":1280 }
+/// ```
+///
+/// ReadTheDocs: [BuyAmmo](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#buyammo)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuyAmmoEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The cost of the ammunition
+    pub cost: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Limpets have been bought
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:15:09Z", "event":"BuyDrones", "Type":"Drones", "Count":32, "BuyPrice":101, "TotalCost":3232 }
+/// ```
+///
+/// ReadTheDocs: [BuyDrones](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#buydrones)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuyDronesEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The type of drone, always ```Drones```
+    #[serde(rename = "Type")]
+    pub drone_type: String,
+
+    /// The number of limpets bought
+    pub count: u32,
+
+    /// The price paid per limpet
+    pub buy_price: u64,
+
+    /// The total price paid
+    pub total_cost: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Limpets have been sold
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:16:40Z", "event":"SellDrones", "Type":"Drones", "Count":8, "SellPrice":101, "TotalSale":808 }
+/// ```
+///
+/// ReadTheDocs: [SellDrones](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#selldrones)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SellDronesEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The type of drone, always ```Drones```
+    #[serde(rename = "Type")]
+    pub drone_type: String,
+
+    /// The number of limpets sold
+    pub count: u32,
+
+    /// The price received per limpet
+    pub sell_price: u64,
+
+    /// The total price received
+    pub total_sale: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Part of the ship has been repaired, older journals give a single ```Item``` newer journals give the list of
+/// ```Items```
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:17:21Z", "event":"Repair", "Items":[ "$hpt_beamlaser_gimbal_huge_name;", "Hull" ], "Cost":7430 }
+/// ```
+///
+/// ReadTheDocs: [Repair](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#repair)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RepairEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The item repaired e.g. ```Hull```, ```Paint``` or a module
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+
+    /// The items repaired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<String>>,
+
+    /// The cost of the repair
+    pub cost: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl RepairEvent {
+
+    /// The items repaired regardless of which journal format was used
+    pub fn repaired_items(&self) -> Vec<&str> {
+        self.items.iter().flatten().chain(self.item.iter()).map(String::as_str).collect()
+    }
+}
+
+/// The whole ship has been repaired
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:17:40Z", "event":"RepairAll", "Cost":12845 }
+/// ```
+///
+/// ReadTheDocs: [RepairAll](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#repairall)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RepairAllEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The cost of the repair
+    pub cost: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The fuel tanks have been filled
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:17:44Z", "event":"RefuelAll", "Cost":613, "Amount":12.238000 }
+/// ```
+///
+/// ReadTheDocs: [RefuelAll](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#refuelall)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RefuelAllEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The cost of the fuel
+    pub cost: u64,
+
+    /// The amount of fuel bought in tons
+    pub amount: f64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A replacement SRV or fighter has been bought
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:18:02Z", "event":"RestockVehicle", "Type":"testbuggy", "Loadout":"starter", "Cost":1030, "Count":1 }
+/// ```
+///
+/// ReadTheDocs: [RestockVehicle](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#restockvehicle)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RestockVehicleEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The type of vehicle
+    #[serde(rename = "Type")]
+    pub vehicle_type: String,
+
+    /// The loadout of the vehicle
+    pub loadout: String,
+
+    /// The cost of the vehicles
+    pub cost: u64,
+
+    /// The number of vehicles bought
+    pub count: u32,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_buy_drones_event() {
+        let json = r#"{ "timestamp":"2025-01-08T20:15:09Z", "Type":"Drones", "Count":32, "BuyPrice":101, "TotalCost":3232 }"#;
+
+        let event: BuyDronesEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-08T20:15:09Z"));
+        assert_eq!(event.count, 32);
+        assert_eq!(event.total_cost, 3232);
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_repaired_items() {
+        let old: RepairEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-08T20:17:21Z", "Item":"Hull", "Cost":340 }"#).unwrap();
+        let new: RepairEvent = serde_json::from_str(r#"{ "timestamp":"2025-01-08T20:17:21Z", "Items":[ "$hpt_beamlaser_gimbal_huge_name;", "Hull" ], "Cost":7430 }"#).unwrap();
+
+        assert_eq!(old.repaired_items(), vec!["Hull"]);
+        assert_eq!(new.repaired_items(), vec!["$hpt_beamlaser_gimbal_huge_name;", "Hull"]);
+        assert_eq!(new.cost, 7430);
+    }
+}
@@ -0,0 +1,563 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// A ship has been bought at a shipyard, the current ship is either stored or sold
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:02:14Z", "event":"ShipyardBuy", "ShipType":"krait_mkii", "ShipType_Localised":"Krait Mk II", "ShipPrice":44150144, "StoreOldShip":"Anaconda", "StoreShipID":5, "MarketID":3223343616 }
+/// ```
+///
+/// ReadTheDocs: [ShipyardBuy](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#shipyardbuy)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "ShipyardBuyEventSchema", into = "ShipyardBuyEventSchema")]
+pub struct ShipyardBuyEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The type of ship bought
+    pub ship_type: LocalisedValue,
+
+    /// The price paid for the ship
+    pub ship_price: u64,
+
+    /// The type of the previous ship if it was stored
+    pub store_old_ship: Option<String>,
+
+    /// The id of the previous ship if it was stored
+    pub store_ship_id: Option<u64>,
+
+    /// The type of the previous ship if it was sold
+    pub sell_old_ship: Option<String>,
+
+    /// The id of the previous ship if it was sold
+    pub sell_ship_id: Option<u64>,
+
+    /// The price the previous ship was sold for
+    pub sell_price: Option<u64>,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ShipyardBuyEventSchema> for ShipyardBuyEvent {
+    fn from(value: ShipyardBuyEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            ship_type: LocalisedValue::new(value.ship_type, value.ship_type_localised),
+            ship_price: value.ship_price,
+            store_old_ship: value.store_old_ship,
+            store_ship_id: value.store_ship_id,
+            sell_old_ship: value.sell_old_ship,
+            sell_ship_id: value.sell_ship_id,
+            sell_price: value.sell_price,
+            market_id: value.market_id,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardBuyEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised", skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    pub ship_price: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_old_ship: Option<String>,
+    #[serde(rename = "StoreShipID", skip_serializing_if = "Option::is_none")]
+    pub store_ship_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_old_ship: Option<String>,
+    #[serde(rename = "SellShipID", skip_serializing_if = "Option::is_none")]
+    pub sell_ship_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_price: Option<u64>,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ShipyardBuyEvent> for ShipyardBuyEventSchema {
+    fn from(value: ShipyardBuyEvent) -> Self {
+        let (ship_type, ship_type_localised) = deconstruct_localised_value(value.ship_type);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            ship_type,
+            ship_type_localised,
+            ship_price: value.ship_price,
+            store_old_ship: value.store_old_ship,
+            store_ship_id: value.store_ship_id,
+            sell_old_ship: value.sell_old_ship,
+            sell_ship_id: value.sell_ship_id,
+            sell_price: value.sell_price,
+            market_id: value.market_id,
+        }
+    }
+}
+
+/// A stored ship has been sold
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:05:47Z", "event":"ShipyardSell", "ShipType":"cobramkiii", "ShipType_Localised":"Cobra MkIII", "SellShipID":2, "ShipPrice":314640, "MarketID":3223343616 }
+/// ```
+///
+/// ReadTheDocs: [ShipyardSell](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#shipyardsell)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "ShipyardSellEventSchema", into = "ShipyardSellEventSchema")]
+pub struct ShipyardSellEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The type of ship sold
+    pub ship_type: LocalisedValue,
+
+    /// The id of the ship sold
+    pub sell_ship_id: u64,
+
+    /// The price the ship was sold for
+    pub ship_price: u64,
+
+    /// The system the ship was in if it was sold remotely
+    pub system: Option<String>,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ShipyardSellEventSchema> for ShipyardSellEvent {
+    fn from(value: ShipyardSellEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            ship_type: LocalisedValue::new(value.ship_type, value.ship_type_localised),
+            sell_ship_id: value.sell_ship_id,
+            ship_price: value.ship_price,
+            system: value.system,
+            market_id: value.market_id,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardSellEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised", skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    #[serde(rename = "SellShipID")]
+    pub sell_ship_id: u64,
+    pub ship_price: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ShipyardSellEvent> for ShipyardSellEventSchema {
+    fn from(value: ShipyardSellEvent) -> Self {
+        let (ship_type, ship_type_localised) = deconstruct_localised_value(value.ship_type);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            ship_type,
+            ship_type_localised,
+            sell_ship_id: value.sell_ship_id,
+            ship_price: value.ship_price,
+            system: value.system,
+            market_id: value.market_id,
+        }
+    }
+}
+
+/// The commander has switched to a stored ship, the current ship is either stored or sold
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:07:19Z", "event":"ShipyardSwap", "ShipType":"anaconda", "ShipType_Localised":"Anaconda", "ShipID":5, "StoreOldShip":"Krait_MkII", "StoreShipID":7, "MarketID":3223343616 }
+/// ```
+///
+/// ReadTheDocs: [ShipyardSwap](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#shipyardswap)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "ShipyardSwapEventSchema", into = "ShipyardSwapEventSchema")]
+pub struct ShipyardSwapEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The type of ship switched to
+    pub ship_type: LocalisedValue,
+
+    /// The id of the ship switched to
+    pub ship_id: u64,
+
+    /// The type of the previous ship if it was stored
+    pub store_old_ship: Option<String>,
+
+    /// The id of the previous ship if it was stored
+    pub store_ship_id: Option<u64>,
+
+    /// The type of the previous ship if it was sold
+    pub sell_old_ship: Option<String>,
+
+    /// The id of the previous ship if it was sold
+    pub sell_ship_id: Option<u64>,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ShipyardSwapEventSchema> for ShipyardSwapEvent {
+    fn from(value: ShipyardSwapEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            ship_type: LocalisedValue::new(value.ship_type, value.ship_type_localised),
+            ship_id: value.ship_id,
+            store_old_ship: value.store_old_ship,
+            store_ship_id: value.store_ship_id,
+            sell_old_ship: value.sell_old_ship,
+            sell_ship_id: value.sell_ship_id,
+            market_id: value.market_id,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardSwapEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised", skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_old_ship: Option<String>,
+    #[serde(rename = "StoreShipID", skip_serializing_if = "Option::is_none")]
+    pub store_ship_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_old_ship: Option<String>,
+    #[serde(rename = "SellShipID", skip_serializing_if = "Option::is_none")]
+    pub sell_ship_id: Option<u64>,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ShipyardSwapEvent> for ShipyardSwapEventSchema {
+    fn from(value: ShipyardSwapEvent) -> Self {
+        let (ship_type, ship_type_localised) = deconstruct_localised_value(value.ship_type);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            ship_type,
+            ship_type_localised,
+            ship_id: value.ship_id,
+            store_old_ship: value.store_old_ship,
+            store_ship_id: value.store_ship_id,
+            sell_old_ship: value.sell_old_ship,
+            sell_ship_id: value.sell_ship_id,
+            market_id: value.market_id,
+        }
+    }
+}
+
+/// A stored ship has been requested to be transferred to the current station
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:09:51Z", "event":"ShipyardTransfer", "ShipType":"cobramkiii", "ShipType_Localised":"Cobra MkIII", "ShipID":2, "System":"Sol", "ShipMarketID":128016640, "Distance":19.123001, "TransferPrice":18774, "TransferTime":1221, "MarketID":3223343616 }
+/// ```
+///
+/// ReadTheDocs: [ShipyardTransfer](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#shipyardtransfer)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "ShipyardTransferEventSchema", into = "ShipyardTransferEventSchema")]
+pub struct ShipyardTransferEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The type of ship transferred
+    pub ship_type: LocalisedValue,
+
+    /// The id of the ship transferred
+    pub ship_id: u64,
+
+    /// The system the ship is being transferred from
+    pub system: String,
+
+    /// The market the ship is being transferred from
+    pub ship_market_id: u64,
+
+    /// The distance the ship is being transferred in light years
+    pub distance: f64,
+
+    /// The cost of the transfer
+    pub transfer_price: u64,
+
+    /// The time the transfer will take in seconds
+    pub transfer_time: u64,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ShipyardTransferEventSchema> for ShipyardTransferEvent {
+    fn from(value: ShipyardTransferEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            ship_type: LocalisedValue::new(value.ship_type, value.ship_type_localised),
+            ship_id: value.ship_id,
+            system: value.system,
+            ship_market_id: value.ship_market_id,
+            distance: value.distance,
+            transfer_price: value.transfer_price,
+            transfer_time: value.transfer_time,
+            market_id: value.market_id,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardTransferEventSchema {
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised", skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    pub system: String,
+    #[serde(rename = "ShipMarketID")]
+    pub ship_market_id: u64,
+    pub distance: f64,
+    pub transfer_price: u64,
+    pub transfer_time: u64,
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ShipyardTransferEvent> for ShipyardTransferEventSchema {
+    fn from(value: ShipyardTransferEvent) -> Self {
+        let (ship_type, ship_type_localised) = deconstruct_localised_value(value.ship_type);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            ship_type,
+            ship_type_localised,
+            ship_id: value.ship_id,
+            system: value.system,
+            ship_market_id: value.ship_market_id,
+            distance: value.distance,
+            transfer_price: value.transfer_price,
+            transfer_time: value.transfer_time,
+            market_id: value.market_id,
+        }
+    }
+}
+
+/// A ship held in storage, ships held at another station have the location and transfer details
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "StoredShipSchema", into = "StoredShipSchema")]
+pub struct StoredShip {
+    /// The id of the ship
+    pub ship_id: u64,
+    /// The type of the ship
+    pub ship_type: LocalisedValue,
+    /// The name given to the ship
+    pub name: Option<String>,
+    /// The system the ship is stored in, not present for ships at the current station or in transit
+    pub star_system: Option<String>,
+    /// The market the ship is stored at, not present for ships at the current station or in transit
+    pub ship_market_id: Option<u64>,
+    /// The cost to transfer the ship to the current station
+    pub transfer_price: Option<u64>,
+    /// The time to transfer the ship to the current station in seconds
+    pub transfer_time: Option<u64>,
+    /// The value of the ship
+    pub value: u64,
+    /// Is the ship hot (wanted)
+    pub hot: bool,
+    /// Is the ship being transferred
+    pub in_transit: Option<bool>,
+}
+
+impl From<StoredShipSchema> for StoredShip {
+    fn from(value: StoredShipSchema) -> Self {
+        Self {
+            ship_id: value.ship_id,
+            ship_type: LocalisedValue::new(value.ship_type, value.ship_type_localised),
+            name: value.name,
+            star_system: value.star_system,
+            ship_market_id: value.ship_market_id,
+            transfer_price: value.transfer_price,
+            transfer_time: value.transfer_time,
+            value: value.value,
+            hot: value.hot,
+            in_transit: value.in_transit,
+        }
+    }
+}
+
+/// Schema for the stored ship due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct StoredShipSchema {
+    #[serde(rename = "ShipID")]
+    ship_id: u64,
+    ship_type: String,
+    #[serde(rename = "ShipType_Localised", skip_serializing_if = "Option::is_none")]
+    ship_type_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    star_system: Option<String>,
+    #[serde(rename = "ShipMarketID", skip_serializing_if = "Option::is_none")]
+    ship_market_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transfer_price: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transfer_time: Option<u64>,
+    value: u64,
+    hot: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_transit: Option<bool>,
+}
+
+impl From<StoredShip> for StoredShipSchema {
+    fn from(value: StoredShip) -> Self {
+        let (ship_type, ship_type_localised) = deconstruct_localised_value(value.ship_type);
+        Self {
+            ship_id: value.ship_id,
+            ship_type,
+            ship_type_localised,
+            name: value.name,
+            star_system: value.star_system,
+            ship_market_id: value.ship_market_id,
+            transfer_price: value.transfer_price,
+            transfer_time: value.transfer_time,
+            value: value.value,
+            hot: value.hot,
+            in_transit: value.in_transit,
+        }
+    }
+}
+
+/// The ships held in storage, written when accessing the shipyard
+///
+/// ```json
+/// { "timestamp":"2025-01-08T20:01:33Z", "event":"StoredShips", "StationName":"Jameson Memorial", "MarketID":3223343616, "StarSystem":"Shinrarta Dezhra", "ShipsHere":[ { "ShipID":5, "ShipType":"anaconda", "Name":"Wanderer", "Value":354814073, "Hot":false } ], "ShipsRemote":[ { "ShipID":2, "ShipType":"cobramkiii", "ShipType_Localised":"Cobra MkIII", "StarSystem":"Sol", "ShipMarketID":128016640, "TransferPrice":18774, "TransferTime":1221, "Value":349600, "Hot":false } ] }
+/// ```
+///
+/// ReadTheDocs: [StoredShips](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#storedships)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct StoredShipsEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The id of the market
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The name of the system
+    pub star_system: String,
+
+    /// The ships stored at the current station
+    pub ships_here: Vec<StoredShip>,
+
+    /// The ships stored at other stations
+    pub ships_remote: Vec<StoredShip>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_shipyard_buy_event() {
+        let json = r#"{"timestamp":"2025-01-08T20:02:14Z","ShipType":"krait_mkii","ShipType_Localised":"Krait Mk II","ShipPrice":44150144,"SellOldShip":"Anaconda","SellShipID":5,"SellPrice":354814073,"MarketID":3223343616}"#;
+
+        let event: ShipyardBuyEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-08T20:02:14Z"));
+        assert_eq!(event.ship_type, LocalisedValue::new("krait_mkii".to_string(), Some("Krait Mk II".to_string())));
+        assert_eq!(event.store_old_ship, None);
+        assert_eq!(event.sell_ship_id, Some(5));
+        assert_eq!(event.sell_price, Some(354814073));
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_shipyard_transfer_event() {
+        let json = r#"{ "timestamp":"2025-01-08T20:09:51Z", "ShipType":"cobramkiii", "ShipType_Localised":"Cobra MkIII", "ShipID":2, "System":"Sol", "ShipMarketID":128016640, "Distance":19.123001, "TransferPrice":18774, "TransferTime":1221, "MarketID":3223343616 }"#;
+
+        let event: ShipyardTransferEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.ship_id, 2);
+        assert_eq!(event.system, "Sol");
+        assert_eq!(event.distance, 19.123001);
+        assert_eq!(event.transfer_time, 1221);
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_stored_ships_event() {
+        let json = r#"{ "timestamp":"2025-01-08T20:01:33Z", "StationName":"Jameson Memorial", "MarketID":3223343616, "StarSystem":"Shinrarta Dezhra", "ShipsHere":[ { "ShipID":5, "ShipType":"anaconda", "Name":"Wanderer", "Value":354814073, "Hot":false } ], "ShipsRemote":[ { "ShipID":2, "ShipType":"cobramkiii", "ShipType_Localised":"Cobra MkIII", "StarSystem":"Sol", "ShipMarketID":128016640, "TransferPrice":18774, "TransferTime":1221, "Value":349600, "Hot":false }, { "ShipID":3, "ShipType":"sidewinder", "InTransit":true, "Value":32000, "Hot":false } ] }"#;
+
+        let event: StoredShipsEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.ships_here.len(), 1);
+        assert_eq!(event.ships_here[0].name, Some("Wanderer".to_string()));
+        assert_eq!(event.ships_remote.len(), 2);
+        assert_eq!(event.ships_remote[0].star_system, Some("Sol".to_string()));
+        assert_eq!(event.ships_remote[1].in_transit, Some(true));
+        assert!(event.extra.is_empty());
+    }
+}
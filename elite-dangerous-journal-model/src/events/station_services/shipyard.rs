@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// A ship that is available to buy in the shipyard
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "ShipyardPriceSchema", into = "ShipyardPriceSchema")]
+pub struct ShipyardPrice {
+    /// The internal id of the ship
+    pub id: u64,
+    /// The type of ship
+    pub ship_type: LocalisedValue,
+    /// The price of the ship
+    pub ship_price: u64,
+}
+
+impl From<ShipyardPriceSchema> for ShipyardPrice {
+    /// Convert from ShipyardPriceSchema
+    fn from(value: ShipyardPriceSchema) -> Self {
+        Self {
+            id: value.id,
+            ship_type: LocalisedValue::new(value.ship_type, value.ship_type_localised),
+            ship_price: value.ship_price,
+        }
+    }
+}
+
+/// As the shipyard price contains a localised value we need an intermediary struct
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardPriceSchema {
+    #[serde(rename = "id")]
+    pub id: u64,
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised", skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    pub ship_price: u64,
+}
+
+impl From<ShipyardPrice> for ShipyardPriceSchema {
+    /// Convert from ShipyardPrice
+    fn from(value: ShipyardPrice) -> Self {
+        let (ship_type, ship_type_localised) = deconstruct_localised_value(value.ship_type);
+        Self {
+            id: value.id,
+            ship_type,
+            ship_type_localised,
+            ship_price: value.ship_price,
+        }
+    }
+}
+
+/// Emitted when accessing the shipyard, the journal only contains the station details but the full list of
+/// ships is written to the Shipyard.json file
+///
+/// Example (Shipyard.json):
+/// ```json
+/// {
+///   "timestamp": "2025-01-23T16:05:12Z",
+///   "event": "Shipyard",
+///   "MarketID": 3228342528,
+///   "StationName": "Hickam Survey",
+///   "StarSystem": "Ngalinn",
+///   "Horizons": true,
+///   "AllowCobraMkIV": false,
+///   "PriceList": [
+///     { "id": 128049267, "ShipType": "adder", "ShipPrice": 87808 },
+///     { "id": 128816574, "ShipType": "krait_mkii", "ShipType_Localised": "Krait Mk II", "ShipPrice": 44152080 }
+///   ]
+/// }
+/// ```
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Station%20Services/#shipyard
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The name of the system
+    pub star_system: String,
+
+    /// Is horizons enabled (only present in the Shipyard.json file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizons: Option<bool>,
+
+    /// Can the commander buy a Cobra Mk IV (only present in the Shipyard.json file)
+    #[serde(rename = "AllowCobraMkIV", skip_serializing_if = "Option::is_none")]
+    pub allow_cobra_mk_iv: Option<bool>,
+
+    /// The ships available (only present in the Shipyard.json file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_list: Option<Vec<ShipyardPrice>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_shipyard_file_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-23T16:05:12Z", "MarketID":3228342528, "StationName":"Hickam Survey", "StarSystem":"Ngalinn", "Horizons":true, "AllowCobraMkIV":false, "PriceList":[ { "id":128049267, "ShipType":"adder", "ShipPrice":87808 }, { "id":128816574, "ShipType":"krait_mkii", "ShipType_Localised":"Krait Mk II", "ShipPrice":44152080 } ] }"#;
+
+        let event: ShipyardEvent = serde_json::from_str(json).expect("Failed to deserialize ShipyardEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-23T16:05:12Z"));
+        assert_eq!(event.allow_cobra_mk_iv, Some(false));
+        let price_list = event.price_list.clone().expect("Expected a price list");
+        assert_eq!(price_list.len(), 2);
+        assert_eq!(price_list[1].ship_type, LocalisedValue::new("krait_mkii".to_string(), Some("Krait Mk II".to_string())));
+        assert_eq!(price_list[1].ship_price, 44152080);
+
+        let serialized: Value = serde_json::to_value(&event).expect("Failed to serialize ShipyardEvent");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+use crate::events::common::engineering::Ingredient;
+use crate::events::exploration::material_collected::MaterialCategory;
+
+/// An item unlocked at a technology broker
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "UnlockedItemSchema", into = "UnlockedItemSchema")]
+pub struct UnlockedItem {
+    /// The item unlocked
+    pub name: LocalisedValue,
+}
+
+impl From<UnlockedItemSchema> for UnlockedItem {
+    fn from(value: UnlockedItemSchema) -> Self {
+        Self {
+            name: LocalisedValue::new(value.name, value.name_localised),
+        }
+    }
+}
+
+/// Schema for the unlocked item due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct UnlockedItemSchema {
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+}
+
+impl From<UnlockedItem> for UnlockedItemSchema {
+    fn from(value: UnlockedItem) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            name,
+            name_localised,
+        }
+    }
+}
+
+/// A material given to a technology broker
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "BrokerMaterialSchema", into = "BrokerMaterialSchema")]
+pub struct BrokerMaterial {
+    /// The material
+    pub name: LocalisedValue,
+    /// The number given
+    pub count: u32,
+    /// The category of the material
+    pub category: MaterialCategory,
+}
+
+impl From<BrokerMaterialSchema> for BrokerMaterial {
+    fn from(value: BrokerMaterialSchema) -> Self {
+        Self {
+            name: LocalisedValue::new(value.name, value.name_localised),
+            count: value.count,
+            category: value.category,
+        }
+    }
+}
+
+/// Schema for the broker material due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct BrokerMaterialSchema {
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+    count: u32,
+    category: MaterialCategory,
+}
+
+impl From<BrokerMaterial> for BrokerMaterialSchema {
+    fn from(value: BrokerMaterial) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            name,
+            name_localised,
+            count: value.count,
+            category: value.category,
+        }
+    }
+}
+
+/// Items have been unlocked at a technology broker in exchange for commodities and materials
+///
+/// ```json
+/// { "timestamp":"2025-01-12T14:40:51Z", "event":"TechnologyBroker", "BrokerType":"guardian", "MarketID":128838136, "ItemsUnlocked":[ { "Name":"Hpt_Guardian_GaussCannon_Fixed_Medium", "Name_Localised":"Guardian Gauss Cannon" } ], "Commodities":[ { "Name":"powergridassembly", "Name_Localised":"Energy Grid Assembly", "Count":1 } ], "Materials":[ { "Name":"guardian_moduleblueprint", "Name_Localised":"Guardian Module Blueprint Fragment", "Count":4, "Category":"Encoded" } ] }
+/// ```
+///
+/// ReadTheDocs: [TechnologyBroker](https://elite-journal.readthedocs.io/en/latest/Station%20Services/#technologybroker)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct TechnologyBrokerEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The type of broker e.g. ```guardian``` or ```human```
+    pub broker_type: String,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The items unlocked
+    pub items_unlocked: Vec<UnlockedItem>,
+
+    /// The commodities given to the broker
+    #[serde(default)]
+    pub commodities: Vec<Ingredient>,
+
+    /// The materials given to the broker
+    #[serde(default)]
+    pub materials: Vec<BrokerMaterial>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_technology_broker_event() {
+        let json = r#"{ "timestamp":"2025-01-12T14:40:51Z", "BrokerType":"guardian", "MarketID":128838136, "ItemsUnlocked":[ { "Name":"Hpt_Guardian_GaussCannon_Fixed_Medium", "Name_Localised":"Guardian Gauss Cannon" } ], "Commodities":[ { "Name":"powergridassembly", "Name_Localised":"Energy Grid Assembly", "Count":1 } ], "Materials":[ { "Name":"guardian_moduleblueprint", "Name_Localised":"Guardian Module Blueprint Fragment", "Count":4, "Category":"Encoded" }, { "Name":"focuscrystals", "Name_Localised":"Focus Crystals", "Count":6, "Category":"Manufactured" } ] }"#;
+
+        let event: TechnologyBrokerEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-12T14:40:51Z"));
+        assert_eq!(event.broker_type, "guardian");
+        assert_eq!(event.items_unlocked[0].name.localised_value, Some("Guardian Gauss Cannon".to_string()));
+        assert_eq!(event.commodities[0].count, 1);
+        assert_eq!(event.materials.len(), 2);
+        assert_eq!(event.materials[0].category, MaterialCategory::Encoded);
+        assert_eq!(event.materials[1].count, 6);
+        assert!(event.extra.is_empty());
+    }
+}
@@ -0,0 +1,6 @@
+/// Decoding of the ```Flags``` and ```Flags2``` bit fields of the status file
+pub mod flags;
+/// Structs to represent the contents of the Status.json file
+pub mod status_file;
+/// Semantic changes derived by comparing consecutive status file snapshots
+pub mod status_change;
@@ -0,0 +1,315 @@
+use serde::{Deserialize, Serialize};
+
+/// Returns true if the bit at the given position is set
+fn is_set(value: u32, bit: u32) -> bool {
+    value & (1 << bit) != 0
+}
+
+/// Returns the value with the bit at the given position set if flag is true
+fn set(value: u32, bit: u32, flag: bool) -> u32 {
+    if flag {
+        value | (1 << bit)
+    } else {
+        value
+    }
+}
+
+/// The decoded ```Flags``` bit field from the status file
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Status%20File/
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(from = "u32", into = "u32")]
+pub struct StatusFlags {
+    /// Ship is docked
+    pub docked: bool,
+    /// Ship is landed on a planet
+    pub landed: bool,
+    /// Landing gear is deployed
+    pub landing_gear_down: bool,
+    /// Shields are up
+    pub shields_up: bool,
+    /// Ship is in supercruise
+    pub supercruise: bool,
+    /// Flight assist is off
+    pub flight_assist_off: bool,
+    /// Hardpoints are deployed
+    pub hardpoints_deployed: bool,
+    /// Commander is in a wing
+    pub in_wing: bool,
+    /// Lights are on
+    pub lights_on: bool,
+    /// Cargo scoop is deployed
+    pub cargo_scoop_deployed: bool,
+    /// Silent running is active
+    pub silent_running: bool,
+    /// Ship is scooping fuel
+    pub scooping_fuel: bool,
+    /// SRV handbrake is on
+    pub srv_handbrake: bool,
+    /// SRV is using turret view
+    pub srv_turret_view: bool,
+    /// SRV turret is retracted (close to ship)
+    pub srv_turret_retracted: bool,
+    /// SRV drive assist is on
+    pub srv_drive_assist: bool,
+    /// Frame shift drive is mass locked
+    pub fsd_mass_locked: bool,
+    /// Frame shift drive is charging
+    pub fsd_charging: bool,
+    /// Frame shift drive is cooling down
+    pub fsd_cooldown: bool,
+    /// Fuel is low (< 25%)
+    pub low_fuel: bool,
+    /// Ship is overheating (> 100%)
+    pub over_heating: bool,
+    /// Latitude and longitude are present
+    pub has_lat_long: bool,
+    /// Commander is in danger
+    pub is_in_danger: bool,
+    /// Commander is being interdicted
+    pub being_interdicted: bool,
+    /// Commander is in their main ship
+    pub in_main_ship: bool,
+    /// Commander is in a fighter
+    pub in_fighter: bool,
+    /// Commander is in an SRV
+    pub in_srv: bool,
+    /// HUD is in analysis mode
+    pub hud_analysis_mode: bool,
+    /// Night vision is on
+    pub night_vision: bool,
+    /// Altitude is measured from the average radius rather than the surface
+    pub altitude_from_average_radius: bool,
+    /// Frame shift drive is jumping
+    pub fsd_jump: bool,
+    /// SRV high beam is on
+    pub srv_high_beam: bool,
+}
+
+impl From<u32> for StatusFlags {
+    /// Decode the bit field
+    fn from(value: u32) -> Self {
+        Self {
+            docked: is_set(value, 0),
+            landed: is_set(value, 1),
+            landing_gear_down: is_set(value, 2),
+            shields_up: is_set(value, 3),
+            supercruise: is_set(value, 4),
+            flight_assist_off: is_set(value, 5),
+            hardpoints_deployed: is_set(value, 6),
+            in_wing: is_set(value, 7),
+            lights_on: is_set(value, 8),
+            cargo_scoop_deployed: is_set(value, 9),
+            silent_running: is_set(value, 10),
+            scooping_fuel: is_set(value, 11),
+            srv_handbrake: is_set(value, 12),
+            srv_turret_view: is_set(value, 13),
+            srv_turret_retracted: is_set(value, 14),
+            srv_drive_assist: is_set(value, 15),
+            fsd_mass_locked: is_set(value, 16),
+            fsd_charging: is_set(value, 17),
+            fsd_cooldown: is_set(value, 18),
+            low_fuel: is_set(value, 19),
+            over_heating: is_set(value, 20),
+            has_lat_long: is_set(value, 21),
+            is_in_danger: is_set(value, 22),
+            being_interdicted: is_set(value, 23),
+            in_main_ship: is_set(value, 24),
+            in_fighter: is_set(value, 25),
+            in_srv: is_set(value, 26),
+            hud_analysis_mode: is_set(value, 27),
+            night_vision: is_set(value, 28),
+            altitude_from_average_radius: is_set(value, 29),
+            fsd_jump: is_set(value, 30),
+            srv_high_beam: is_set(value, 31),
+        }
+    }
+}
+
+impl From<StatusFlags> for u32 {
+    /// Encode the bit field
+    fn from(value: StatusFlags) -> Self {
+        let mut flags = 0;
+        flags = set(flags, 0, value.docked);
+        flags = set(flags, 1, value.landed);
+        flags = set(flags, 2, value.landing_gear_down);
+        flags = set(flags, 3, value.shields_up);
+        flags = set(flags, 4, value.supercruise);
+        flags = set(flags, 5, value.flight_assist_off);
+        flags = set(flags, 6, value.hardpoints_deployed);
+        flags = set(flags, 7, value.in_wing);
+        flags = set(flags, 8, value.lights_on);
+        flags = set(flags, 9, value.cargo_scoop_deployed);
+        flags = set(flags, 10, value.silent_running);
+        flags = set(flags, 11, value.scooping_fuel);
+        flags = set(flags, 12, value.srv_handbrake);
+        flags = set(flags, 13, value.srv_turret_view);
+        flags = set(flags, 14, value.srv_turret_retracted);
+        flags = set(flags, 15, value.srv_drive_assist);
+        flags = set(flags, 16, value.fsd_mass_locked);
+        flags = set(flags, 17, value.fsd_charging);
+        flags = set(flags, 18, value.fsd_cooldown);
+        flags = set(flags, 19, value.low_fuel);
+        flags = set(flags, 20, value.over_heating);
+        flags = set(flags, 21, value.has_lat_long);
+        flags = set(flags, 22, value.is_in_danger);
+        flags = set(flags, 23, value.being_interdicted);
+        flags = set(flags, 24, value.in_main_ship);
+        flags = set(flags, 25, value.in_fighter);
+        flags = set(flags, 26, value.in_srv);
+        flags = set(flags, 27, value.hud_analysis_mode);
+        flags = set(flags, 28, value.night_vision);
+        flags = set(flags, 29, value.altitude_from_average_radius);
+        flags = set(flags, 30, value.fsd_jump);
+        set(flags, 31, value.srv_high_beam)
+    }
+}
+
+/// The decoded ```Flags2``` bit field from the status file (added in Odyssey)
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Status%20File/
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(from = "u32", into = "u32")]
+pub struct StatusFlags2 {
+    /// Commander is on foot
+    pub on_foot: bool,
+    /// Commander is in a taxi (or dropship/shuttle)
+    pub in_taxi: bool,
+    /// Commander is in someone else's ship (multicrew)
+    pub in_multicrew: bool,
+    /// Commander is on foot in a station
+    pub on_foot_in_station: bool,
+    /// Commander is on foot on a planet
+    pub on_foot_on_planet: bool,
+    /// Commander is aiming down sight
+    pub aim_down_sight: bool,
+    /// Oxygen is low
+    pub low_oxygen: bool,
+    /// Health is low
+    pub low_health: bool,
+    /// Commander is cold
+    pub cold: bool,
+    /// Commander is hot
+    pub hot: bool,
+    /// Commander is very cold
+    pub very_cold: bool,
+    /// Commander is very hot
+    pub very_hot: bool,
+    /// Commander is in glide mode
+    pub glide_mode: bool,
+    /// Commander is on foot in a hangar
+    pub on_foot_in_hangar: bool,
+    /// Commander is on foot in a social space
+    pub on_foot_social_space: bool,
+    /// Commander is on foot outside
+    pub on_foot_exterior: bool,
+    /// The atmosphere is breathable
+    pub breathable_atmosphere: bool,
+    /// Commander is in multicrew via telepresence
+    pub telepresence_multicrew: bool,
+    /// Commander is physically in a multicrew ship
+    pub physical_multicrew: bool,
+    /// Frame shift drive is charging for a hyperspace jump
+    pub fsd_hyperdrive_charging: bool,
+}
+
+impl From<u32> for StatusFlags2 {
+    /// Decode the bit field
+    fn from(value: u32) -> Self {
+        Self {
+            on_foot: is_set(value, 0),
+            in_taxi: is_set(value, 1),
+            in_multicrew: is_set(value, 2),
+            on_foot_in_station: is_set(value, 3),
+            on_foot_on_planet: is_set(value, 4),
+            aim_down_sight: is_set(value, 5),
+            low_oxygen: is_set(value, 6),
+            low_health: is_set(value, 7),
+            cold: is_set(value, 8),
+            hot: is_set(value, 9),
+            very_cold: is_set(value, 10),
+            very_hot: is_set(value, 11),
+            glide_mode: is_set(value, 12),
+            on_foot_in_hangar: is_set(value, 13),
+            on_foot_social_space: is_set(value, 14),
+            on_foot_exterior: is_set(value, 15),
+            breathable_atmosphere: is_set(value, 16),
+            telepresence_multicrew: is_set(value, 17),
+            physical_multicrew: is_set(value, 18),
+            fsd_hyperdrive_charging: is_set(value, 19),
+        }
+    }
+}
+
+impl From<StatusFlags2> for u32 {
+    /// Encode the bit field
+    fn from(value: StatusFlags2) -> Self {
+        let mut flags = 0;
+        flags = set(flags, 0, value.on_foot);
+        flags = set(flags, 1, value.in_taxi);
+        flags = set(flags, 2, value.in_multicrew);
+        flags = set(flags, 3, value.on_foot_in_station);
+        flags = set(flags, 4, value.on_foot_on_planet);
+        flags = set(flags, 5, value.aim_down_sight);
+        flags = set(flags, 6, value.low_oxygen);
+        flags = set(flags, 7, value.low_health);
+        flags = set(flags, 8, value.cold);
+        flags = set(flags, 9, value.hot);
+        flags = set(flags, 10, value.very_cold);
+        flags = set(flags, 11, value.very_hot);
+        flags = set(flags, 12, value.glide_mode);
+        flags = set(flags, 13, value.on_foot_in_hangar);
+        flags = set(flags, 14, value.on_foot_social_space);
+        flags = set(flags, 15, value.on_foot_exterior);
+        flags = set(flags, 16, value.breathable_atmosphere);
+        flags = set(flags, 17, value.telepresence_multicrew);
+        flags = set(flags, 18, value.physical_multicrew);
+        set(flags, 19, value.fsd_hyperdrive_charging)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_flags_decode() {
+        // Docked, Landing gear down, Shields up, FSD mass locked, In main ship
+        let flags = StatusFlags::from(16842765);
+
+        assert!(flags.docked);
+        assert!(!flags.landed);
+        assert!(flags.landing_gear_down);
+        assert!(flags.shields_up);
+        assert!(!flags.supercruise);
+        assert!(flags.fsd_mass_locked);
+        assert!(flags.in_main_ship);
+        assert!(!flags.in_srv);
+    }
+
+    #[test]
+    fn test_status_flags_round_trip() {
+        for value in [0u32, 16842765, 0x8000_0001, u32::MAX] {
+            assert_eq!(u32::from(StatusFlags::from(value)), value);
+        }
+    }
+
+    #[test]
+    fn test_status_flags2_decode() {
+        // On foot, On foot on planet, Breathable atmosphere
+        let flags = StatusFlags2::from(65553);
+
+        assert!(flags.on_foot);
+        assert!(flags.on_foot_on_planet);
+        assert!(flags.breathable_atmosphere);
+        assert!(!flags.in_taxi);
+        assert!(!flags.low_oxygen);
+    }
+
+    #[test]
+    fn test_status_flags2_round_trip() {
+        for value in [0u32, 65553, 0x000F_FFFF] {
+            assert_eq!(u32::from(StatusFlags2::from(value)), value);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::events::status::status_file::{Destination, GuiFocus, LegalState, Pips, StatusFile};
+
+/// A fine grained change in state determined by comparing two consecutive [StatusFile](StatusFile) snapshots
+///
+/// This saves every consumer having to track the previous status and re-implement the diffing of the raw
+/// bit fields. The changes are produced by [StatusChange::diff](StatusChange::diff)
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatusChange {
+    /// The ship has docked
+    Docked,
+    /// The ship has undocked
+    Undocked,
+    /// The ship has touched down on a planet
+    Landed,
+    /// The ship has lifted off from a planet
+    LiftedOff,
+    /// The landing gear has been lowered
+    LandingGearDeployed,
+    /// The landing gear has been raised
+    LandingGearRetracted,
+    /// The shields have come back online
+    ShieldsUp,
+    /// The shields have gone down
+    ShieldsDown,
+    /// The ship has entered supercruise
+    SupercruiseEntered,
+    /// The ship has dropped out of supercruise
+    SupercruiseExited,
+    /// Flight assist has been turned off
+    FlightAssistOff,
+    /// Flight assist has been turned back on
+    FlightAssistOn,
+    /// The hardpoints have been deployed
+    HardpointsDeployed,
+    /// The hardpoints have been retracted
+    HardpointsRetracted,
+    /// The lights have been turned on
+    LightsOn,
+    /// The lights have been turned off
+    LightsOff,
+    /// The cargo scoop has been deployed
+    CargoScoopDeployed,
+    /// The cargo scoop has been retracted
+    CargoScoopRetracted,
+    /// Silent running has been activated
+    SilentRunningOn,
+    /// Silent running has been deactivated
+    SilentRunningOff,
+    /// The ship has started scooping fuel
+    FuelScoopingStarted,
+    /// The ship has stopped scooping fuel
+    FuelScoopingStopped,
+    /// The FSD has become mass locked
+    MassLocked,
+    /// The FSD is no longer mass locked
+    MassLockReleased,
+    /// The FSD has started charging
+    FsdChargeStarted,
+    /// The FSD has stopped charging (either jumped or cancelled)
+    FsdChargeStopped,
+    /// The fuel has dropped below 25%
+    FuelLow,
+    /// The fuel is no longer low
+    FuelOk,
+    /// The ship has started overheating
+    OverheatingStarted,
+    /// The ship is no longer overheating
+    OverheatingStopped,
+    /// The commander is in danger
+    DangerStarted,
+    /// The commander is no longer in danger
+    DangerEnded,
+    /// The ship is being interdicted
+    InterdictionStarted,
+    /// The ship is no longer being interdicted
+    InterdictionEnded,
+    /// The HUD has been switched to analysis mode
+    HudAnalysisMode,
+    /// The HUD has been switched to combat mode
+    HudCombatMode,
+    /// Night vision has been turned on
+    NightVisionOn,
+    /// Night vision has been turned off
+    NightVisionOff,
+    /// The commander's oxygen is low (on foot)
+    OxygenLow,
+    /// The commander's oxygen is no longer low (on foot)
+    OxygenOk,
+    /// The commander's health is low (on foot)
+    HealthLow,
+    /// The commander's health is no longer low (on foot)
+    HealthOk,
+    /// The gui focus has changed to the contained value
+    GuiFocusChanged(GuiFocus),
+    /// The pips have been redistributed to the contained value
+    PipsChanged(Pips),
+    /// The selected fire group has changed to the contained value
+    FireGroupChanged(u32),
+    /// The legal state has changed to the contained value
+    LegalStateChanged(LegalState),
+    /// The legal state has changed from clean to the contained (non clean) value
+    EnteredDangerousLegalState(LegalState),
+    /// The legal state has returned to clean
+    LeftDangerousLegalState,
+    /// The selected destination has changed, None if the destination has been cleared
+    DestinationChanged(Option<Destination>),
+}
+
+/// Returns the on change if the flag has been set, the off change if it has been cleared or None if unchanged
+fn flag_change(previous: bool, current: bool, on: StatusChange, off: StatusChange) -> Option<StatusChange> {
+    match (previous, current) {
+        (false, true) => Some(on),
+        (true, false) => Some(off),
+        _ => None,
+    }
+}
+
+impl StatusChange {
+
+    /// Compare two consecutive status snapshots and return the changes between them, in the order the fields
+    /// appear in the status file. An empty vector is returned if nothing of interest has changed
+    pub fn diff(previous: &StatusFile, current: &StatusFile) -> Vec<StatusChange> {
+        let prev = &previous.flags;
+        let curr = &current.flags;
+
+        let mut changes: Vec<StatusChange> = [
+            flag_change(prev.docked, curr.docked, StatusChange::Docked, StatusChange::Undocked),
+            flag_change(prev.landed, curr.landed, StatusChange::Landed, StatusChange::LiftedOff),
+            flag_change(prev.landing_gear_down, curr.landing_gear_down, StatusChange::LandingGearDeployed, StatusChange::LandingGearRetracted),
+            flag_change(prev.shields_up, curr.shields_up, StatusChange::ShieldsUp, StatusChange::ShieldsDown),
+            flag_change(prev.supercruise, curr.supercruise, StatusChange::SupercruiseEntered, StatusChange::SupercruiseExited),
+            flag_change(prev.flight_assist_off, curr.flight_assist_off, StatusChange::FlightAssistOff, StatusChange::FlightAssistOn),
+            flag_change(prev.hardpoints_deployed, curr.hardpoints_deployed, StatusChange::HardpointsDeployed, StatusChange::HardpointsRetracted),
+            flag_change(prev.lights_on, curr.lights_on, StatusChange::LightsOn, StatusChange::LightsOff),
+            flag_change(prev.cargo_scoop_deployed, curr.cargo_scoop_deployed, StatusChange::CargoScoopDeployed, StatusChange::CargoScoopRetracted),
+            flag_change(prev.silent_running, curr.silent_running, StatusChange::SilentRunningOn, StatusChange::SilentRunningOff),
+            flag_change(prev.scooping_fuel, curr.scooping_fuel, StatusChange::FuelScoopingStarted, StatusChange::FuelScoopingStopped),
+            flag_change(prev.fsd_mass_locked, curr.fsd_mass_locked, StatusChange::MassLocked, StatusChange::MassLockReleased),
+            flag_change(prev.fsd_charging, curr.fsd_charging, StatusChange::FsdChargeStarted, StatusChange::FsdChargeStopped),
+            flag_change(prev.low_fuel, curr.low_fuel, StatusChange::FuelLow, StatusChange::FuelOk),
+            flag_change(prev.over_heating, curr.over_heating, StatusChange::OverheatingStarted, StatusChange::OverheatingStopped),
+            flag_change(prev.is_in_danger, curr.is_in_danger, StatusChange::DangerStarted, StatusChange::DangerEnded),
+            flag_change(prev.being_interdicted, curr.being_interdicted, StatusChange::InterdictionStarted, StatusChange::InterdictionEnded),
+            flag_change(prev.hud_analysis_mode, curr.hud_analysis_mode, StatusChange::HudAnalysisMode, StatusChange::HudCombatMode),
+            flag_change(prev.night_vision, curr.night_vision, StatusChange::NightVisionOn, StatusChange::NightVisionOff),
+        ].into_iter().flatten().collect();
+
+        let prev2 = previous.flags2.unwrap_or_default();
+        let curr2 = current.flags2.unwrap_or_default();
+        changes.extend([
+            flag_change(prev2.low_oxygen, curr2.low_oxygen, StatusChange::OxygenLow, StatusChange::OxygenOk),
+            flag_change(prev2.low_health, curr2.low_health, StatusChange::HealthLow, StatusChange::HealthOk),
+        ].into_iter().flatten());
+
+        if let Some(pips) = current.pips {
+            if previous.pips != Some(pips) {
+                changes.push(StatusChange::PipsChanged(pips));
+            }
+        }
+
+        if let Some(fire_group) = current.fire_group {
+            if previous.fire_group != Some(fire_group) {
+                changes.push(StatusChange::FireGroupChanged(fire_group));
+            }
+        }
+
+        if let Some(gui_focus) = current.gui_focus {
+            if previous.gui_focus != Some(gui_focus) {
+                changes.push(StatusChange::GuiFocusChanged(gui_focus));
+            }
+        }
+
+        if let Some(legal_state) = &current.legal_state {
+            if previous.legal_state.as_ref() != Some(legal_state) {
+                let was_dangerous = previous.legal_state.as_ref().is_some_and(LegalState::is_dangerous);
+                changes.push(StatusChange::LegalStateChanged(legal_state.clone()));
+                if legal_state.is_dangerous() && !was_dangerous {
+                    changes.push(StatusChange::EnteredDangerousLegalState(legal_state.clone()));
+                } else if !legal_state.is_dangerous() && was_dangerous {
+                    changes.push(StatusChange::LeftDangerousLegalState);
+                }
+            }
+        }
+
+        if previous.destination != current.destination {
+            changes.push(StatusChange::DestinationChanged(current.destination.clone()));
+        }
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(json: &str) -> StatusFile {
+        serde_json::from_str(json).expect("Failed to deserialize StatusFile")
+    }
+
+    #[test]
+    fn test_diff_flags() {
+        // Docked, landing gear down, shields up, mass locked, in main ship
+        let previous = status(r#"{ "timestamp":"2025-01-04T19:31:02Z", "event":"Status", "Flags":16842765, "Pips":[4,8,0], "FireGroup":0, "GuiFocus":0, "LegalState":"Clean" }"#);
+        // Landing gear up, shields up, mass locked, in main ship, hardpoints deployed
+        let current = status(r#"{ "timestamp":"2025-01-04T19:31:05Z", "event":"Status", "Flags":16842824, "Pips":[4,8,0], "FireGroup":0, "GuiFocus":0, "LegalState":"Clean" }"#);
+
+        let changes = StatusChange::diff(&previous, &current);
+
+        assert_eq!(changes, vec![StatusChange::Undocked, StatusChange::LandingGearRetracted, StatusChange::HardpointsDeployed]);
+    }
+
+    #[test]
+    fn test_diff_no_changes() {
+        let json = r#"{ "timestamp":"2025-01-04T19:31:02Z", "event":"Status", "Flags":16842765, "Pips":[4,8,0], "FireGroup":0, "GuiFocus":0, "LegalState":"Clean" }"#;
+
+        assert!(StatusChange::diff(&status(json), &status(json)).is_empty());
+    }
+
+    #[test]
+    fn test_diff_values() {
+        let previous = status(r#"{ "timestamp":"2025-01-04T19:31:02Z", "event":"Status", "Flags":16777224, "Pips":[4,8,0], "FireGroup":0, "GuiFocus":0, "LegalState":"Clean" }"#);
+        let current = status(r#"{ "timestamp":"2025-01-04T19:31:05Z", "event":"Status", "Flags":16777224, "Pips":[2,4,6], "FireGroup":1, "GuiFocus":6, "LegalState":"Wanted" }"#);
+
+        let changes = StatusChange::diff(&previous, &current);
+
+        assert_eq!(changes, vec![
+            StatusChange::PipsChanged(Pips { systems: 2, engines: 4, weapons: 6 }),
+            StatusChange::FireGroupChanged(1),
+            StatusChange::GuiFocusChanged(GuiFocus::GalaxyMap),
+            StatusChange::LegalStateChanged(LegalState::Wanted),
+            StatusChange::EnteredDangerousLegalState(LegalState::Wanted),
+        ]);
+
+        let changes = StatusChange::diff(&current, &previous);
+        assert!(changes.contains(&StatusChange::LeftDangerousLegalState));
+        assert!(changes.contains(&StatusChange::GuiFocusChanged(GuiFocus::NoFocus)));
+    }
+}
@@ -0,0 +1,518 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, EventMeta, LocalisedValue};
+use crate::events::status::flags::{StatusFlags, StatusFlags2};
+
+/// The distribution of power to systems, engines and weapons
+///
+/// The values are in half pips, so a value of 8 is a full 4 pips. In the file this is represented as an array:
+///
+/// ```json
+/// "Pips": [4, 8, 0]
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pips {
+    /// Half pips in systems
+    pub systems: u8,
+    /// Half pips in engines
+    pub engines: u8,
+    /// Half pips in weapons
+    pub weapons: u8,
+}
+
+impl Serialize for Pips {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+    {
+        (self.systems, self.engines, self.weapons).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pips {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        Deserialize::deserialize(deserializer)
+            .map(|(systems, engines, weapons)| Pips { systems, engines, weapons })
+    }
+}
+
+/// Which screen/panel currently has the focus in the game
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum GuiFocus {
+    /// No panel has focus
+    NoFocus,
+    /// The internal (right hand) panel
+    InternalPanel,
+    /// The external (left hand) panel
+    ExternalPanel,
+    /// The comms (top) panel
+    CommsPanel,
+    /// The role (bottom) panel
+    RolePanel,
+    /// The station services screen
+    StationServices,
+    /// The galaxy map
+    GalaxyMap,
+    /// The system map
+    SystemMap,
+    /// The orrery
+    Orrery,
+    /// The full spectrum system scanner
+    FSSMode,
+    /// The surface area analysis (detailed surface scanner)
+    SAAMode,
+    /// The codex
+    Codex,
+    /// Catch all for a focus value not yet known
+    Unknown(u8),
+}
+
+impl From<u8> for GuiFocus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => GuiFocus::NoFocus,
+            1 => GuiFocus::InternalPanel,
+            2 => GuiFocus::ExternalPanel,
+            3 => GuiFocus::CommsPanel,
+            4 => GuiFocus::RolePanel,
+            5 => GuiFocus::StationServices,
+            6 => GuiFocus::GalaxyMap,
+            7 => GuiFocus::SystemMap,
+            8 => GuiFocus::Orrery,
+            9 => GuiFocus::FSSMode,
+            10 => GuiFocus::SAAMode,
+            11 => GuiFocus::Codex,
+            v => GuiFocus::Unknown(v),
+        }
+    }
+}
+
+impl From<GuiFocus> for u8 {
+    fn from(value: GuiFocus) -> Self {
+        match value {
+            GuiFocus::NoFocus => 0,
+            GuiFocus::InternalPanel => 1,
+            GuiFocus::ExternalPanel => 2,
+            GuiFocus::CommsPanel => 3,
+            GuiFocus::RolePanel => 4,
+            GuiFocus::StationServices => 5,
+            GuiFocus::GalaxyMap => 6,
+            GuiFocus::SystemMap => 7,
+            GuiFocus::Orrery => 8,
+            GuiFocus::FSSMode => 9,
+            GuiFocus::SAAMode => 10,
+            GuiFocus::Codex => 11,
+            GuiFocus::Unknown(v) => v,
+        }
+    }
+}
+
+/// The legal state of the commander
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum LegalState {
+    /// Commander is clean
+    Clean,
+    /// Commander is carrying illegal cargo
+    IllegalCargo,
+    /// Commander is speeding
+    Speeding,
+    /// Commander is wanted
+    Wanted,
+    /// Commander is hostile
+    Hostile,
+    /// Commander is carrying a wanted passenger
+    PassengerWanted,
+    /// Commander has a warrant
+    Warrant,
+    /// Catch all for any other legal state
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl LegalState {
+
+    /// Returns true if the legal state is anything other than clean, i.e. the commander may be attacked by
+    /// system security or is carrying something that could get them fined
+    pub fn is_dangerous(&self) -> bool {
+        !matches!(self, LegalState::Clean)
+    }
+}
+
+/// The fuel levels of the ship
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Fuel {
+    /// The fuel in the main tank
+    pub fuel_main: f32,
+    /// The fuel in the reservoir
+    pub fuel_reservoir: f32,
+}
+
+/// The currently selected destination
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "DestinationSchema", into = "DestinationSchema")]
+pub struct Destination {
+    /// The system address of the destination
+    pub system: u64,
+    /// The body id of the destination
+    pub body: u32,
+    /// The name of the destination
+    pub name: LocalisedValue,
+}
+
+impl From<DestinationSchema> for Destination {
+    fn from(value: DestinationSchema) -> Self {
+        Self {
+            system: value.system,
+            body: value.body,
+            name: LocalisedValue::new(value.name, value.name_localised),
+        }
+    }
+}
+
+/// As the destination contains a localised value we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DestinationSchema {
+    /// The system address of the destination
+    pub system: u64,
+    /// The body id of the destination
+    pub body: u32,
+    /// The name of the destination
+    pub name: String,
+    /// The localised name of the destination
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+}
+
+impl From<Destination> for DestinationSchema {
+    fn from(value: Destination) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            system: value.system,
+            body: value.body,
+            name,
+            name_localised,
+        }
+    }
+}
+
+/// The contents of the Status.json file, this is rewritten by the game several times a second
+/// whenever the state of the ship/commander changes
+///
+/// Example:
+/// ```json
+/// {
+///   "timestamp": "2025-01-04T19:31:02Z",
+///   "event": "Status",
+///   "Flags": 16842765,
+///   "Flags2": 0,
+///   "Pips": [4, 8, 0],
+///   "FireGroup": 0,
+///   "GuiFocus": 0,
+///   "Fuel": { "FuelMain": 32.000000, "FuelReservoir": 0.630000 },
+///   "Cargo": 0.000000,
+///   "LegalState": "Clean",
+///   "Balance": 53038302
+/// }
+/// ```
+///
+/// **Note**: When the game is not running (or in the main menu) only the timestamp and ```Flags``` are present
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Status%20File/
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "StatusFileSchema", into = "StatusFileSchema")]
+pub struct StatusFile {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The decoded ```Flags``` bit field
+    pub flags: StatusFlags,
+
+    /// The decoded ```Flags2``` bit field (Odyssey only)
+    pub flags2: Option<StatusFlags2>,
+
+    /// The current power distribution
+    pub pips: Option<Pips>,
+
+    /// The currently selected fire group
+    pub fire_group: Option<u32>,
+
+    /// The panel/screen that currently has focus
+    pub gui_focus: Option<GuiFocus>,
+
+    /// The current fuel levels
+    pub fuel: Option<Fuel>,
+
+    /// The current cargo mass in tons
+    pub cargo: Option<f32>,
+
+    /// The current legal state
+    pub legal_state: Option<LegalState>,
+
+    /// Latitude when near a planet
+    pub latitude: Option<f64>,
+
+    /// Longitude when near a planet
+    pub longitude: Option<f64>,
+
+    /// Altitude when near a planet
+    pub altitude: Option<f64>,
+
+    /// Heading when near a planet
+    pub heading: Option<f64>,
+
+    /// The name of the body when near a planet
+    pub body_name: Option<String>,
+
+    /// The radius of the planet when near a planet
+    pub planet_radius: Option<f64>,
+
+    /// The credit balance of the commander
+    pub balance: Option<i64>,
+
+    /// The selected destination
+    pub destination: Option<Destination>,
+
+    /// The oxygen level when on foot (0.0 - 1.0)
+    pub oxygen: Option<f32>,
+
+    /// The health when on foot (0.0 - 1.0)
+    pub health: Option<f32>,
+
+    /// The temperature in kelvin when on foot
+    pub temperature: Option<f32>,
+
+    /// The weapon selected when on foot
+    pub selected_weapon: Option<LocalisedValue>,
+
+    /// The gravity in G when on foot
+    pub gravity: Option<f32>,
+}
+
+impl From<StatusFileSchema> for StatusFile {
+    /// Convert from StatusFileSchema
+    fn from(value: StatusFileSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            flags: value.flags,
+            flags2: value.flags2,
+            pips: value.pips,
+            fire_group: value.fire_group,
+            gui_focus: value.gui_focus,
+            fuel: value.fuel,
+            cargo: value.cargo,
+            legal_state: value.legal_state,
+            latitude: value.latitude,
+            longitude: value.longitude,
+            altitude: value.altitude,
+            heading: value.heading,
+            body_name: value.body_name,
+            planet_radius: value.planet_radius,
+            balance: value.balance,
+            destination: value.destination,
+            oxygen: value.oxygen,
+            health: value.health,
+            temperature: value.temperature,
+            selected_weapon: LocalisedValue::new_optional(value.selected_weapon, value.selected_weapon_localised),
+            gravity: value.gravity,
+        }
+    }
+}
+
+/// As the status file contains a localised value we need an intermediary struct
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct StatusFileSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The ```Flags``` bit field
+    #[serde(default)]
+    pub flags: StatusFlags,
+
+    /// The ```Flags2``` bit field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags2: Option<StatusFlags2>,
+
+    /// The current power distribution
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pips: Option<Pips>,
+
+    /// The currently selected fire group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fire_group: Option<u32>,
+
+    /// The panel/screen that currently has focus
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gui_focus: Option<GuiFocus>,
+
+    /// The current fuel levels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuel: Option<Fuel>,
+
+    /// The current cargo mass in tons
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cargo: Option<f32>,
+
+    /// The current legal state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_state: Option<LegalState>,
+
+    /// Latitude when near a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+
+    /// Longitude when near a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+
+    /// Altitude when near a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub altitude: Option<f64>,
+
+    /// Heading when near a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<f64>,
+
+    /// The name of the body when near a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_name: Option<String>,
+
+    /// The radius of the planet when near a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub planet_radius: Option<f64>,
+
+    /// The credit balance of the commander
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<i64>,
+
+    /// The selected destination
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<Destination>,
+
+    /// The oxygen level when on foot (0.0 - 1.0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oxygen: Option<f32>,
+
+    /// The health when on foot (0.0 - 1.0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<f32>,
+
+    /// The temperature in kelvin when on foot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    /// The weapon selected when on foot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_weapon: Option<String>,
+
+    /// The localised weapon selected when on foot
+    #[serde(rename = "SelectedWeapon_Localised", skip_serializing_if = "Option::is_none")]
+    pub selected_weapon_localised: Option<String>,
+
+    /// The gravity in G when on foot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gravity: Option<f32>,
+}
+
+impl From<StatusFile> for StatusFileSchema {
+    /// Convert from StatusFile
+    fn from(value: StatusFile) -> Self {
+        let (selected_weapon, selected_weapon_localised) = deconstruct_optional_localised_value(value.selected_weapon);
+        Self {
+            event_meta: value.event_meta,
+            flags: value.flags,
+            flags2: value.flags2,
+            pips: value.pips,
+            fire_group: value.fire_group,
+            gui_focus: value.gui_focus,
+            fuel: value.fuel,
+            cargo: value.cargo,
+            legal_state: value.legal_state,
+            latitude: value.latitude,
+            longitude: value.longitude,
+            altitude: value.altitude,
+            heading: value.heading,
+            body_name: value.body_name,
+            planet_radius: value.planet_radius,
+            balance: value.balance,
+            destination: value.destination,
+            oxygen: value.oxygen,
+            health: value.health,
+            temperature: value.temperature,
+            selected_weapon,
+            selected_weapon_localised,
+            gravity: value.gravity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_status_file_ship() {
+        let json = r#"{ "timestamp":"2025-01-04T19:31:02Z", "event":"Status", "Flags":16842765, "Flags2":0, "Pips":[4,8,0], "FireGroup":0, "GuiFocus":6, "Fuel":{ "FuelMain":32.0, "FuelReservoir":0.63 }, "Cargo":0.0, "LegalState":"Clean", "Balance":53038302, "Destination":{ "System":3107509342922, "Body":0, "Name":"Ngalinn" } }"#;
+
+        let status: StatusFile = serde_json::from_str(json).expect("Failed to deserialize StatusFile");
+
+        assert_eq!(status.event_meta.timestamp, create_timestamp("2025-01-04T19:31:02Z"));
+        assert!(status.flags.docked);
+        assert!(status.flags.landing_gear_down);
+        assert!(status.flags.in_main_ship);
+        assert_eq!(status.flags2, Some(StatusFlags2::default()));
+        assert_eq!(status.pips, Some(Pips { systems: 4, engines: 8, weapons: 0 }));
+        assert_eq!(status.gui_focus, Some(GuiFocus::GalaxyMap));
+        assert_eq!(status.fuel, Some(Fuel { fuel_main: 32.0, fuel_reservoir: 0.63 }));
+        assert_eq!(status.legal_state, Some(LegalState::Clean));
+        assert_eq!(status.balance, Some(53038302));
+        assert_eq!(status.destination.map(|d| d.system), Some(3107509342922));
+        assert_eq!(status.selected_weapon, None);
+    }
+
+    #[test]
+    fn test_deserialize_status_file_on_foot() {
+        let json = r#"{ "timestamp":"2025-01-04T19:31:02Z", "event":"Status", "Flags":2097152, "Flags2":65553, "Oxygen":1.0, "Health":0.75, "Temperature":293.5, "SelectedWeapon":"$humanoid_fists_name;", "SelectedWeapon_Localised":"Unarmed", "Gravity":0.166, "LegalState":"Clean", "Latitude":-22.5, "Longitude":104.25, "Heading":87, "BodyName":"Ngalinn A 1", "PlanetRadius":2439700.0 }"#;
+
+        let status: StatusFile = serde_json::from_str(json).expect("Failed to deserialize StatusFile");
+
+        assert!(status.flags.has_lat_long);
+        let flags2 = status.flags2.expect("Expected Flags2");
+        assert!(flags2.on_foot);
+        assert!(flags2.on_foot_on_planet);
+        assert_eq!(status.oxygen, Some(1.0));
+        assert_eq!(status.health, Some(0.75));
+        assert_eq!(status.selected_weapon, Some(LocalisedValue::new("$humanoid_fists_name;".to_string(), Some("Unarmed".to_string()))));
+        assert_eq!(status.latitude, Some(-22.5));
+        assert_eq!(status.heading, Some(87.0));
+        assert_eq!(status.body_name, Some("Ngalinn A 1".to_string()));
+    }
+
+    #[test]
+    fn test_status_file_game_not_running_round_trip() {
+        let json = r#"{ "timestamp":"2025-01-04T19:31:02Z", "Flags":0 }"#;
+
+        let status: StatusFile = serde_json::from_str(json).expect("Failed to deserialize StatusFile");
+
+        assert_eq!(status.flags, StatusFlags::default());
+        assert_eq!(status.pips, None);
+
+        let serialized: Value = serde_json::to_value(&status).expect("Failed to serialize StatusFile");
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn test_gui_focus_unknown_value() {
+        let focus: GuiFocus = serde_json::from_str("42").unwrap();
+        assert_eq!(focus, GuiFocus::Unknown(42));
+        assert_eq!(serde_json::to_string(&focus).unwrap(), "42");
+    }
+}
@@ -1,2 +1,10 @@
 /// Mining events
-pub mod mining;
\ No newline at end of file
+pub mod mining;
+/// Market buy and sell events
+pub mod market;
+
+/// Cargo collection, ejection and transfer events
+pub mod cargo;
+
+/// Buy trade data event
+pub mod buy_trade_data;
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Trade data for a system has been bought
+///
+/// ```json
+/// { "timestamp":"2025-01-06T20:10:00Z", "event":"BuyTradeData", "System":"i Bootis", "Cost":100 }
+/// ```
+///
+/// ReadTheDocs: [BuyTradeData](https://elite-journal.readthedocs.io/en/latest/Trade/#buytradedata)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuyTradeDataEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The system the data was bought for
+    pub system: String,
+
+    /// The cost of the data
+    pub cost: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_buy_trade_data_event() {
+        let json = r#"{ "timestamp":"2025-01-06T20:10:00Z", "System":"i Bootis", "Cost":100 }"#;
+
+        let event: BuyTradeDataEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-06T20:10:00Z"));
+        assert_eq!(event.system, "i Bootis");
+        assert_eq!(event.cost, 100);
+        assert!(event.extra.is_empty());
+    }
+}
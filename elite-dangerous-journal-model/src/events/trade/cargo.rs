@@ -0,0 +1,315 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Cargo has been scooped into the cargo hold
+///
+/// ```json
+/// { "timestamp":"2025-01-06T21:02:44Z", "event":"CollectCargo", "Type":"$painite_name;", "Type_Localised":"Painite", "Stolen":false }
+/// ```
+///
+/// ReadTheDocs: [CollectCargo](https://elite-journal.readthedocs.io/en/latest/Trade/#collectcargo)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "CollectCargoEventSchema", into = "CollectCargoEventSchema")]
+pub struct CollectCargoEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The commodity collected
+    pub commodity: LocalisedValue,
+
+    /// Is the cargo stolen
+    pub stolen: bool,
+
+    /// The mission the cargo is for
+    pub mission_id: Option<u64>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<CollectCargoEventSchema> for CollectCargoEvent {
+    fn from(value: CollectCargoEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            commodity: LocalisedValue::new(value.commodity, value.commodity_localised),
+            stolen: value.stolen,
+            mission_id: value.mission_id,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CollectCargoEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The commodity collected
+    #[serde(rename = "Type")]
+    pub commodity: String,
+
+    /// The localised commodity collected
+    #[serde(rename = "Type_Localised", skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+
+    /// Is the cargo stolen
+    pub stolen: bool,
+
+    /// The mission the cargo is for
+    #[serde(rename = "MissionID", skip_serializing_if = "Option::is_none")]
+    pub mission_id: Option<u64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<CollectCargoEvent> for CollectCargoEventSchema {
+    fn from(value: CollectCargoEvent) -> Self {
+        let (commodity, commodity_localised) = deconstruct_localised_value(value.commodity);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            commodity,
+            commodity_localised,
+            stolen: value.stolen,
+            mission_id: value.mission_id,
+        }
+    }
+}
+
+/// Cargo has been jettisoned from the cargo hold
+///
+/// ```json
+/// { "timestamp":"2025-01-06T21:05:10Z", "event":"EjectCargo", "Type":"drones", "Type_Localised":"Limpet", "Count":4, "Abandoned":true }
+/// ```
+///
+/// ReadTheDocs: [EjectCargo](https://elite-journal.readthedocs.io/en/latest/Trade/#ejectcargo)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "EjectCargoEventSchema", into = "EjectCargoEventSchema")]
+pub struct EjectCargoEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The commodity ejected
+    pub commodity: LocalisedValue,
+
+    /// The number of units ejected
+    pub count: u32,
+
+    /// Was the cargo abandoned
+    pub abandoned: bool,
+
+    /// The mission the cargo was for
+    pub mission_id: Option<u64>,
+
+    /// The power the cargo was for if it was powerplay cargo
+    pub powerplay_origin: Option<String>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<EjectCargoEventSchema> for EjectCargoEvent {
+    fn from(value: EjectCargoEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            commodity: LocalisedValue::new(value.commodity, value.commodity_localised),
+            count: value.count,
+            abandoned: value.abandoned,
+            mission_id: value.mission_id,
+            powerplay_origin: value.powerplay_origin,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct EjectCargoEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The commodity ejected
+    #[serde(rename = "Type")]
+    pub commodity: String,
+
+    /// The localised commodity ejected
+    #[serde(rename = "Type_Localised", skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+
+    /// The number of units ejected
+    pub count: u32,
+
+    /// Was the cargo abandoned
+    pub abandoned: bool,
+
+    /// The mission the cargo was for
+    #[serde(rename = "MissionID", skip_serializing_if = "Option::is_none")]
+    pub mission_id: Option<u64>,
+
+    /// The power the cargo was for if it was powerplay cargo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub powerplay_origin: Option<String>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<EjectCargoEvent> for EjectCargoEventSchema {
+    fn from(value: EjectCargoEvent) -> Self {
+        let (commodity, commodity_localised) = deconstruct_localised_value(value.commodity);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            commodity,
+            commodity_localised,
+            count: value.count,
+            abandoned: value.abandoned,
+            mission_id: value.mission_id,
+            powerplay_origin: value.powerplay_origin,
+        }
+    }
+}
+
+/// The direction cargo has been transferred in
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum CargoTransferDirection {
+    /// From the fleet carrier or SRV to the ship
+    ToShip,
+    /// From the ship to the SRV
+    ToSrv,
+    /// From the ship to the fleet carrier
+    ToCarrier,
+    /// A direction that is not modelled
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A commodity moved as part of a cargo transfer
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "CargoTransferItemSchema", into = "CargoTransferItemSchema")]
+pub struct CargoTransferItem {
+    /// The commodity transferred
+    pub commodity: LocalisedValue,
+    /// The number of units transferred
+    pub count: u32,
+    /// The direction of the transfer
+    pub direction: CargoTransferDirection,
+    /// The mission the cargo is for
+    pub mission_id: Option<u64>,
+}
+
+impl From<CargoTransferItemSchema> for CargoTransferItem {
+    fn from(value: CargoTransferItemSchema) -> Self {
+        Self {
+            commodity: LocalisedValue::new(value.commodity, value.commodity_localised),
+            count: value.count,
+            direction: value.direction,
+            mission_id: value.mission_id,
+        }
+    }
+}
+
+/// Schema for the cargo transfer item due to the localised value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct CargoTransferItemSchema {
+    #[serde(rename = "Type")]
+    commodity: String,
+    #[serde(rename = "Type_Localised", skip_serializing_if = "Option::is_none")]
+    commodity_localised: Option<String>,
+    count: u32,
+    direction: CargoTransferDirection,
+    #[serde(rename = "MissionID", skip_serializing_if = "Option::is_none")]
+    mission_id: Option<u64>,
+}
+
+impl From<CargoTransferItem> for CargoTransferItemSchema {
+    fn from(value: CargoTransferItem) -> Self {
+        let (commodity, commodity_localised) = deconstruct_localised_value(value.commodity);
+        Self {
+            commodity,
+            commodity_localised,
+            count: value.count,
+            direction: value.direction,
+            mission_id: value.mission_id,
+        }
+    }
+}
+
+/// Cargo has been transferred between the ship and a fleet carrier or SRV
+///
+/// ```json
+/// { "timestamp":"2025-01-06T21:40:12Z", "event":"CargoTransfer", "Transfers":[ { "Type":"tritium", "Count":200, "Direction":"tocarrier" } ] }
+/// ```
+///
+/// ReadTheDocs: [CargoTransfer](https://elite-journal.readthedocs.io/en/latest/Trade/#cargotransfer)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct CargoTransferEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The commodities transferred
+    pub transfers: Vec<CargoTransferItem>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_collect_cargo_event() {
+        let json = r#"{"timestamp":"2025-01-06T21:02:44Z","Type":"$painite_name;","Type_Localised":"Painite","Stolen":false}"#;
+
+        let event: CollectCargoEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-06T21:02:44Z"));
+        assert_eq!(event.commodity, LocalisedValue::new("$painite_name;".to_string(), Some("Painite".to_string())));
+        assert!(!event.stolen);
+        assert_eq!(event.mission_id, None);
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_eject_cargo_event() {
+        let json = r#"{ "timestamp":"2025-01-06T21:05:10Z", "Type":"drones", "Type_Localised":"Limpet", "Count":4, "Abandoned":true, "MissionID":998877665 }"#;
+
+        let event: EjectCargoEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.commodity.value, "drones");
+        assert_eq!(event.count, 4);
+        assert!(event.abandoned);
+        assert_eq!(event.mission_id, Some(998877665));
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_cargo_transfer_event() {
+        let json = r#"{ "timestamp":"2025-01-06T21:40:12Z", "Transfers":[ { "Type":"tritium", "Count":200, "Direction":"tocarrier" }, { "Type":"drones", "Type_Localised":"Limpet", "Count":8, "Direction":"toship" }, { "Type":"gold", "Count":1, "Direction":"tobuggy" } ] }"#;
+
+        let event: CargoTransferEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.transfers.len(), 3);
+        assert_eq!(event.transfers[0].direction, CargoTransferDirection::ToCarrier);
+        assert_eq!(event.transfers[1].commodity, LocalisedValue::new("drones".to_string(), Some("Limpet".to_string())));
+        assert_eq!(event.transfers[1].direction, CargoTransferDirection::ToShip);
+        assert_eq!(event.transfers[2].direction, CargoTransferDirection::Other("tobuggy".to_string()));
+    }
+}
@@ -0,0 +1,273 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Commodities have been bought at a market
+///
+/// ```json
+/// { "timestamp":"2025-01-06T20:14:51Z", "event":"MarketBuy", "MarketID":3228883456, "Type":"gold", "Count":64, "BuyPrice":45390, "TotalCost":2904960 }
+/// ```
+///
+/// ReadTheDocs: [MarketBuy](https://elite-journal.readthedocs.io/en/latest/Trade/#marketbuy)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "MarketBuyEventSchema", into = "MarketBuyEventSchema")]
+pub struct MarketBuyEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The commodity bought
+    pub commodity: LocalisedValue,
+
+    /// The number of units bought
+    pub count: u32,
+
+    /// The price paid per unit
+    pub buy_price: u64,
+
+    /// The total price paid
+    pub total_cost: u64,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<MarketBuyEventSchema> for MarketBuyEvent {
+    fn from(value: MarketBuyEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            commodity: LocalisedValue::new(value.commodity, value.commodity_localised),
+            count: value.count,
+            buy_price: value.buy_price,
+            total_cost: value.total_cost,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MarketBuyEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The commodity bought
+    #[serde(rename = "Type")]
+    pub commodity: String,
+
+    /// The localised commodity bought
+    #[serde(rename = "Type_Localised", skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+
+    /// The number of units bought
+    pub count: u32,
+
+    /// The price paid per unit
+    pub buy_price: u64,
+
+    /// The total price paid
+    pub total_cost: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<MarketBuyEvent> for MarketBuyEventSchema {
+    fn from(value: MarketBuyEvent) -> Self {
+        let (commodity, commodity_localised) = deconstruct_localised_value(value.commodity);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            commodity,
+            commodity_localised,
+            count: value.count,
+            buy_price: value.buy_price,
+            total_cost: value.total_cost,
+        }
+    }
+}
+
+/// Commodities have been sold at a market
+///
+/// ```json
+/// { "timestamp":"2025-01-06T20:31:02Z", "event":"MarketSell", "MarketID":3223343616, "Type":"gold", "Count":64, "SellPrice":47812, "TotalSale":3059968, "AvgPricePaid":45390 }
+/// ```
+///
+/// ReadTheDocs: [MarketSell](https://elite-journal.readthedocs.io/en/latest/Trade/#marketsell)
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(from = "MarketSellEventSchema", into = "MarketSellEventSchema")]
+pub struct MarketSellEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    pub market_id: u64,
+
+    /// The commodity sold
+    pub commodity: LocalisedValue,
+
+    /// The number of units sold
+    pub count: u32,
+
+    /// The price received per unit
+    pub sell_price: u64,
+
+    /// The total price received
+    pub total_sale: u64,
+
+    /// The average price paid per unit for the commodity, zero for mined or collected commodities
+    pub avg_price_paid: u64,
+
+    /// Were the goods illegal in the system
+    pub illegal_goods: Option<bool>,
+
+    /// Were the goods stolen
+    pub stolen_goods: Option<bool>,
+
+    /// Were the goods sold to a black market
+    pub black_market: Option<bool>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl MarketSellEvent {
+
+    /// The profit made on the sale based on the average price paid
+    pub fn profit(&self) -> i64 {
+        self.total_sale as i64 - (self.avg_price_paid * self.count as u64) as i64
+    }
+}
+
+impl From<MarketSellEventSchema> for MarketSellEvent {
+    fn from(value: MarketSellEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            commodity: LocalisedValue::new(value.commodity, value.commodity_localised),
+            count: value.count,
+            sell_price: value.sell_price,
+            total_sale: value.total_sale,
+            avg_price_paid: value.avg_price_paid,
+            illegal_goods: value.illegal_goods,
+            stolen_goods: value.stolen_goods,
+            black_market: value.black_market,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MarketSellEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The id of the market
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The commodity sold
+    #[serde(rename = "Type")]
+    pub commodity: String,
+
+    /// The localised commodity sold
+    #[serde(rename = "Type_Localised", skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+
+    /// The number of units sold
+    pub count: u32,
+
+    /// The price received per unit
+    pub sell_price: u64,
+
+    /// The total price received
+    pub total_sale: u64,
+
+    /// The average price paid per unit for the commodity
+    pub avg_price_paid: u64,
+
+    /// Were the goods illegal in the system
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub illegal_goods: Option<bool>,
+
+    /// Were the goods stolen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stolen_goods: Option<bool>,
+
+    /// Were the goods sold to a black market
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub black_market: Option<bool>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<MarketSellEvent> for MarketSellEventSchema {
+    fn from(value: MarketSellEvent) -> Self {
+        let (commodity, commodity_localised) = deconstruct_localised_value(value.commodity);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            market_id: value.market_id,
+            commodity,
+            commodity_localised,
+            count: value.count,
+            sell_price: value.sell_price,
+            total_sale: value.total_sale,
+            avg_price_paid: value.avg_price_paid,
+            illegal_goods: value.illegal_goods,
+            stolen_goods: value.stolen_goods,
+            black_market: value.black_market,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_market_buy_event() {
+        let json = r#"{ "timestamp":"2025-01-06T20:14:51Z", "MarketID":3228883456, "Type":"gold", "Count":64, "BuyPrice":45390, "TotalCost":2904960 }"#;
+
+        let event: MarketBuyEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-06T20:14:51Z"));
+        assert_eq!(event.market_id, 3228883456);
+        assert_eq!(event.commodity, LocalisedValue::new("gold".to_string(), None));
+        assert_eq!(event.count, 64);
+        assert_eq!(event.total_cost, 2904960);
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_serialize_market_sell_event() {
+        let json = r#"{"timestamp":"2025-01-06T20:31:02Z","MarketID":3223343616,"Type":"$painite_name;","Type_Localised":"Painite","Count":10,"SellPrice":210000,"TotalSale":2100000,"AvgPricePaid":0,"StolenGoods":true,"BlackMarket":true}"#;
+
+        let event: MarketSellEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.commodity, LocalisedValue::new("$painite_name;".to_string(), Some("Painite".to_string())));
+        assert_eq!(event.profit(), 2100000);
+        assert_eq!(event.illegal_goods, None);
+        assert_eq!(event.stolen_goods, Some(true));
+        assert_eq!(event.black_market, Some(true));
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -1,24 +1,27 @@
 use serde::Deserialize;
 use serde_with::serde_derive::Serialize;
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{deconstruct_localised_value, deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
 
 /// Emitted when mining fragments are converted
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(from="MiningRefinedEventSchema", into="MiningRefinedEventSchema")]
 pub struct MiningRefinedEvent {
     
-    /// The event meta data
+    /// The event metadata
     pub event_meta: EventMeta,
     
     /// The type of resource refined
-    pub resource_type: LocalisedValue
+    pub resource_type: LocalisedValue,
     
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<MiningRefinedEventSchema> for MiningRefinedEvent {
     fn from(value: MiningRefinedEventSchema) -> Self {
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             resource_type: LocalisedValue { 
                 value: value.resource_type, 
                 localised_value: value.resource_type_localised 
@@ -39,14 +42,18 @@ pub struct MiningRefinedEventSchema {
     #[serde(rename="Type")]
     pub resource_type: String,
     
-    #[serde(rename="Type_Localised")]
+    #[serde(rename="Type_Localised", skip_serializing_if = "Option::is_none")]
     pub resource_type_localised: Option<String>,
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl From<MiningRefinedEvent> for MiningRefinedEventSchema {
     fn from(value: MiningRefinedEvent) -> Self {
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             resource_type: value.resource_type.value,
             resource_type_localised: value.resource_type.localised_value,
         }
@@ -54,6 +61,188 @@ impl From<MiningRefinedEvent> for MiningRefinedEventSchema {
 }
 
 
+/// A material found in a prospected asteroid
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "ProspectedMaterialSchema", into = "ProspectedMaterialSchema")]
+pub struct ProspectedMaterial {
+    /// The material
+    pub name: LocalisedValue,
+    /// The percentage of the asteroid that is the material
+    pub proportion: f64,
+}
+
+impl From<ProspectedMaterialSchema> for ProspectedMaterial {
+    fn from(value: ProspectedMaterialSchema) -> Self {
+        Self {
+            name: LocalisedValue::new(value.name, value.name_localised),
+            proportion: value.proportion,
+        }
+    }
+}
+
+/// Schema for the prospected material due to the localised value
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ProspectedMaterialSchema {
+    name: String,
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    name_localised: Option<String>,
+    proportion: f64,
+}
+
+impl From<ProspectedMaterial> for ProspectedMaterialSchema {
+    fn from(value: ProspectedMaterial) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            name,
+            name_localised,
+            proportion: value.proportion,
+        }
+    }
+}
+
+/// The material content of a prospected asteroid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentLevel {
+    /// Low material content
+    Low,
+    /// Medium material content
+    Medium,
+    /// High material content
+    High,
+}
+
+/// A prospector limpet has hit an asteroid
+///
+/// ```json
+/// { "timestamp":"2025-01-06T21:00:31Z", "event":"ProspectedAsteroid", "Materials":[ { "Name":"Painite", "Proportion":26.078022 }, { "Name":"Bauxite", "Proportion":10.189009 } ], "MotherlodeMaterial":"Alexandrite", "Content":"$AsteroidMaterialContent_High;", "Content_Localised":"Material Content: High", "Remaining":100.000000 }
+/// ```
+///
+/// ReadTheDocs: [ProspectedAsteroid](https://elite-journal.readthedocs.io/en/latest/Trade/#prospectedasteroid)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "ProspectedAsteroidEventSchema", into = "ProspectedAsteroidEventSchema")]
+pub struct ProspectedAsteroidEvent {
+
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+
+    /// The materials in the asteroid
+    pub materials: Vec<ProspectedMaterial>,
+
+    /// The material of the motherlode (core) if the asteroid has one
+    pub motherlode_material: Option<LocalisedValue>,
+
+    /// The material content of the asteroid
+    pub content: LocalisedValue,
+
+    /// The percentage of the asteroid that is left to mine
+    pub remaining: f64,
+}
+
+impl ProspectedAsteroidEvent {
+
+    /// The material content level of the asteroid, None if it is not recognised
+    pub fn content_level(&self) -> Option<ContentLevel> {
+        match self.content.value.as_str() {
+            "$AsteroidMaterialContent_Low;" => Some(ContentLevel::Low),
+            "$AsteroidMaterialContent_Medium;" => Some(ContentLevel::Medium),
+            "$AsteroidMaterialContent_High;" => Some(ContentLevel::High),
+            _ => None,
+        }
+    }
+
+    /// Does the asteroid have a motherlode (core) that can be cracked
+    pub fn has_motherlode(&self) -> bool {
+        self.motherlode_material.is_some()
+    }
+}
+
+impl From<ProspectedAsteroidEventSchema> for ProspectedAsteroidEvent {
+    fn from(value: ProspectedAsteroidEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            materials: value.materials,
+            motherlode_material: LocalisedValue::new_optional(value.motherlode_material, value.motherlode_material_localised),
+            content: LocalisedValue::new(value.content, value.content_localised),
+            remaining: value.remaining,
+        }
+    }
+}
+
+/// Schema representation of prospected asteroid as contains localised values
+#[allow(missing_docs)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all="PascalCase")]
+pub struct ProspectedAsteroidEventSchema {
+
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    pub materials: Vec<ProspectedMaterial>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub motherlode_material: Option<String>,
+
+    #[serde(rename="MotherlodeMaterial_Localised", skip_serializing_if = "Option::is_none")]
+    pub motherlode_material_localised: Option<String>,
+
+    pub content: String,
+
+    #[serde(rename="Content_Localised", skip_serializing_if = "Option::is_none")]
+    pub content_localised: Option<String>,
+
+    pub remaining: f64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ProspectedAsteroidEvent> for ProspectedAsteroidEventSchema {
+    fn from(value: ProspectedAsteroidEvent) -> Self {
+        let (motherlode_material, motherlode_material_localised) = deconstruct_optional_localised_value(value.motherlode_material);
+        let (content, content_localised) = deconstruct_localised_value(value.content);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            materials: value.materials,
+            motherlode_material,
+            motherlode_material_localised,
+            content,
+            content_localised,
+            remaining: value.remaining,
+        }
+    }
+}
+
+/// A motherlode asteroid has been cracked open
+///
+/// ```json
+/// { "timestamp":"2025-01-06T21:10:45Z", "event":"AsteroidCracked", "Body":"Wregoe TC-X b29-0 A Belt Cluster 4" }
+/// ```
+///
+/// ReadTheDocs: [AsteroidCracked](https://elite-journal.readthedocs.io/en/latest/Trade/#asteroidcracked)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all="PascalCase")]
+pub struct AsteroidCrackedEvent {
+
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the belt cluster or ring the asteroid is in
+    pub body: String,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,4 +271,32 @@ mod tests {
             Some("Gold (Localised)".to_string())
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_deserialize_serialize_prospected_asteroid_event() {
+        let json = r#"{"timestamp":"2025-01-06T21:00:31Z","Materials":[{"Name":"LowTemperatureDiamond","Name_Localised":"Low Temperature Diamonds","Proportion":26.5},{"Name":"Bauxite","Proportion":10.25}],"MotherlodeMaterial":"Alexandrite","Content":"$AsteroidMaterialContent_High;","Content_Localised":"Material Content: High","Remaining":100.0}"#;
+
+        let event: ProspectedAsteroidEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.materials.len(), 2);
+        assert_eq!(event.materials[0].name, LocalisedValue::new("LowTemperatureDiamond".to_string(), Some("Low Temperature Diamonds".to_string())));
+        assert_eq!(event.materials[1].proportion, 10.25);
+        assert!(event.has_motherlode());
+        assert_eq!(event.content_level(), Some(ContentLevel::High));
+        assert_eq!(event.remaining, 100.0);
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_asteroid_cracked_event() {
+        let json = r#"{ "timestamp":"2025-01-06T21:10:45Z", "Body":"Wregoe TC-X b29-0 A Belt Cluster 4" }"#;
+
+        let event: AsteroidCrackedEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-06T21:10:45Z"));
+        assert_eq!(event.body, "Wregoe TC-X b29-0 A Belt Cluster 4");
+        assert!(event.extra.is_empty());
+    }
+}
@@ -7,3 +7,36 @@ pub mod fsd_jump;
 /// Start Jump event
 pub mod start_jump;
 
+
+/// Nav route event and NavRoute.json file
+pub mod nav_route;
+
+/// Location and CarrierJump events
+pub mod location;
+
+/// Docked, Undocked and the docking request events
+pub mod docking;
+
+/// Supercruise entry and exit events
+pub mod supercruise;
+
+/// ApproachBody and LeaveBody events
+pub mod body;
+
+/// Approach settlement event
+pub mod approach_settlement;
+
+/// Touchdown and Liftoff events
+pub mod surface;
+
+/// Embark and Disembark events
+pub mod embark;
+
+/// Jet cone boost event
+pub mod jet_cone_boost;
+
+/// Unidentified signal source drop event
+pub mod uss_drop;
+
+/// Book taxi event
+pub mod book_taxi;
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+use crate::events::common::station::StationInformation;
+
+/// Approaching a planetary settlement
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:27:29Z", "event":"ApproachSettlement", "Name":"Long Sight Base", "MarketID":128678023, "StationFaction":{ "Name":"Elvira Martuuk" }, "StationGovernment":"$government_Engineer;", "StationGovernment_Localised":"Workshop", "StationServices":[ "dock", "autodock", "commodities" ], "StationEconomy":"$economy_Colony;", "StationEconomy_Localised":"Colony", "StationEconomies":[ { "Name":"$economy_Colony;", "Name_Localised":"Colony", "Proportion":1.000000 } ], "SystemAddress":3107241104074, "BodyID":13, "BodyName":"Khun 5", "Latitude":-33.519184, "Longitude":-100.314255 }
+/// ```
+///
+/// ReadTheDocs: [ApproachSettlement](https://elite-journal.readthedocs.io/en/latest/Travel/#approachsettlement)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "ApproachSettlementEventSchema", into = "ApproachSettlementEventSchema")]
+pub struct ApproachSettlementEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The name of the settlement
+    pub name: LocalisedValue,
+
+    /// The market id of the settlement if it has one
+    pub market_id: Option<u64>,
+
+    /// The information about the settlement if it is dockable
+    pub station: Option<StationInformation>,
+
+    /// The address of the system
+    pub system_address: u64,
+
+    /// The id of the body the settlement is on
+    pub body_id: u64,
+
+    /// The name of the body the settlement is on
+    pub body_name: String,
+
+    /// The latitude of the settlement
+    pub latitude: Option<f64>,
+
+    /// The longitude of the settlement
+    pub longitude: Option<f64>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<ApproachSettlementEventSchema> for ApproachSettlementEvent {
+    fn from(value: ApproachSettlementEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name: LocalisedValue::new(value.name, value.name_localised),
+            market_id: value.market_id,
+            station: value.station,
+            system_address: value.system_address,
+            body_id: value.body_id,
+            body_name: value.body_name,
+            latitude: value.latitude,
+            longitude: value.longitude,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ApproachSettlementEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the settlement
+    pub name: String,
+
+    /// The localised name of the settlement
+    #[serde(rename = "Name_Localised", skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+
+    /// The market id of the settlement if it has one
+    #[serde(rename = "MarketID", skip_serializing_if = "Option::is_none")]
+    pub market_id: Option<u64>,
+
+    /// The information about the settlement if it is dockable
+    #[serde(flatten)]
+    pub station: Option<StationInformation>,
+
+    /// The address of the system
+    pub system_address: u64,
+
+    /// The id of the body the settlement is on
+    #[serde(rename = "BodyID")]
+    pub body_id: u64,
+
+    /// The name of the body the settlement is on
+    pub body_name: String,
+
+    /// The latitude of the settlement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+
+    /// The longitude of the settlement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<ApproachSettlementEvent> for ApproachSettlementEventSchema {
+    fn from(value: ApproachSettlementEvent) -> Self {
+        let (name, name_localised) = deconstruct_localised_value(value.name);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            name,
+            name_localised,
+            market_id: value.market_id,
+            station: value.station,
+            system_address: value.system_address,
+            body_id: value.body_id,
+            body_name: value.body_name,
+            latitude: value.latitude,
+            longitude: value.longitude,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_approach_settlement_event() {
+        let json = r#"{ "timestamp":"2025-01-04T19:27:29Z", "Name":"Long Sight Base", "MarketID":128678023, "StationFaction":{ "Name":"Elvira Martuuk" }, "StationGovernment":"$government_Engineer;", "StationGovernment_Localised":"Workshop", "StationServices":[ "dock", "autodock", "commodities" ], "StationEconomy":"$economy_Colony;", "StationEconomy_Localised":"Colony", "StationEconomies":[ { "Name":"$economy_Colony;", "Name_Localised":"Colony", "Proportion":1.000000 } ], "SystemAddress":3107241104074, "BodyID":13, "BodyName":"Khun 5", "Latitude":-33.519184, "Longitude":-100.314255 }"#;
+
+        let event: ApproachSettlementEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:27:29Z"));
+        assert_eq!(event.name, LocalisedValue::new("Long Sight Base".to_string(), None));
+        assert_eq!(event.market_id, Some(128678023));
+        assert_eq!(event.station.unwrap().station_faction.name, "Elvira Martuuk");
+        assert_eq!(event.body_name, "Khun 5");
+        assert_eq!(event.latitude, Some(-33.519184));
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_approach_settlement_event_not_dockable() {
+        let json = r#"{ "timestamp":"2025-01-11T14:20:02Z", "Name":"$Ancient_Tiny_003:#index=1;", "Name_Localised":"Guardian Structure", "SystemAddress":5031654888146, "BodyID":8, "BodyName":"Synuefe EN-H d11-96 3", "Latitude":-54.7361, "Longitude":-129.6546 }"#;
+
+        let event: ApproachSettlementEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.name, LocalisedValue::new("$Ancient_Tiny_003:#index=1;".to_string(), Some("Guardian Structure".to_string())));
+        assert_eq!(event.market_id, None);
+        assert_eq!(event.station, None);
+        assert!(event.extra.is_empty());
+    }
+}
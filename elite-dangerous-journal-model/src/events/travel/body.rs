@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Approaching or leaving a planetary body, this is used for the ApproachBody and LeaveBody events
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:43:05Z", "event":"LeaveBody", "StarSystem":"Khun", "SystemAddress":3107241104074, "Body":"Khun 5", "BodyID":13 }
+/// ```
+///
+/// ReadTheDocs: [ApproachBody](https://elite-journal.readthedocs.io/en/latest/Travel/#approachbody),
+/// [LeaveBody](https://elite-journal.readthedocs.io/en/latest/Travel/#leavebody)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BodyEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the star system
+    pub star_system: String,
+
+    /// The address of the system
+    pub system_address: u64,
+
+    /// The name of the body
+    pub body: String,
+
+    /// The id of the body
+    #[serde(rename = "BodyID")]
+    pub body_id: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_body_event() {
+        let json = r#"{ "timestamp":"2025-01-04T19:43:05Z", "event":"LeaveBody", "StarSystem":"Khun", "SystemAddress":3107241104074, "Body":"Khun 5", "BodyID":13 }"#;
+
+        let event: BodyEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:43:05Z"));
+        assert_eq!(event.star_system, "Khun");
+        assert_eq!(event.system_address, 3107241104074);
+        assert_eq!(event.body, "Khun 5");
+        assert_eq!(event.body_id, 13);
+    }
+}
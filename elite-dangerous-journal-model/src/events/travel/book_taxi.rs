@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Booked a taxi (or dropship)
+///
+/// ```json
+/// { "timestamp":"2025-01-11T12:40:04Z", "event":"BookTaxi", "Cost":2000, "DestinationSystem":"Khun", "DestinationLocation":"Long Sight Base" }
+/// ```
+///
+/// ReadTheDocs: [BookTaxi](https://elite-journal.readthedocs.io/en/latest/Odyssey/#booktaxi)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BookTaxiEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The cost of the journey
+    pub cost: u64,
+
+    /// The destination star system
+    pub destination_system: String,
+
+    /// The destination station or settlement
+    pub destination_location: String,
+
+    /// Is the taxi retreating from a conflict zone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retreat: Option<bool>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_book_taxi_event() {
+        let json = r#"{ "timestamp":"2025-01-11T12:40:04Z", "event":"BookTaxi", "Cost":2000, "DestinationSystem":"Khun", "DestinationLocation":"Long Sight Base" }"#;
+
+        let event: BookTaxiEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T12:40:04Z"));
+        assert_eq!(event.cost, 2000);
+        assert_eq!(event.destination_system, "Khun");
+        assert_eq!(event.destination_location, "Long Sight Base");
+        assert_eq!(event.retreat, None);
+    }
+}
@@ -0,0 +1,307 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+use crate::events::common::station::{StationInformation, StationType};
+use crate::events::common::system::Allegiance;
+
+/// The number of landing pads of each size at a station
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct LandingPads {
+    /// Number of small pads
+    pub small: u32,
+    /// Number of medium pads
+    pub medium: u32,
+    /// Number of large pads
+    pub large: u32,
+}
+
+/// Docked at a station
+///
+/// ```json
+/// { "timestamp":"2025-01-06T20:51:44Z", "event":"Docked", "StationName":"Long Sight Base", "StationType":"CraterOutpost", "Taxi":false, "Multicrew":false, "StarSystem":"Khun", "SystemAddress":3107241104074, "MarketID":128678023, "StationFaction":{ "Name":"Elvira Martuuk" }, "StationGovernment":"$government_Engineer;", "StationGovernment_Localised":"Workshop", "StationServices":[ "dock", "autodock", "commodities" ], "StationEconomy":"$economy_Colony;", "StationEconomy_Localised":"Colony", "StationEconomies":[ { "Name":"$economy_Colony;", "Name_Localised":"Colony", "Proportion":1.000000 } ], "DistFromStarLS":60.541745, "LandingPads":{ "Small":2, "Medium":4, "Large":2 } }
+/// ```
+///
+/// ReadTheDocs: [Docked](https://elite-journal.readthedocs.io/en/latest/Travel/#docked)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DockedEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The type of the station
+    pub station_type: StationType,
+
+    /// The name of the star system
+    pub star_system: String,
+
+    /// The address of the system
+    pub system_address: u64,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The information about the station
+    #[serde(flatten)]
+    pub station: StationInformation,
+
+    /// The allegiance of the station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station_allegiance: Option<Allegiance>,
+
+    /// The distance from the arrival star in light seconds
+    #[serde(rename = "DistFromStarLS")]
+    pub dist_from_star_ls: f64,
+
+    /// The landing pads of the station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub landing_pads: Option<LandingPads>,
+
+    /// Is the commander wanted at the station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wanted: Option<bool>,
+
+    /// Does the commander have an active fine at the station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_fine: Option<bool>,
+
+    /// Did the commander dock with a breached cockpit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cockpit_breach: Option<bool>,
+
+    /// Is the commander in a taxi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+
+    /// Is the commander in multi crew
+    #[serde(rename = "Multicrew", skip_serializing_if = "Option::is_none")]
+    pub multi_crew: Option<bool>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Undocked from a station
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:40:15Z", "event":"Undocked", "StationName":"Long Sight Base", "StationType":"CraterOutpost", "MarketID":128678023, "Taxi":false, "Multicrew":false }
+/// ```
+///
+/// ReadTheDocs: [Undocked](https://elite-journal.readthedocs.io/en/latest/Travel/#undocked)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UndockedEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The type of the station
+    pub station_type: StationType,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// Is the commander in a taxi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+
+    /// Is the commander in multi crew
+    #[serde(rename = "Multicrew", skip_serializing_if = "Option::is_none")]
+    pub multi_crew: Option<bool>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Requested docking at a station
+///
+/// ```json
+/// { "timestamp":"2025-01-06T20:50:58Z", "event":"DockingRequested", "MarketID":128678023, "StationName":"Long Sight Base", "StationType":"CraterOutpost", "LandingPads":{ "Small":2, "Medium":4, "Large":2 } }
+/// ```
+///
+/// ReadTheDocs: [DockingRequested](https://elite-journal.readthedocs.io/en/latest/Travel/#dockingrequested)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DockingRequestedEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The type of the station
+    pub station_type: StationType,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The landing pads of the station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub landing_pads: Option<LandingPads>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Docking has been granted by a station
+///
+/// ```json
+/// { "timestamp":"2025-01-06T20:50:59Z", "event":"DockingGranted", "LandingPad":3, "MarketID":128678023, "StationName":"Long Sight Base", "StationType":"CraterOutpost" }
+/// ```
+///
+/// ReadTheDocs: [DockingGranted](https://elite-journal.readthedocs.io/en/latest/Travel/#dockinggranted)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DockingGrantedEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The type of the station
+    pub station_type: StationType,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The number of the landing pad that has been assigned
+    pub landing_pad: u32,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The reason docking was denied
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum DockingDeniedReason {
+    /// There are no free landing pads
+    NoSpace,
+    /// The ship is too large for the landing pads
+    TooLarge,
+    /// The station is hostile to the commander
+    Hostile,
+    /// The commander has offences at the station
+    Offences,
+    /// The ship is too far from the station
+    Distance,
+    /// The commander has a fighter deployed
+    ActiveFighter,
+    /// No reason was given
+    NoReason,
+    /// Any other reason
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Docking has been denied by a station
+///
+/// ```json
+/// { "timestamp":"2025-01-06T20:50:59Z", "event":"DockingDenied", "Reason":"Distance", "MarketID":128678023, "StationName":"Long Sight Base", "StationType":"CraterOutpost" }
+/// ```
+///
+/// ReadTheDocs: [DockingDenied](https://elite-journal.readthedocs.io/en/latest/Travel/#dockingdenied)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DockingDeniedEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The type of the station
+    pub station_type: StationType,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// Why docking was denied
+    pub reason: DockingDeniedReason,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A docking event that only identifies the station, this is used for the DockingCancelled and DockingTimeout
+/// events
+///
+/// ```json
+/// { "timestamp":"2025-01-06T20:50:59Z", "event":"DockingCancelled", "MarketID":128678023, "StationName":"Long Sight Base", "StationType":"CraterOutpost" }
+/// ```
+///
+/// ReadTheDocs: [DockingCancelled](https://elite-journal.readthedocs.io/en/latest/Travel/#dockingcancelled),
+/// [DockingTimeout](https://elite-journal.readthedocs.io/en/latest/Travel/#dockingtimeout)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DockingEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The name of the station
+    pub station_name: String,
+
+    /// The type of the station
+    pub station_type: StationType,
+
+    /// The market id of the station
+    #[serde(rename = "MarketID")]
+    pub market_id: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_docked_event() {
+        let json = r#"{ "timestamp":"2025-01-06T20:51:44Z", "StationName":"Long Sight Base", "StationType":"CraterOutpost", "Taxi":false, "Multicrew":false, "StarSystem":"Khun", "SystemAddress":3107241104074, "MarketID":128678023, "StationFaction":{ "Name":"Elvira Martuuk" }, "StationGovernment":"$government_Engineer;", "StationGovernment_Localised":"Workshop", "StationServices":[ "dock", "autodock", "commodities" ], "StationEconomy":"$economy_Colony;", "StationEconomy_Localised":"Colony", "StationEconomies":[ { "Name":"$economy_Colony;", "Name_Localised":"Colony", "Proportion":1.000000 } ], "DistFromStarLS":60.541745, "LandingPads":{ "Small":2, "Medium":4, "Large":2 } }"#;
+
+        let event: DockedEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-06T20:51:44Z"));
+        assert_eq!(event.station_name, "Long Sight Base");
+        assert_eq!(event.station_type, StationType::CraterOutpost);
+        assert_eq!(event.star_system, "Khun");
+        assert_eq!(event.market_id, 128678023);
+        assert_eq!(event.station.station_faction.name, "Elvira Martuuk");
+        assert_eq!(event.landing_pads, Some(LandingPads { small: 2, medium: 4, large: 2 }));
+        assert_eq!(event.taxi, Some(false));
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_docking_denied_event() {
+        let json = r#"{ "timestamp":"2025-01-06T20:50:59Z", "event":"DockingDenied", "Reason":"Distance", "MarketID":128678023, "StationName":"Long Sight Base", "StationType":"CraterOutpost" }"#;
+
+        let event: DockingDeniedEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.reason, DockingDeniedReason::Distance);
+        assert_eq!(event.market_id, 128678023);
+    }
+}
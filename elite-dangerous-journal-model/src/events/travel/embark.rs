@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+use crate::events::common::station::StationType;
+
+/// Getting into or out of a ship, SRV or taxi, this is used for the Embark and Disembark events
+///
+/// ```json
+/// { "timestamp":"2025-01-11T13:02:51Z", "event":"Disembark", "SRV":false, "Taxi":false, "Multicrew":false, "ID":5, "StarSystem":"Khun", "SystemAddress":3107241104074, "Body":"Khun 5", "BodyID":13, "OnStation":true, "OnPlanet":false, "StationName":"Long Sight Base", "StationType":"CraterOutpost", "MarketID":128678023 }
+/// ```
+///
+/// ReadTheDocs: [Embark](https://elite-journal.readthedocs.io/en/latest/Odyssey/#embark),
+/// [Disembark](https://elite-journal.readthedocs.io/en/latest/Odyssey/#disembark)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EmbarkEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// Is it an SRV
+    #[serde(rename = "SRV")]
+    pub srv: bool,
+
+    /// Is it a taxi
+    pub taxi: bool,
+
+    /// Is it another commander's ship in multi crew
+    #[serde(rename = "Multicrew")]
+    pub multi_crew: bool,
+
+    /// The id of the ship or SRV, not given for a taxi or multi crew
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+
+    /// The name of the star system
+    pub star_system: String,
+
+    /// The address of the system
+    pub system_address: u64,
+
+    /// The name of the body
+    pub body: String,
+
+    /// The id of the body
+    #[serde(rename = "BodyID")]
+    pub body_id: u64,
+
+    /// Is the commander on a station
+    pub on_station: bool,
+
+    /// Is the commander on a planet
+    pub on_planet: bool,
+
+    /// The name of the station if on a station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station_name: Option<String>,
+
+    /// The type of the station if on a station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station_type: Option<StationType>,
+
+    /// The market id of the station if on a station
+    #[serde(rename = "MarketID", skip_serializing_if = "Option::is_none")]
+    pub market_id: Option<u64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_embark_event() {
+        let json = r#"{ "timestamp":"2025-01-11T13:02:51Z", "event":"Disembark", "SRV":false, "Taxi":false, "Multicrew":false, "ID":5, "StarSystem":"Khun", "SystemAddress":3107241104074, "Body":"Khun 5", "BodyID":13, "OnStation":true, "OnPlanet":false, "StationName":"Long Sight Base", "StationType":"CraterOutpost", "MarketID":128678023 }"#;
+
+        let event: EmbarkEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T13:02:51Z"));
+        assert!(!event.srv);
+        assert_eq!(event.id, Some(5));
+        assert!(event.on_station);
+        assert_eq!(event.station_type, Some(StationType::CraterOutpost));
+        assert_eq!(event.market_id, Some(128678023));
+    }
+}
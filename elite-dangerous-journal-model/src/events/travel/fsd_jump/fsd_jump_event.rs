@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::{EventMeta, LocalisedValue};
+use crate::events::common::{EventMeta, ExtraFields, LocalisedValue};
 use crate::events::common::system::{Allegiance, Conflict, Faction, ThargoidWar};
 use crate::events::travel::fsd_jump::star_position::StarPosition;
 
@@ -9,7 +9,7 @@ use crate::events::travel::fsd_jump::star_position::StarPosition;
 #[serde(from = "FSDJumpEventSchema", into = "FSDJumpEventSchema")]
 pub struct FSDJumpEvent {
 
-    /// The event meta data
+    /// The event metadata
     pub event_meta: EventMeta,
 
     /// The star name
@@ -55,13 +55,13 @@ pub struct FSDJumpEvent {
     pub population: u64,
 
     /// The distance of the jump
-    pub jump_dist: f32,
+    pub jump_dist: f64,
 
     /// Amount of fuel used
-    pub fuel_used: f32,
+    pub fuel_used: f64,
 
     /// Fuel level
-    pub fuel_level: f32,
+    pub fuel_level: f64,
 
     /// Taxi
     pub taxi: bool,
@@ -76,6 +76,8 @@ pub struct FSDJumpEvent {
     /// Thargoid war state
     pub thargoid_war: Option<ThargoidWar>,
 
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
 }
 
 impl From<FSDJumpEventSchema> for FSDJumpEvent {
@@ -102,6 +104,7 @@ impl From<FSDJumpEventSchema> for FSDJumpEvent {
 
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             body: value.body,
             body_id: value.body_id,
             body_type: value.body_type,
@@ -130,10 +133,10 @@ impl From<FSDJumpEventSchema> for FSDJumpEvent {
 
 /// FSD Jump event schema
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-#[serde(rename_all="PascalCase", deny_unknown_fields)]
+#[serde(rename_all="PascalCase")]
 pub struct FSDJumpEventSchema {
 
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
 
@@ -148,6 +151,7 @@ pub struct FSDJumpEventSchema {
     pub body_type: String,
 
     /// The factions in the system
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub factions: Option<Vec<Faction>>,
 
     /// The name of the Star System
@@ -160,6 +164,7 @@ pub struct FSDJumpEventSchema {
     pub system_allegiance: Allegiance,
 
     /// The faction of the system
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub system_faction: Option<Faction>,
 
     /// The position of the star
@@ -197,13 +202,13 @@ pub struct FSDJumpEventSchema {
     pub population: u64,
 
     /// The distance of the jump
-    pub jump_dist: f32,
+    pub jump_dist: f64,
 
     /// Amount of fuel used
-    pub fuel_used: f32,
+    pub fuel_used: f64,
 
     /// Fuel level
-    pub fuel_level: f32,
+    pub fuel_level: f64,
 
     /// Taxi
     pub taxi: bool,
@@ -213,17 +218,23 @@ pub struct FSDJumpEventSchema {
     pub multi_crew: bool,
 
     /// Conflicts in the system
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub conflicts: Option<Vec<Conflict>>,
 
     /// Thargoid war state
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thargoid_war: Option<ThargoidWar>,
 
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl From<FSDJumpEvent> for FSDJumpEventSchema {
     fn from(value: FSDJumpEvent) -> Self {
         Self {
             event_meta: value.event_meta,
+            extra: value.extra,
             body: value.body,
             body_id: value.body_id,
             body_type: value.body_type,
@@ -50,11 +50,11 @@ mod tests {
 #[derive(Debug, Clone, PartialEq)]
 pub struct StarPosition {
     /// X position of star
-    pub x: f32,
+    pub x: f64,
     /// Y postion of star
-    pub y: f32,
+    pub y: f64,
     /// Z position of star
-    pub z: f32,
+    pub z: f64,
 }
 
 impl Serialize for StarPosition {
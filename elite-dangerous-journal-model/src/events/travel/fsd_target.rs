@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::EventMeta;
+use crate::events::common::{EventMeta, ExtraFields};
 
 /// An event that show the target of a FSD Jump
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -20,7 +20,12 @@ pub struct FSDTargetEvent {
     pub star_class: String,
 
     /// Remaining jumps in the current route
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remaining_jumps_in_route: Option<u32>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
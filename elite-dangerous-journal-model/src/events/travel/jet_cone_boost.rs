@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Boosted the FSD by flying through the jet cone of a neutron star or white dwarf
+///
+/// ```json
+/// { "timestamp":"2025-01-11T14:01:44Z", "event":"JetConeBoost", "BoostValue":4.000000 }
+/// ```
+///
+/// ReadTheDocs: [JetConeBoost](https://elite-journal.readthedocs.io/en/latest/Other%20Events/#jetconeboost)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct JetConeBoostEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The multiplier applied to the jump range
+    pub boost_value: f64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_jet_cone_boost_event() {
+        let json = r#"{ "timestamp":"2025-01-11T14:01:44Z", "event":"JetConeBoost", "BoostValue":4.000000 }"#;
+
+        let event: JetConeBoostEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T14:01:44Z"));
+        assert_eq!(event.boost_value, 4.0);
+    }
+}
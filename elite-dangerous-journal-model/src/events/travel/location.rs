@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+use crate::events::common::station::{StationInformation, StationType};
+use crate::events::common::system::SystemInformation;
+
+/// The location of the commander, written at start up or when resurrecting at a station. The same structure is
+/// used for the CarrierJump event which is written when the commander is docked on a fleet carrier as it jumps
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:27:38Z", "event":"Location", "DistFromStarLS":60.541745, "Docked":true, "StationName":"Long Sight Base", "StationType":"CraterOutpost", "MarketID":128678023, "StationFaction":{ "Name":"Elvira Martuuk" }, "StationGovernment":"$government_Engineer;", "StationGovernment_Localised":"Workshop", "StationServices":[ "dock", "autodock", "commodities" ], "StationEconomy":"$economy_Colony;", "StationEconomy_Localised":"Colony", "StationEconomies":[ { "Name":"$economy_Colony;", "Name_Localised":"Colony", "Proportion":1.000000 } ], "Taxi":false, "Multicrew":false, "StarSystem":"Khun", "SystemAddress":3107241104074, "StarPos":[-171.59375,19.96875,-56.96875], "SystemAllegiance":"Independent", "SystemEconomy":"$economy_Terraforming;", "SystemEconomy_Localised":"Terraforming", "SystemSecondEconomy":"$economy_Colony;", "SystemSecondEconomy_Localised":"Colony", "SystemGovernment":"$government_Cooperative;", "SystemGovernment_Localised":"Cooperative", "SystemSecurity":"$SYSTEM_SECURITY_low;", "SystemSecurity_Localised":"Low Security", "Population":154370, "Body":"Khun 5", "BodyID":13, "BodyType":"Planet" }
+/// ```
+///
+/// ReadTheDocs: [Location](https://elite-journal.readthedocs.io/en/latest/Travel/#location)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct LocationEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The distance from the arrival star in light seconds
+    #[serde(rename = "DistFromStarLS", skip_serializing_if = "Option::is_none")]
+    pub dist_from_star_ls: Option<f64>,
+
+    /// Is the commander docked
+    pub docked: bool,
+
+    /// The name of the station if docked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station_name: Option<String>,
+
+    /// The type of the station if docked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station_type: Option<StationType>,
+
+    /// The market id of the station if docked
+    #[serde(rename = "MarketID", skip_serializing_if = "Option::is_none")]
+    pub market_id: Option<u64>,
+
+    /// The information about the station if docked
+    #[serde(flatten)]
+    pub station: Option<StationInformation>,
+
+    /// Is the commander in a taxi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+
+    /// Is the commander in multi crew
+    #[serde(rename = "Multicrew", skip_serializing_if = "Option::is_none")]
+    pub multi_crew: Option<bool>,
+
+    /// Is the commander in an SRV
+    #[serde(rename = "InSRV", skip_serializing_if = "Option::is_none")]
+    pub in_srv: Option<bool>,
+
+    /// Is the commander on foot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_foot: Option<bool>,
+
+    /// The information about the star system
+    #[serde(flatten)]
+    pub system: SystemInformation,
+
+    /// The name of the nearest body
+    pub body: String,
+
+    /// The id of the nearest body
+    #[serde(rename = "BodyID")]
+    pub body_id: u64,
+
+    /// The type of the nearest body
+    pub body_type: String,
+
+    /// The latitude if on or near a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+
+    /// The longitude if on or near a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::common::LocalisedValue;
+    use crate::events::common::station::StationService;
+    use crate::events::common::system::Allegiance;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_location_event_docked() {
+        let json = r#"{ "timestamp":"2025-01-04T19:27:38Z", "DistFromStarLS":60.541745, "Docked":true, "StationName":"Long Sight Base", "StationType":"CraterOutpost", "MarketID":128678023, "StationFaction":{ "Name":"Elvira Martuuk" }, "StationGovernment":"$government_Engineer;", "StationGovernment_Localised":"Workshop", "StationServices":[ "dock", "autodock", "commodities" ], "StationEconomy":"$economy_Colony;", "StationEconomy_Localised":"Colony", "StationEconomies":[ { "Name":"$economy_Colony;", "Name_Localised":"Colony", "Proportion":1.000000 } ], "Taxi":false, "Multicrew":false, "StarSystem":"Khun", "SystemAddress":3107241104074, "StarPos":[-171.59375,19.96875,-56.96875], "SystemAllegiance":"Independent", "SystemEconomy":"$economy_Terraforming;", "SystemEconomy_Localised":"Terraforming", "SystemSecondEconomy":"$economy_Colony;", "SystemSecondEconomy_Localised":"Colony", "SystemGovernment":"$government_Cooperative;", "SystemGovernment_Localised":"Cooperative", "SystemSecurity":"$SYSTEM_SECURITY_low;", "SystemSecurity_Localised":"Low Security", "Population":154370, "Body":"Khun 5", "BodyID":13, "BodyType":"Planet", "SystemFaction":{ "Name":"Canonn", "FactionState":"Investment" } }"#;
+
+        let event: LocationEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:27:38Z"));
+        assert!(event.docked);
+        assert_eq!(event.station_name, Some("Long Sight Base".to_string()));
+        assert_eq!(event.station_type, Some(StationType::CraterOutpost));
+        assert_eq!(event.market_id, Some(128678023));
+
+        let station = event.station.unwrap();
+        assert_eq!(station.station_faction.name, "Elvira Martuuk");
+        assert_eq!(station.station_services, vec![StationService::Dock, StationService::Autodock, StationService::Commodities]);
+
+        assert_eq!(event.system.star_system, "Khun");
+        assert_eq!(event.system.system_address, 3107241104074);
+        assert_eq!(event.system.system_allegiance, Allegiance::Independent);
+        assert_eq!(event.system.system_security, LocalisedValue::new("$SYSTEM_SECURITY_low;".to_string(), Some("Low Security".to_string())));
+        assert_eq!(event.system.population, 154370);
+        assert_eq!(event.system.system_faction.unwrap().name, "Canonn");
+        assert_eq!(event.body, "Khun 5");
+        assert_eq!(event.body_id, 13);
+        assert!(event.extra.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_serialize_location_event_in_space() {
+        let json = r#"{"timestamp":"2025-01-06T21:02:11Z","DistFromStarLS":0.0,"Docked":false,"Taxi":false,"Multicrew":false,"StarSystem":"Tiguai","SystemAddress":11664191792537,"StarPos":[-132.28125,31.40625,-39.625],"SystemAllegiance":"Independent","SystemEconomy":"$economy_Colony;","SystemEconomy_Localised":"Colony","SystemSecondEconomy":"$economy_Extraction;","SystemSecondEconomy_Localised":"Extraction","SystemGovernment":"$government_Dictatorship;","SystemGovernment_Localised":"Dictatorship","SystemSecurity":"$SYSTEM_SECURITY_low;","SystemSecurity_Localised":"Low Security","Population":1662,"Body":"Tiguai","BodyID":0,"BodyType":"Star","Wibble":"Fish"}"#;
+
+        let event: LocationEvent = serde_json::from_str(json).unwrap();
+
+        assert!(!event.docked);
+        assert_eq!(event.station, None);
+        assert_eq!(event.extra.len(), 1);
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
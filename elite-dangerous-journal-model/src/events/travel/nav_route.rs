@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+use crate::events::travel::fsd_jump::star_position::StarPosition;
+
+/// A system on the plotted route
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct NavRouteEntry {
+    /// The name of the system
+    pub star_system: String,
+    /// The address of the system
+    pub system_address: u64,
+    /// The position of the star
+    pub star_pos: StarPosition,
+    /// The class of the star
+    pub star_class: String,
+}
+
+/// A route has been plotted, the journal only contains the event metadata but the full route is written to
+/// the NavRoute.json file
+///
+/// Example (NavRoute.json):
+/// ```json
+/// {
+///   "timestamp": "2025-01-04T19:40:21Z",
+///   "event": "NavRoute",
+///   "Route": [
+///     { "StarSystem": "Ngalinn", "SystemAddress": 3107509342922, "StarPos": [-39.46875, 13.46875, -91.53125], "StarClass": "K" },
+///     { "StarSystem": "LF 8 +16 41", "SystemAddress": 251012319587, "StarPos": [-35.40625, 11.25, -83.84375], "StarClass": "F" }
+///   ]
+/// }
+/// ```
+///
+/// **Note**: After a ```NavRouteClear``` the file is rewritten with an empty route
+///
+/// ReadTheDocs: https://elite-journal.readthedocs.io/en/latest/Travel/#navroute
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct NavRouteEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The systems on the route (only present in the NavRoute.json file)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route: Option<Vec<NavRouteEntry>>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_nav_route_file() {
+        let json = r#"{ "timestamp":"2025-01-04T19:40:21Z", "event":"NavRoute", "Route":[ { "StarSystem":"Ngalinn", "SystemAddress":3107509342922, "StarPos":[-39.46875,13.46875,-91.53125], "StarClass":"K" }, { "StarSystem":"LF 8 +16 41", "SystemAddress":251012319587, "StarPos":[-35.40625,11.25,-83.84375], "StarClass":"F" } ] }"#;
+
+        let event: NavRouteEvent = serde_json::from_str(json).expect("Failed to deserialize NavRouteEvent");
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T19:40:21Z"));
+        let route = event.route.expect("Expected a route");
+        assert_eq!(route.len(), 2);
+        assert_eq!(route[0].star_system, "Ngalinn");
+        assert_eq!(route[0].system_address, 3107509342922);
+        assert_eq!(route[1].star_pos, StarPosition { x: -35.40625, y: 11.25, z: -83.84375 });
+        assert_eq!(route[1].star_class, "F");
+    }
+
+    #[test]
+    fn test_deserialize_nav_route_journal_event() {
+        let json = r#"{ "timestamp":"2025-01-04T19:40:21Z", "event":"NavRoute" }"#;
+
+        let event: NavRouteEvent = serde_json::from_str(json).expect("Failed to deserialize NavRouteEvent");
+
+        assert_eq!(event.route, None);
+    }
+}
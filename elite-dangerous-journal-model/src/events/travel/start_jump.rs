@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::events::common::EventMeta;
+use crate::events::common::{remove_modelled_fields, EventMeta, ExtraFields};
 
 /// Optional fields when type is hyperspace
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -28,9 +28,9 @@ pub enum JumpType {
 
 /// The actual jump event
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-#[serde(rename_all="PascalCase")]
+#[serde(rename_all="PascalCase", from = "StartJumpEventSchema")]
 pub struct StartJumpEvent {
-    /// The event meta data
+    /// The event metadata
     #[serde(flatten)]
     pub event_meta: EventMeta,
     /// The jump type
@@ -38,8 +38,38 @@ pub struct StartJumpEvent {
     pub jump_type: JumpType,
 
     /// Is it a tzxi journey
-    pub taxi: bool
+    pub taxi: bool,
 
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<StartJumpEventSchema> for StartJumpEvent {
+    fn from(value: StartJumpEventSchema) -> Self {
+        let mut extra = value.extra;
+        remove_modelled_fields(&mut extra, &value.jump_type);
+        Self {
+            event_meta: value.event_meta,
+            jump_type: value.jump_type,
+            taxi: value.taxi,
+            extra,
+        }
+    }
+}
+
+/// The schema to deserialize the start jump event, the jump type is flattened so its fields need removing from
+/// the extra fields
+#[derive(Deserialize)]
+#[serde(rename_all="PascalCase")]
+struct StartJumpEventSchema {
+    #[serde(flatten)]
+    event_meta: EventMeta,
+    #[serde(flatten)]
+    jump_type: JumpType,
+    taxi: bool,
+    #[serde(flatten)]
+    extra: ExtraFields,
 }
 
 
@@ -93,5 +123,18 @@ mod tests {
         assert_eq!(result.jump_type, JumpType::SuperCruise);
         assert_eq!(result.taxi, true);
     }
-}
 
+    #[test]
+    fn test_deserialize_serialize_start_jump_event_extra() {
+        let json = r#"{"timestamp":"2025-01-04T19:49:15Z","JumpType":"Hyperspace","StarSystem":"Sol","StarClass":"G","SystemAddress":123456789,"Taxi":false,"Wibble":"Fish"}"#;
+
+        let result: StartJumpEvent = serde_json::from_str(json).unwrap();
+
+        // Only the field that is not modelled is kept, not those of the flattened jump type
+        assert_eq!(result.extra.len(), 1);
+        assert_eq!(result.extra["Wibble"], "Fish");
+
+        let serialized = serde_json::to_value(&result).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{EventMeta, ExtraFields};
+
+/// Entered supercruise
+///
+/// ```json
+/// { "timestamp":"2025-01-04T19:42:57Z", "event":"SupercruiseEntry", "Taxi":false, "Multicrew":false, "StarSystem":"Khun", "SystemAddress":3107241104074 }
+/// ```
+///
+/// ReadTheDocs: [SupercruiseEntry](https://elite-journal.readthedocs.io/en/latest/Travel/#supercruiseentry)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupercruiseEntryEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// Is the commander in a taxi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+
+    /// Is the commander in multi crew
+    #[serde(rename = "Multicrew", skip_serializing_if = "Option::is_none")]
+    pub multi_crew: Option<bool>,
+
+    /// The name of the star system
+    pub star_system: String,
+
+    /// The address of the system
+    pub system_address: u64,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Dropped out of supercruise
+///
+/// ```json
+/// { "timestamp":"2025-01-04T21:07:34Z", "event":"SupercruiseExit", "Taxi":false, "Multicrew":false, "StarSystem":"Blu Thua RI-A b14-5", "SystemAddress":11669829068921, "Body":"Blu Thua RI-A b14-5", "BodyID":0, "BodyType":"Star" }
+/// ```
+///
+/// ReadTheDocs: [SupercruiseExit](https://elite-journal.readthedocs.io/en/latest/Travel/#supercruiseexit)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupercruiseExitEvent {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// Is the commander in a taxi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+
+    /// Is the commander in multi crew
+    #[serde(rename = "Multicrew", skip_serializing_if = "Option::is_none")]
+    pub multi_crew: Option<bool>,
+
+    /// The name of the star system
+    pub star_system: String,
+
+    /// The address of the system
+    pub system_address: u64,
+
+    /// The name of the nearest body
+    pub body: String,
+
+    /// The id of the nearest body
+    #[serde(rename = "BodyID")]
+    pub body_id: u64,
+
+    /// The type of the nearest body
+    pub body_type: String,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_supercruise_exit_event() {
+        let json = r#"{ "timestamp":"2025-01-04T21:07:34Z", "event":"SupercruiseExit", "Taxi":false, "Multicrew":false, "StarSystem":"Blu Thua RI-A b14-5", "SystemAddress":11669829068921, "Body":"Blu Thua RI-A b14-5", "BodyID":0, "BodyType":"Star" }"#;
+
+        let event: SupercruiseExitEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-04T21:07:34Z"));
+        assert_eq!(event.taxi, Some(false));
+        assert_eq!(event.star_system, "Blu Thua RI-A b14-5");
+        assert_eq!(event.system_address, 11669829068921);
+        assert_eq!(event.body, "Blu Thua RI-A b14-5");
+        assert_eq!(event.body_id, 0);
+        assert_eq!(event.body_type, "Star");
+    }
+}
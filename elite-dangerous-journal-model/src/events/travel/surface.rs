@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_optional_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Landing on or lifting off from a planet surface, this is used for the Touchdown and Liftoff events
+///
+/// ```json
+/// { "timestamp":"2025-01-06T20:13:21Z", "event":"Touchdown", "PlayerControlled":true, "Taxi":false, "Multicrew":false, "StarSystem":"Khun", "SystemAddress":3107241104074, "Body":"Khun 5", "BodyID":13, "OnStation":false, "OnPlanet":true, "Latitude":-33.519184, "Longitude":-100.314255, "NearestDestination":"$SAA_Unknown_Signal:#type=$SAA_SignalType_Geological;:#index=3;", "NearestDestination_Localised":"Surface signal: Geological (3)" }
+/// ```
+///
+/// ReadTheDocs: [Touchdown](https://elite-journal.readthedocs.io/en/latest/Travel/#touchdown),
+/// [Liftoff](https://elite-journal.readthedocs.io/en/latest/Travel/#liftoff)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "SurfaceEventSchema", into = "SurfaceEventSchema")]
+pub struct SurfaceEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// Is the ship being flown by the commander, false if it is being recalled or dismissed
+    pub player_controlled: bool,
+
+    /// Is the commander in a taxi
+    pub taxi: Option<bool>,
+
+    /// Is the commander in multi crew
+    pub multi_crew: Option<bool>,
+
+    /// The name of the star system
+    pub star_system: Option<String>,
+
+    /// The address of the system
+    pub system_address: Option<u64>,
+
+    /// The name of the body
+    pub body: Option<String>,
+
+    /// The id of the body
+    pub body_id: Option<u64>,
+
+    /// Is the ship on a station
+    pub on_station: Option<bool>,
+
+    /// Is the ship on a planet
+    pub on_planet: Option<bool>,
+
+    /// The latitude, only given when the ship is player controlled
+    pub latitude: Option<f64>,
+
+    /// The longitude, only given when the ship is player controlled
+    pub longitude: Option<f64>,
+
+    /// The nearest point of interest
+    pub nearest_destination: Option<LocalisedValue>,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<SurfaceEventSchema> for SurfaceEvent {
+    fn from(value: SurfaceEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            player_controlled: value.player_controlled,
+            taxi: value.taxi,
+            multi_crew: value.multi_crew,
+            star_system: value.star_system,
+            system_address: value.system_address,
+            body: value.body,
+            body_id: value.body_id,
+            on_station: value.on_station,
+            on_planet: value.on_planet,
+            latitude: value.latitude,
+            longitude: value.longitude,
+            nearest_destination: LocalisedValue::new_optional(value.nearest_destination, value.nearest_destination_localised),
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SurfaceEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// Is the ship being flown by the commander, false if it is being recalled or dismissed
+    pub player_controlled: bool,
+
+    /// Is the commander in a taxi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+
+    /// Is the commander in multi crew
+    #[serde(rename = "Multicrew", skip_serializing_if = "Option::is_none")]
+    pub multi_crew: Option<bool>,
+
+    /// The name of the star system
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub star_system: Option<String>,
+
+    /// The address of the system
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_address: Option<u64>,
+
+    /// The name of the body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// The id of the body
+    #[serde(rename = "BodyID", skip_serializing_if = "Option::is_none")]
+    pub body_id: Option<u64>,
+
+    /// Is the ship on a station
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_station: Option<bool>,
+
+    /// Is the ship on a planet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_planet: Option<bool>,
+
+    /// The latitude, only given when the ship is player controlled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+
+    /// The longitude, only given when the ship is player controlled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+
+    /// The nearest point of interest
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nearest_destination: Option<String>,
+
+    /// The localised nearest point of interest
+    #[serde(rename = "NearestDestination_Localised", skip_serializing_if = "Option::is_none")]
+    pub nearest_destination_localised: Option<String>,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<SurfaceEvent> for SurfaceEventSchema {
+    fn from(value: SurfaceEvent) -> Self {
+        let (nearest_destination, nearest_destination_localised) = deconstruct_optional_localised_value(value.nearest_destination);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            player_controlled: value.player_controlled,
+            taxi: value.taxi,
+            multi_crew: value.multi_crew,
+            star_system: value.star_system,
+            system_address: value.system_address,
+            body: value.body,
+            body_id: value.body_id,
+            on_station: value.on_station,
+            on_planet: value.on_planet,
+            latitude: value.latitude,
+            longitude: value.longitude,
+            nearest_destination,
+            nearest_destination_localised,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_serialize_surface_event() {
+        let json = r#"{"timestamp":"2025-01-06T20:13:21Z","PlayerControlled":true,"Taxi":false,"Multicrew":false,"StarSystem":"Khun","SystemAddress":3107241104074,"Body":"Khun 5","BodyID":13,"OnStation":false,"OnPlanet":true,"Latitude":-33.519184,"Longitude":-100.314255,"NearestDestination":"$SAA_Unknown_Signal:#type=$SAA_SignalType_Geological;:#index=3;","NearestDestination_Localised":"Surface signal: Geological (3)"}"#;
+
+        let event: SurfaceEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-06T20:13:21Z"));
+        assert!(event.player_controlled);
+        assert_eq!(event.body, Some("Khun 5".to_string()));
+        assert_eq!(event.on_planet, Some(true));
+        assert_eq!(event.nearest_destination, Some(LocalisedValue::new("$SAA_Unknown_Signal:#type=$SAA_SignalType_Geological;:#index=3;".to_string(), Some("Surface signal: Geological (3)".to_string()))));
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert_eq!(serialized, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_surface_event_not_player_controlled() {
+        let json = r#"{ "timestamp":"2025-01-06T20:20:02Z", "event":"Liftoff", "PlayerControlled":false, "Taxi":false, "Multicrew":false, "StarSystem":"Khun", "SystemAddress":3107241104074, "Body":"Khun 5", "BodyID":13, "OnStation":false, "OnPlanet":true }"#;
+
+        let event: SurfaceEvent = serde_json::from_str(json).unwrap();
+
+        assert!(!event.player_controlled);
+        assert_eq!(event.latitude, None);
+        assert_eq!(event.nearest_destination, None);
+    }
+}
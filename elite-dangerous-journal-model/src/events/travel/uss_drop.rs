@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use crate::events::common::{deconstruct_localised_value, EventMeta, ExtraFields, LocalisedValue};
+
+/// Dropped out of supercruise at an unidentified signal source
+///
+/// ```json
+/// { "timestamp":"2025-01-11T14:35:09Z", "event":"USSDrop", "USSType":"$USS_Type_Salvage;", "USSType_Localised":"Degraded emissions", "USSThreat":0 }
+/// ```
+///
+/// ReadTheDocs: [USSDrop](https://elite-journal.readthedocs.io/en/latest/Travel/#ussdrop)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "USSDropEventSchema", into = "USSDropEventSchema")]
+pub struct USSDropEvent {
+    /// The event metadata
+    pub event_meta: EventMeta,
+
+    /// The type of the signal source
+    pub uss_type: LocalisedValue,
+
+    /// The threat level of the signal source
+    pub uss_threat: u32,
+
+    /// The fields that are not modelled
+    pub extra: ExtraFields,
+}
+
+impl From<USSDropEventSchema> for USSDropEvent {
+    fn from(value: USSDropEventSchema) -> Self {
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            uss_type: LocalisedValue::new(value.uss_type, value.uss_type_localised),
+            uss_threat: value.uss_threat,
+        }
+    }
+}
+
+/// Due to using localised values we need an intermediary event
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct USSDropEventSchema {
+    /// The event metadata
+    #[serde(flatten)]
+    pub event_meta: EventMeta,
+
+    /// The type of the signal source
+    #[serde(rename = "USSType")]
+    pub uss_type: String,
+
+    /// The localised type of the signal source
+    #[serde(rename = "USSType_Localised", skip_serializing_if = "Option::is_none")]
+    pub uss_type_localised: Option<String>,
+
+    /// The threat level of the signal source
+    #[serde(rename = "USSThreat")]
+    pub uss_threat: u32,
+
+    /// The fields that are not modelled
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl From<USSDropEvent> for USSDropEventSchema {
+    fn from(value: USSDropEvent) -> Self {
+        let (uss_type, uss_type_localised) = deconstruct_localised_value(value.uss_type);
+        Self {
+            event_meta: value.event_meta,
+            extra: value.extra,
+            uss_type,
+            uss_type_localised,
+            uss_threat: value.uss_threat,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::serde_helpers::create_timestamp;
+
+    #[test]
+    fn test_deserialize_uss_drop_event() {
+        let json = r#"{ "timestamp":"2025-01-11T14:35:09Z", "event":"USSDrop", "USSType":"$USS_Type_Salvage;", "USSType_Localised":"Degraded emissions", "USSThreat":0 }"#;
+
+        let event: USSDropEvent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_meta.timestamp, create_timestamp("2025-01-11T14:35:09Z"));
+        assert_eq!(event.uss_type, LocalisedValue::new("$USS_Type_Salvage;".to_string(), Some("Degraded emissions".to_string())));
+        assert_eq!(event.uss_threat, 0);
+    }
+}
@@ -0,0 +1,259 @@
+use std::fmt::{Display, Formatter};
+use serde::Deserialize;
+use serde_json::Value;
+use crate::events::JournalEvent;
+use crate::events::startup::game::GameMode;
+use crate::events::station_services::engineer::{Engineer, EngineerData, EngineerProgress};
+
+/// How strictly a journal line is checked when it is parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidationMode {
+    /// Any line that deserializes is accepted, fields that are not modelled are kept in the extra fields of the
+    /// event and events that are not modelled become [Unknown](JournalEvent::Unknown)
+    #[default]
+    Lenient,
+    /// The event must be modelled, every field of the line must be modelled, the event must serialize back to the
+    /// line and the documented required/conditional fields must be present
+    Strict,
+}
+
+/// The reasons a journal line can be rejected
+#[derive(Debug)]
+pub enum ValidationError {
+    /// The line is not valid json or does not deserialize into an event, this includes a modelled event with a
+    /// missing or mistyped field
+    Parse(serde_json::Error),
+    /// The event is not modelled
+    UnknownEvent(String),
+    /// The event contains fields that are not modelled, nested fields are given by their path
+    UnknownFields {
+        /// The name of the event
+        event: String,
+        /// The fields that are not modelled, e.g. ```SystemPowers``` or ```Factions[0].SquadronFaction```
+        fields: Vec<String>,
+    },
+    /// The event does not serialize back to the line, the fields that have a different value or are only present
+    /// in the serialized event are given by their path
+    ChangedFields {
+        /// The name of the event
+        event: String,
+        /// The fields that have changed, e.g. ```Influence``` or ```Factions[0].Happiness```
+        fields: Vec<String>,
+    },
+    /// A documented required/conditional field is missing or present when it should not be
+    InvalidField {
+        /// The name of the event
+        event: String,
+        /// The rule that has been broken
+        message: String,
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::Parse(e) => write!(f, "Failed to parse event: {}", e),
+            ValidationError::UnknownEvent(event) => write!(f, "Unknown event: {}", event),
+            ValidationError::UnknownFields { event, fields } => write!(f, "Unknown fields in {}: {}", event, fields.join(", ")),
+            ValidationError::ChangedFields { event, fields } => write!(f, "Changed fields in {}: {}", event, fields.join(", ")),
+            ValidationError::InvalidField { event, message } => write!(f, "Invalid field in {}: {}", event, message),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidationError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl JournalEvent {
+
+    /// Parse a line of the journal, in [Strict](ValidationMode::Strict) mode the event is also validated against
+    /// the source line
+    ///
+    /// Example:
+    /// ```
+    /// use elite_dangerous_journal_model::events::JournalEvent;
+    /// use elite_dangerous_journal_model::events::validation::ValidationMode;
+    ///
+    /// let line = r#"{ "timestamp":"2025-01-04T19:45:12Z", "event":"HeatWarning", "Wibble":"Fish" }"#;
+    /// assert!(JournalEvent::parse(line, ValidationMode::Lenient).is_ok());
+    /// assert!(JournalEvent::parse(line, ValidationMode::Strict).is_err());
+    /// ```
+    pub fn parse(line: &str, mode: ValidationMode) -> Result<JournalEvent, ValidationError> {
+        let source: Value = serde_json::from_str(line).map_err(ValidationError::Parse)?;
+        let event = JournalEvent::deserialize(&source).map_err(ValidationError::Parse)?;
+
+        if mode == ValidationMode::Strict {
+            event.validate(&source)?;
+        }
+        Ok(event)
+    }
+
+    /// Validate the event against the source json it was deserialized from. This checks that the event is modelled,
+    /// that no field of the source has been dropped or captured in the extra fields, that the event serializes back
+    /// to the source and the documented conditional fields
+    pub fn validate(&self, source: &Value) -> Result<(), ValidationError> {
+        let event_name = source.get("event").and_then(|event| event.as_str()).unwrap_or_default().to_string();
+
+        let extra = match self.extra() {
+            Some(extra) => extra,
+            None => return Err(ValidationError::UnknownEvent(event_name)),
+        };
+
+        let mut fields: Vec<String> = extra.keys().cloned().collect();
+        let mut changed = Vec::new();
+        let modelled = serde_json::to_value(self).map_err(ValidationError::Parse)?;
+        compare_fields(source, &modelled, "", &mut fields, &mut changed);
+        if !fields.is_empty() {
+            return Err(ValidationError::UnknownFields { event: event_name, fields });
+        }
+        if !changed.is_empty() {
+            return Err(ValidationError::ChangedFields { event: event_name, fields: changed });
+        }
+
+        self.validate_conditional_fields()
+            .map_err(|message| ValidationError::InvalidField { event: event_name, message })
+    }
+
+    /// Check the fields that are documented as only being present depending on the value of another field
+    fn validate_conditional_fields(&self) -> Result<(), String> {
+        match self {
+            JournalEvent::LoadGame(load_game) => {
+                match (&load_game.game_mode, &load_game.group) {
+                    (GameMode::Group, None) => Err("Group must be present when GameMode is Group".to_string()),
+                    (GameMode::Group, Some(_)) | (_, None) => Ok(()),
+                    (game_mode, Some(_)) => Err(format!("Group must only be present when GameMode is Group, not {:?}", game_mode)),
+                }
+            }
+            JournalEvent::EngineerProgress(engineer_progress) => {
+                match &engineer_progress.data {
+                    EngineerData::Single(engineer) => validate_engineer(engineer),
+                    EngineerData::Multiple(engineers) => engineers.iter().try_for_each(validate_engineer),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The rank (and rank progress) of an engineer is only present when the engineer is unlocked
+fn validate_engineer(engineer: &Engineer) -> Result<(), String> {
+    if engineer.progress != Some(EngineerProgress::Unlocked) && (engineer.rank.is_some() || engineer.rank_progress.is_some()) {
+        return Err(format!("Rank must only be present when {} is Unlocked, not {:?}", engineer.engineer, engineer.progress))
+    }
+    Ok(())
+}
+
+/// Compare the source json with the modelled (re-serialized) json, the path of each field in the source that is not in
+/// the modelled json is added to dropped and the path of each field with a different value or that is only in the
+/// modelled json is added to changed. Numbers are compared by value as the journal writes some floats without a
+/// fraction e.g. ```"MassMT":12090```
+fn compare_fields(source: &Value, modelled: &Value, path: &str, dropped: &mut Vec<String>, changed: &mut Vec<String>) {
+    let field_path = |key: &str| if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) };
+    match (source, modelled) {
+        (Value::Object(source_fields), Value::Object(modelled_fields)) => {
+            for (key, source_value) in source_fields {
+                match modelled_fields.get(key) {
+                    Some(modelled_value) => compare_fields(source_value, modelled_value, &field_path(key), dropped, changed),
+                    None => dropped.push(field_path(key)),
+                }
+            }
+            changed.extend(modelled_fields.keys().filter(|key| !source_fields.contains_key(*key)).map(|key| field_path(key)));
+        }
+        (Value::Array(source_items), Value::Array(modelled_items)) if source_items.len() == modelled_items.len() => {
+            for (index, (source_item, modelled_item)) in source_items.iter().zip(modelled_items).enumerate() {
+                compare_fields(source_item, modelled_item, &format!("{}[{}]", path, index), dropped, changed);
+            }
+        }
+        (Value::Number(source_number), Value::Number(modelled_number)) => {
+            if source_number != modelled_number && source_number.as_f64() != modelled_number.as_f64() {
+                changed.push(path.to_string());
+            }
+        }
+        _ => {
+            if source != modelled {
+                changed.push(path.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_strict_unknown_fields() {
+        let line = r#"{ "timestamp":"2025-01-04T19:45:12Z", "event":"FSDTarget", "Name":"Col 285 Sector CC-K a38-2", "SystemAddress":5068732573105, "StarClass":"M", "RemainingJumpsInRoute":2, "Wibble":"Fish" }"#;
+
+        assert!(matches!(JournalEvent::parse(line, ValidationMode::Lenient), Ok(JournalEvent::FSDTarget(_))));
+        match JournalEvent::parse(line, ValidationMode::Strict) {
+            Err(ValidationError::UnknownFields { event, fields }) => {
+                assert_eq!(event, "FSDTarget");
+                assert_eq!(fields, vec!["Wibble".to_string()]);
+            }
+            other => panic!("Expected unknown fields but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_strict_unknown_nested_fields() {
+        let line = r#"{ "timestamp":"2025-01-04T19:31:30Z", "event":"Cargo", "Vessel":"Ship", "Count":1, "Inventory":[ { "Name":"drones", "Name_Localised":"Limpet", "Count":1, "Stolen":0, "Wibble":"Fish" } ] }"#;
+
+        match JournalEvent::parse(line, ValidationMode::Strict) {
+            Err(ValidationError::UnknownFields { fields, .. }) => assert_eq!(fields, vec!["Inventory[0].Wibble".to_string()]),
+            other => panic!("Expected unknown fields but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_strict_unknown_event() {
+        let line = r#"{ "timestamp":"2025-01-04T19:45:12Z", "event":"WibbleFish" }"#;
+
+        assert!(matches!(JournalEvent::parse(line, ValidationMode::Lenient), Ok(JournalEvent::Unknown(_))));
+        assert!(matches!(JournalEvent::parse(line, ValidationMode::Strict), Err(ValidationError::UnknownEvent(event)) if event == "WibbleFish"));
+    }
+
+    #[test]
+    fn test_parse_strict_mistyped_field() {
+        let line = r#"{ "timestamp":"2025-01-04T19:45:12Z", "event":"FSDTarget", "Name":"Col 285 Sector CC-K a38-2", "SystemAddress":"5068732573105", "StarClass":"M", "RemainingJumpsInRoute":2 }"#;
+
+        for mode in [ValidationMode::Lenient, ValidationMode::Strict] {
+            match JournalEvent::parse(line, mode) {
+                Err(ValidationError::Parse(e)) => assert!(e.to_string().starts_with("Invalid FSDTarget event: invalid type: string"), "{}", e),
+                other => panic!("Expected a parse error but got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_strict_conditional_fields() {
+        let group_without_name = r#"{ "timestamp":"2025-01-04T19:27:20Z", "event":"LoadGame", "FID":"F00000000", "Commander":"ANON", "Horizons":true, "Odyssey":true, "Ship":"Anaconda", "ShipID":5, "ShipName":"", "ShipIdent":"", "FuelLevel":32.000000, "FuelCapacity":32.000000, "GameMode":"Group", "Credits":53038302, "Loan":0, "language":"English/UK", "gameversion":"4.0.0.1904", "build":"r308767/r0 " }"#;
+        let solo_with_name = r#"{ "timestamp":"2025-01-04T19:27:20Z", "event":"LoadGame", "FID":"F00000000", "Commander":"ANON", "Horizons":true, "Odyssey":true, "Ship":"Anaconda", "ShipID":5, "ShipName":"", "ShipIdent":"", "FuelLevel":32.000000, "FuelCapacity":32.000000, "GameMode":"Solo", "Group":"Mobius", "Credits":53038302, "Loan":0, "language":"English/UK", "gameversion":"4.0.0.1904", "build":"r308767/r0 " }"#;
+        let ranked_but_known = r#"{ "timestamp":"2025-01-06T20:07:22Z", "event":"EngineerProgress", "Engineer":"Marco Qwent", "EngineerID":300200, "Progress":"Known", "Rank":2 }"#;
+
+        for line in [group_without_name, solo_with_name, ranked_but_known] {
+            assert!(JournalEvent::parse(line, ValidationMode::Lenient).is_ok());
+            assert!(matches!(JournalEvent::parse(line, ValidationMode::Strict), Err(ValidationError::InvalidField { .. })), "Expected invalid field for {}", line);
+        }
+    }
+
+    #[test]
+    fn test_compare_fields() {
+        let source: Value = serde_json::from_str(r#"{ "Name":"Canonn", "Influence":0.201798, "MassMT":12090, "Factions":[ { "Name":"Canonn" } ], "Wibble":"Fish" }"#).unwrap();
+        let modelled: Value = serde_json::from_str(r#"{ "Name":"Canonn", "Influence":0.20179800689220428, "MassMT":12090.0, "Factions":[ { "Name":"Canonn", "Happiness":"" } ], "Group":null }"#).unwrap();
+        let mut dropped = Vec::new();
+        let mut changed = Vec::new();
+
+        compare_fields(&source, &modelled, "", &mut dropped, &mut changed);
+
+        assert_eq!(dropped, vec!["Wibble".to_string()]);
+        assert_eq!(changed, vec!["Factions[0].Happiness".to_string(), "Influence".to_string(), "Group".to_string()]);
+    }
+}
+
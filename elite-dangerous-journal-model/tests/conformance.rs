@@ -0,0 +1,121 @@
+//! Conformance tests that parse a corpus of real journal lines, one or more for every supported event, in both the
+//! lenient and strict validation modes. When an event is added to the model a line for it should be added to the
+//! corpus. Every line of the corpus and the example journals must also serialize back to the source line
+
+use std::collections::HashSet;
+use std::fs::read_dir;
+use std::path::Path;
+use serde_json::Value;
+use elite_dangerous_journal_model::events::common::ship_catalogue::ship_info;
+use elite_dangerous_journal_model::events::JournalEvent;
+use elite_dangerous_journal_model::events::validation::ValidationMode;
+
+const CORPUS: &str = include_str!("corpus/journal_events.log");
+
+fn corpus_lines() -> impl Iterator<Item = &'static str> {
+    CORPUS.lines().filter(|line| !line.trim().is_empty())
+}
+
+fn event_name(line: &str) -> String {
+    let source: Value = serde_json::from_str(line).expect("Corpus line is not valid json");
+    source["event"].as_str().expect("Corpus line has no event").to_string()
+}
+
+#[test]
+fn test_corpus_covers_supported_events() {
+    let corpus_events: HashSet<String> = corpus_lines().map(event_name).collect();
+
+    let missing: Vec<&&str> = JournalEvent::SUPPORTED_EVENTS.iter().filter(|event| !corpus_events.contains(**event)).collect();
+    assert!(missing.is_empty(), "No corpus lines for: {:?}", missing);
+}
+
+#[test]
+fn test_corpus_lenient() {
+    for line in corpus_lines() {
+        match JournalEvent::parse(line, ValidationMode::Lenient) {
+            Ok(JournalEvent::Unknown(unknown)) => panic!("{} was parsed as an unknown event: {}", unknown.event, line),
+            Ok(_) => {}
+            Err(e) => panic!("{}: {}", e, line),
+        }
+    }
+}
+
+#[test]
+fn test_corpus_strict() {
+    for line in corpus_lines() {
+        if let Err(e) = JournalEvent::parse(line, ValidationMode::Strict) {
+            panic!("{}: {}", e, line);
+        }
+    }
+}
+
+/// The lines of every journal in the examples directory
+fn example_journal_lines() -> Vec<String> {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    let mut lines = Vec::new();
+    for entry in read_dir(examples).expect("Unable to read the examples directory") {
+        let path = entry.unwrap().path();
+        if path.file_name().is_some_and(|name| name.to_string_lossy().starts_with("Journal.")) {
+            let journal = std::fs::read_to_string(&path).expect("Unable to read example journal");
+            lines.extend(journal.lines().filter(|line| !line.trim().is_empty()).map(str::to_string));
+        }
+    }
+    lines
+}
+
+/// Json does not distinguish between integer and float numbers (the journal writes some float fields such as
+/// ```MassMT``` without a fraction) so numbers are compared by value
+fn same_json(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => left == right || left.as_f64() == right.as_f64(),
+        (Value::Array(left), Value::Array(right)) => left.len() == right.len() && left.iter().zip(right).all(|(left, right)| same_json(left, right)),
+        (Value::Object(left), Value::Object(right)) => left.len() == right.len() && left.iter().all(|(key, left)| right.get(key).is_some_and(|right| same_json(left, right))),
+        _ => left == right,
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let example_lines = example_journal_lines();
+    assert!(!example_lines.is_empty(), "No example journals found");
+
+    for line in example_lines.into_iter().chain(corpus_lines().map(str::to_string)) {
+        let line = line.as_str();
+        let source: Value = serde_json::from_str(line).expect("Journal line is not valid json");
+        let event = JournalEvent::parse(line, ValidationMode::Lenient).unwrap_or_else(|e| panic!("{}: {}", e, line));
+
+        let serialized = serde_json::to_value(&event).unwrap();
+        assert!(same_json(&serialized, &source), "Serialized event does not match the source line\n  source: {}\n  serialized: {}", line, serialized);
+    }
+}
+
+/// The fields that hold the journal symbol of a player ship
+const SHIP_FIELDS: [&str; 5] = ["Ship", "ShipType", "NewShip", "StoreOldShip", "SellOldShip"];
+
+fn collect_ship_symbols(value: &Value, symbols: &mut HashSet<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                match field {
+                    Value::String(symbol) if SHIP_FIELDS.contains(&key.as_str()) => { symbols.insert(symbol.clone()); }
+                    _ => collect_ship_symbols(field, symbols),
+                }
+            }
+        }
+        Value::Array(values) => values.iter().for_each(|value| collect_ship_symbols(value, symbols)),
+        _ => {}
+    }
+}
+
+#[test]
+fn test_ship_catalogue_covers_journal_ships() {
+    let mut symbols = HashSet::new();
+    for line in example_journal_lines().into_iter().chain(corpus_lines().map(str::to_string)) {
+        let source: Value = serde_json::from_str(&line).expect("Journal line is not valid json");
+        collect_ship_symbols(&source, &mut symbols);
+    }
+    assert!(!symbols.is_empty(), "No ships found in the journals");
+
+    let missing: Vec<&String> = symbols.iter().filter(|symbol| ship_info(symbol).is_none()).collect();
+    assert!(missing.is_empty(), "Ships missing from the catalogue: {:?}", missing);
+}
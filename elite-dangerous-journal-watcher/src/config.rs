@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
+use crate::error::WatcherError;
 
 const CONFIG_FILE_NAME: &str = "journal_watcher.json";
 
@@ -12,64 +13,120 @@ pub struct JournalWatcherConfig {
 }
 
 impl JournalWatcherConfig {
-    pub fn new<P: AsRef<Path>>(location: P) -> Self {
-        
+    pub fn new<P: AsRef<Path>>(location: P) -> Result<Self, WatcherError> {
+
         let config_file = location.as_ref().join(CONFIG_FILE_NAME);
-        
+
         let data : JournalWatcherConfigData;
         if config_file.exists() {
-            data = JournalWatcherConfigData::load(&config_file);
+            data = JournalWatcherConfigData::load(&config_file)?;
         } else {
             data = Default::default();
-            data.save(&config_file);
+            data.save(&config_file)?;
         }
 
         let metadata = JournalWatcherConfigMetadata {
             config_file_location: config_file
         };
-        
-        Self {
+
+        Ok(Self {
             metadata,
             data,
-        }
+        })
     }
-    
-    pub fn save(&self) {
-        self.data.save(self.metadata.config_file_location.clone());
+
+    pub fn save(&self) -> Result<(), WatcherError> {
+        self.data.save(self.metadata.config_file_location.clone())
     }
 
 }
 
 
 /// Configuration data of the Journal watcher
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct JournalWatcherConfigData {
     pub file_positions: HashMap<PathBuf, u64>,
+    /// The number of lines read from each journal file, used to give the line number of each event
+    #[serde(default)]
+    pub file_line_numbers: HashMap<PathBuf, u64>,
+    /// Journal files that have been superseded by a newer journal file and so will not be written to again
+    #[serde(default)]
+    pub finished_files: HashSet<PathBuf>,
+    /// The sequence number of the next event, saved with the file positions so the sequence carries on after a restart
+    #[serde(default)]
+    pub next_sequence: u64,
     pub tick_rate_milli: Option<u64>,
     pub timeout_milli: u64,
+    /// The mechanism used to detect changes to the journal directory
+    #[serde(default)]
+    pub backend: WatcherBackend,
+    /// How often (in milliseconds) the directory is polled when using the polling backend
+    #[serde(default = "default_poll_interval_milli")]
+    pub poll_interval_milli: u64,
+    /// How often (in milliseconds) the auto backend checks whether native events are arriving
+    #[serde(default = "default_fallback_check_milli")]
+    pub fallback_check_milli: u64,
+    /// Read the existing journal and companion files at start up, so consumers receive the history and current state
+    /// before live events
+    #[serde(default = "default_backfill")]
+    pub backfill: bool,
+}
+
+/// The mechanism used to detect changes to the journal directory
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WatcherBackend {
+    /// Start with native notifications and switch to polling if journal files grow without any events
+    /// arriving, for example under Wine/Proton or when the journal directory is a network share
+    #[default]
+    Auto,
+    /// Only use the native notifications of the OS
+    Native,
+    /// Only poll the journal directory
+    Poll,
+}
+
+fn default_poll_interval_milli() -> u64 {
+    1000
+}
+
+fn default_fallback_check_milli() -> u64 {
+    10000
+}
+
+fn default_backfill() -> bool {
+    true
 }
 
 impl Default for JournalWatcherConfigData {
     fn default() -> Self {
         Self {
             file_positions: HashMap::new(),
+            file_line_numbers: HashMap::new(),
+            finished_files: HashSet::new(),
+            next_sequence: 0,
             tick_rate_milli: None,
             timeout_milli: 500,
+            backend: WatcherBackend::default(),
+            poll_interval_milli: default_poll_interval_milli(),
+            fallback_check_milli: default_fallback_check_milli(),
+            backfill: default_backfill(),
         }
     }
 }
 
 impl JournalWatcherConfigData {
-    pub fn load<P: AsRef<Path>>(location: P) -> Self {
+    pub fn load<P: AsRef<Path>>(location: P) -> Result<Self, WatcherError> {
 
-        let file = File::open(location).expect("Unable to open config file");
-        let config: Self = serde_json::from_reader(file).expect("Unable to parse config file");
-        config
+        let file = File::open(&location).map_err(|source| WatcherError::Io { path: location.as_ref().to_path_buf(), source })?;
+        let config: Self = serde_json::from_reader(file)
+            .map_err(|e| WatcherError::Config(format!("Unable to parse config file {:?}: {}", location.as_ref(), e)))?;
+        Ok(config)
     }
-    
-    pub fn save<P: AsRef<Path>>(&self, location: P) {
-        let file = File::create(location).expect("Unable to create config file");
-        serde_json::to_writer(file, self).expect("Unable to write config file");
+
+    pub fn save<P: AsRef<Path>>(&self, location: P) -> Result<(), WatcherError> {
+        let file = File::create(&location).map_err(|source| WatcherError::Io { path: location.as_ref().to_path_buf(), source })?;
+        serde_json::to_writer(file, self)
+            .map_err(|e| WatcherError::Config(format!("Unable to write config file {:?}: {}", location.as_ref(), e)))
     }
 }
 
@@ -77,4 +134,4 @@ impl JournalWatcherConfigData {
 #[derive(Debug)]
 pub struct JournalWatcherConfigMetadata {
     config_file_location: PathBuf,
-}
\ No newline at end of file
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use futures::channel::mpsc::Sender;
+use futures::SinkExt;
+use notify::{PollWatcher, RecommendedWatcher, RecursiveMode};
+use notify_debouncer_full::{new_debouncer, new_debouncer_opt, DebounceEventResult, Debouncer, RecommendedCache};
+use tracing::error;
+use crate::config::JournalWatcherConfigData;
+use crate::error::WatcherError;
+use crate::processor::journal_file_name::JournalFileName;
+
+/// The debounced file watcher that is watching the journal directory, either using the native notification
+/// mechanism of the OS or by polling the directory
+pub enum JournalDebouncer {
+    /// Uses the native notifications (inotify, ReadDirectoryChangesW etc.)
+    Native(Debouncer<RecommendedWatcher, RecommendedCache>),
+    /// Polls the directory for changes in modification time and size
+    Poll(Debouncer<PollWatcher, RecommendedCache>),
+}
+
+impl JournalDebouncer {
+
+    /// Create a debouncer that watches the journal directory and sends the debounced events to notify_tx. Every
+    /// result passed to the handler increments event_count, this is used to detect when native events are not arriving
+    pub fn new<P: AsRef<Path>>(poll: bool, journal_dir: P, config: &JournalWatcherConfigData, notify_tx: Sender<DebounceEventResult>, event_count: Arc<AtomicU64>) -> Result<Self, WatcherError> {
+
+        let timeout = Duration::from_millis(config.timeout_milli);
+        let tick_rate = config.tick_rate_milli.map(Duration::from_millis);
+
+        let event_handler = move |event: DebounceEventResult| {
+            event_count.fetch_add(1, Ordering::Relaxed);
+            let mut notify_tx = notify_tx.clone();
+            futures::executor::block_on(async {
+                // The receiver is only dropped when the watcher is stopping so there is nothing to recover
+                if let Err(e) = notify_tx.send(event).await {
+                    error!("Failed to send event to processor: {}", e);
+                }
+            });
+        };
+
+        let mut debouncer = if poll {
+            let notify_config = notify::Config::default()
+                .with_poll_interval(Duration::from_millis(config.poll_interval_milli));
+            JournalDebouncer::Poll(new_debouncer_opt(timeout, tick_rate, event_handler, RecommendedCache::new(), notify_config)?)
+        } else {
+            JournalDebouncer::Native(new_debouncer(timeout, tick_rate, event_handler)?)
+        };
+
+        debouncer.watch(journal_dir)?;
+        Ok(debouncer)
+    }
+
+    fn watch<P: AsRef<Path>>(&mut self, journal_dir: P) -> Result<(), WatcherError> {
+        match self {
+            JournalDebouncer::Native(debouncer) => debouncer.watch(journal_dir, RecursiveMode::Recursive)?,
+            JournalDebouncer::Poll(debouncer) => debouncer.watch(journal_dir, RecursiveMode::Recursive)?,
+        }
+        Ok(())
+    }
+
+    /// Returns true if this debouncer is polling the journal directory
+    pub fn is_polling(&self) -> bool {
+        matches!(self, JournalDebouncer::Poll(_))
+    }
+
+    /// Stop watching the journal directory
+    pub fn stop(self) {
+        match self {
+            JournalDebouncer::Native(debouncer) => debouncer.stop(),
+            JournalDebouncer::Poll(debouncer) => debouncer.stop(),
+        }
+    }
+}
+
+/// Get the size of each of the journal log files in the journal directory
+pub fn journal_file_sizes<P: AsRef<Path>>(journal_dir: P) -> Result<HashMap<PathBuf, u64>, WatcherError> {
+    let journal_dir = journal_dir.as_ref();
+    let entries = std::fs::read_dir(journal_dir)
+        .map_err(|source| WatcherError::Io { path: journal_dir.to_path_buf(), source })?;
+
+    Ok(entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| JournalFileName::from_path(entry.path()).is_some())
+        .filter_map(|entry| entry.metadata().ok().map(|metadata| (entry.path(), metadata.len())))
+        .collect())
+}
+
+/// Returns true if any journal file has grown (or a new non empty one has appeared) between the two snapshots
+pub fn journal_files_grown(previous: &HashMap<PathBuf, u64>, current: &HashMap<PathBuf, u64>) -> bool {
+    current.iter().any(|(path, size)| *size > previous.get(path).copied().unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_journal_files_grown() {
+        let previous = HashMap::from([(PathBuf::from("Journal.2025-01-04T185730.01.log"), 100)]);
+
+        assert!(!journal_files_grown(&previous, &previous));
+        assert!(journal_files_grown(&previous, &HashMap::from([(PathBuf::from("Journal.2025-01-04T185730.01.log"), 120)])));
+        assert!(journal_files_grown(&previous, &HashMap::from([
+            (PathBuf::from("Journal.2025-01-04T185730.01.log"), 100),
+            (PathBuf::from("Journal.2025-01-04T185730.02.log"), 10),
+        ])));
+    }
+}
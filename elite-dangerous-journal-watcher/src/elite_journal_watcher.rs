@@ -1,13 +1,14 @@
 use std::path::{Path};
 use std::time::Duration;
-use std::sync::{Arc, RwLock};
-use futures::{SinkExt, StreamExt};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use futures::StreamExt;
 use futures::channel::oneshot::Receiver;
-use notify::RecursiveMode;
-use notify_debouncer_full::{new_debouncer, DebounceEventResult};
 use tokio::task::JoinSet;
-use tracing::{debug, error, info};
-use crate::config::JournalWatcherConfig;
+use tracing::{debug, info, warn};
+use crate::config::{JournalWatcherConfig, WatcherBackend};
+use crate::debouncer::{journal_file_sizes, journal_files_grown, JournalDebouncer};
+use crate::error::WatcherError;
 use crate::processor::NotifierProcessor;
 
 
@@ -23,11 +24,21 @@ use crate::processor::NotifierProcessor;
 /// * 'journal_dir'  - Path where the journal files are stored
 /// * 'processor'    - Implementation of the [NotifierProcessor](NotifierProcessor) used to process the [notify](notify) events
 /// * 'terminate_rx' - The [Receiver](Receiver) end of a [OneShot](futures::channel::oneshot) channel that is used to send termination message to the watcher
+///
+/// The backend used to detect changes is set in the config, see [WatcherBackend](WatcherBackend). Whichever backend is in
+/// use the events are passed to the same processor
+///
+/// # Errors
+///
+/// Returns a [WatcherError](WatcherError) if the config cannot be loaded, the journal directory cannot be watched
+/// or the processor returns an error. Recoverable errors are published by the processor and do not stop the watcher
 /// # Non-Running Example
 /// ```ignore
 ///     let mut task_set = tokio::task::JoinSet::new();
 ///     task_set.spawn(async move {
-///         elite_dangerous_journal_watcher::elite_journal_watcher::start(working_dir, journal_dir, processor, terminate_rx).await;
+///         if let Err(e) = elite_dangerous_journal_watcher::elite_journal_watcher::start(working_dir, journal_dir, processor, terminate_rx).await {
+///             error!("Journal watcher stopped: {}", e);
+///         }
 ///     });
 ///
 /// 
@@ -47,55 +58,111 @@ use crate::processor::NotifierProcessor;
 ///         Err(e) => { Err(e.to_string()) }
 ///     }
 ///```
-pub async fn start<D, P>(working_dir: D, journal_dir: D, processor: Arc<P>, terminate_rx: Receiver<()>)
+pub async fn start<D, P>(working_dir: D, journal_dir: D, processor: Arc<P>, terminate_rx: Receiver<()>) -> Result<(), WatcherError>
 where D: AsRef<Path>, P: NotifierProcessor + Send + Sync + 'static {
-    let config = JournalWatcherConfig::new(working_dir.as_ref().to_path_buf());
+    let config = JournalWatcherConfig::new(working_dir.as_ref())?;
 
     debug!("Config: {:?}", config);
     
 
     // The notify channel is used to passed events from the notify crate to the processor
-    let (mut notify_tx, mut notify_rx) = futures::channel::mpsc::channel(1024);
+    let (notify_tx, mut notify_rx) = futures::channel::mpsc::channel(1024);
+    let event_count = Arc::new(AtomicU64::new(0));
+    let journal_dir = journal_dir.as_ref().to_path_buf();
 
-    let tick_rate = match config.data.tick_rate_milli {
-        None => { None }
-        Some(tr) => { Some(Duration::from_millis(tr)) }
-    };
-
-    let mut watcher = new_debouncer(
-        Duration::from_millis(config.data.timeout_milli),
-        tick_rate,
-        move |event: DebounceEventResult| {
-            futures::executor::block_on(async { notify_tx.send(event).await.expect("Failed to send event to processor") });
-        }
-    ).expect("Failed to create debouncer to watch journal files");
+    let watcher = JournalDebouncer::new(
+        config.data.backend == WatcherBackend::Poll,
+        &journal_dir,
+        &config.data,
+        notify_tx.clone(),
+        Arc::clone(&event_count)
+    )?;
+    let watcher = Arc::new(Mutex::new(Some(watcher)));
 
-    watcher.watch(journal_dir, RecursiveMode::Recursive).expect("Failed to add journal dir to file watcher");
+    let backend = config.data.backend;
+    let fallback_check = Duration::from_millis(config.data.fallback_check_milli);
+    let poll_config = config.data.clone();
+    let backfill = config.data.backfill;
+    let backfill_dir = journal_dir.clone();
 
     let config_lock = Arc::new(RwLock::new(config));
     let mut join_set = JoinSet::new();
 
     let thread_processor = Arc::clone(&processor);
     join_set.spawn(async move {
+        // The watcher is already running so any changes during the backfill are queued, as both read from the
+        // saved file positions the live events carry on exactly where the backfill finished. A failed backfill is
+        // reported rather than stopping the watcher, as any files it missed are still read when they next change
+        if backfill {
+            if let Err(e) = thread_processor.backfill(&backfill_dir, Arc::clone(&config_lock)).await {
+                thread_processor.report_error(e).await;
+            }
+        }
         while let Some(res) = notify_rx.next().await {
-            let _ = thread_processor.process(res, Arc::clone(&config_lock)).await;
+            thread_processor.process(res, Arc::clone(&config_lock)).await?;
         }
+        Ok(())
     });
 
-    let terminate_handle = join_set.spawn(async move {
+    if backend == WatcherBackend::Auto {
+        let fallback_watcher = Arc::clone(&watcher);
+        join_set.spawn(async move {
+            let mut previous_sizes = None;
+            let mut previous_count = event_count.load(Ordering::Relaxed);
+            loop {
+                match journal_file_sizes(&journal_dir) {
+                    Ok(sizes) => {
+                        let count = event_count.load(Ordering::Relaxed);
+                        let grown = previous_sizes.as_ref().is_some_and(|previous| journal_files_grown(previous, &sizes));
+                        if count == previous_count && grown {
+                            warn!("Journal files are growing but no file events have been received, falling back to polling");
+                            // The processor reads from the saved file positions so any changes missed are picked up by the first poll
+                            match JournalDebouncer::new(true, &journal_dir, &poll_config, notify_tx.clone(), Arc::clone(&event_count)) {
+                                Ok(poll_watcher) => {
+                                    {
+                                        let mut watcher = fallback_watcher.lock()
+                                            .map_err(|e| WatcherError::Config(format!("Failed to lock watcher: {}", e)))?;
+                                        if let Some(native_watcher) = watcher.replace(poll_watcher) {
+                                            native_watcher.stop();
+                                        }
+                                    }
+                                    // Keep the task alive, the join set treats any completed task as the watcher stopping
+                                    futures::future::pending::<()>().await;
+                                }
+                                // The previous sizes are kept so the fallback is tried again on the next check
+                                Err(e) => { warn!("Unable to start polling, retrying on the next check: {}", e); }
+                            }
+                        } else {
+                            previous_sizes = Some(sizes);
+                            previous_count = count;
+                        }
+                    }
+                    // Network shares can be briefly unavailable, keep the previous sizes and check again on the next tick
+                    Err(e) => { warn!("Unable to read journal file sizes, retrying on the next check: {}", e); }
+                }
+                tokio::time::sleep(fallback_check).await;
+            }
+        });
+    }
+
+    join_set.spawn(async move {
         let _ = terminate_rx.await;
         info!("Terminate message received, stopping watcher...");
+        Ok(())
     });
 
-    match join_set.join_next_with_id().await {
-        None => { error!("Failed to join thread");}
-        Some(res) => {
-            let (id, _) = res.expect("Failed to join thread");
+    let result = match join_set.join_next().await {
+        None => { Err(WatcherError::Channel("No watcher tasks to join".to_string())) }
+        Some(Ok(result)) => { result }
+        Some(Err(e)) => { Err(WatcherError::Channel(format!("Failed to join watcher task: {}", e))) }
+    };
 
-            if id == terminate_handle.id() {
-                watcher.stop()
-            }
+    if let Ok(mut watcher) = watcher.lock() {
+        if let Some(watcher) = watcher.take() {
+            debug!("Stopping watcher (polling: {})", watcher.is_polling());
+            watcher.stop();
         }
     }
+    result
 }
 
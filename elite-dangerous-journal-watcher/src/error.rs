@@ -0,0 +1,60 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+/// The errors that can occur while watching the journal directory
+///
+/// Errors that only affect a single file or line (for example a journal file being locked or a line that
+/// cannot be parsed) are recoverable and are published on the error channel of the processor rather than
+/// stopping the watcher
+#[derive(Debug)]
+pub enum WatcherError {
+    /// Failed to read from (or open) the file at the path
+    Io {
+        /// The path of the file being accessed
+        path: PathBuf,
+        /// The underlying error
+        source: std::io::Error,
+    },
+    /// Failed to load, save or access the watcher configuration
+    Config(String),
+    /// An error reported by the file watcher
+    Notify(notify::Error),
+    /// Failed to parse a line/file into an event
+    Parse {
+        /// The content that failed to parse
+        content: String,
+        /// The underlying error
+        source: serde_json::Error,
+    },
+    /// Failed to publish to a channel
+    Channel(String),
+}
+
+impl Display for WatcherError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WatcherError::Io { path, source } => write!(f, "IO error for {:?}: {}", path, source),
+            WatcherError::Config(message) => write!(f, "Config error: {}", message),
+            WatcherError::Notify(e) => write!(f, "Notify error: {}", e),
+            WatcherError::Parse { content, source } => write!(f, "Failed to parse '{}': {}", content, source),
+            WatcherError::Channel(message) => write!(f, "Channel error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for WatcherError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WatcherError::Io { source, .. } => Some(source),
+            WatcherError::Notify(e) => Some(e),
+            WatcherError::Parse { source, .. } => Some(source),
+            WatcherError::Config(_) | WatcherError::Channel(_) => None,
+        }
+    }
+}
+
+impl From<notify::Error> for WatcherError {
+    fn from(value: notify::Error) -> Self {
+        WatcherError::Notify(value)
+    }
+}
@@ -9,4 +9,13 @@ pub mod elite_journal_watcher;
 
 /// Module contains the code to persist configuration of watchers
 pub mod config;
+
+/// Module contains the error type of the watcher
+pub mod error;
+
+/// Module contains the native and polling file watchers used to watch the journal directory
+pub mod debouncer;
 pub mod processor;
+
+/// Module contains the replayer used to re-emit historical journal files
+pub mod replayer;
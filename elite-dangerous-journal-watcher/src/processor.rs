@@ -1,11 +1,28 @@
 use std::future::Future;
+use std::path::Path;
 use std::sync::{Arc, RwLock};
 use notify_debouncer_full::DebounceEventResult;
+use tracing::error;
 use crate::config::JournalWatcherConfig;
+use crate::error::WatcherError;
 
 pub mod log_event_processor;
 pub mod journal_file_processor;
+pub mod companion_file;
+pub mod journal_file_name;
+pub mod tail_reader;
 
 pub trait NotifierProcessor {
-    fn process(&self, event: DebounceEventResult, config: Arc<RwLock<JournalWatcherConfig>>) -> impl Future<Output = Result<(), String>> + Send;
+    fn process(&self, event: DebounceEventResult, config: Arc<RwLock<JournalWatcherConfig>>) -> impl Future<Output = Result<(), WatcherError>> + Send;
+
+    /// Called once at start up, before any events are processed, to read the files that already exist in the
+    /// journal directory. By default nothing is read
+    fn backfill(&self, _journal_dir: &Path, _config: Arc<RwLock<JournalWatcherConfig>>) -> impl Future<Output = Result<(), WatcherError>> + Send {
+        async { Ok(()) }
+    }
+
+    /// Called with any error that should not stop the watcher, such as a failed backfill. By default the error is logged
+    fn report_error(&self, watcher_error: WatcherError) -> impl Future<Output = ()> + Send {
+        async move { error!("{}", watcher_error); }
+    }
 }
@@ -0,0 +1,51 @@
+use elite_dangerous_journal_model::events::EliteDangerousEvent;
+use elite_dangerous_journal_model::events::other::module_info::ModuleInfoEvent;
+use elite_dangerous_journal_model::events::startup::cargo::CargoEvent;
+use elite_dangerous_journal_model::events::station_services::market::MarketEvent;
+use elite_dangerous_journal_model::events::station_services::outfitting::OutfittingEvent;
+use elite_dangerous_journal_model::events::station_services::shipyard::ShipyardEvent;
+use elite_dangerous_journal_model::events::status::status_file::StatusFile;
+use elite_dangerous_journal_model::events::travel::nav_route::NavRouteEvent;
+
+/// The companion files that elite dangerous rewrites (rather than appends to) in the journal directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompanionFile {
+    Status,
+    NavRoute,
+    Cargo,
+    Market,
+    Outfitting,
+    Shipyard,
+    ModulesInfo,
+}
+
+impl CompanionFile {
+
+    /// Get the companion file that matches the supplied file name, returns None if the file is not a companion file
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        match file_name {
+            "Status.json" => Some(Self::Status),
+            "NavRoute.json" => Some(Self::NavRoute),
+            "Cargo.json" => Some(Self::Cargo),
+            "Market.json" => Some(Self::Market),
+            "Outfitting.json" => Some(Self::Outfitting),
+            "Shipyard.json" => Some(Self::Shipyard),
+            "ModulesInfo.json" => Some(Self::ModulesInfo),
+            _ => None,
+        }
+    }
+
+    /// Parse the full contents of the companion file into the relevant [EliteDangerousEvent](EliteDangerousEvent)
+    pub fn parse(&self, contents: &str) -> serde_json::Result<EliteDangerousEvent> {
+        let event = match self {
+            Self::Status => EliteDangerousEvent::StatusFile(serde_json::from_str::<StatusFile>(contents)?),
+            Self::NavRoute => EliteDangerousEvent::NavRouteFile(serde_json::from_str::<NavRouteEvent>(contents)?),
+            Self::Cargo => EliteDangerousEvent::CargoFile(serde_json::from_str::<CargoEvent>(contents)?),
+            Self::Market => EliteDangerousEvent::MarketFile(serde_json::from_str::<MarketEvent>(contents)?),
+            Self::Outfitting => EliteDangerousEvent::OutfittingFile(serde_json::from_str::<OutfittingEvent>(contents)?),
+            Self::Shipyard => EliteDangerousEvent::ShipyardFile(serde_json::from_str::<ShipyardEvent>(contents)?),
+            Self::ModulesInfo => EliteDangerousEvent::ModulesInfoFile(serde_json::from_str::<ModuleInfoEvent>(contents)?),
+        };
+        Ok(event)
+    }
+}
@@ -0,0 +1,83 @@
+use std::path::Path;
+use lazy_regex::regex_captures;
+
+/// The parsed name of a journal log file, used to order the journal files
+///
+/// Two formats of name are supported:
+///
+/// ```text
+/// Journal.2025-01-24T172047.01.log  (current)
+/// Journal.250124172047.01.log       (pre 4.0)
+/// ```
+///
+/// Ordering is by the timestamp in the name and then the part number, so continuation files (written once a
+/// journal reaches its line limit) sort after the file they continue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JournalFileName {
+    /// The timestamp in the name as a number of the form YYYYMMDDHHMMSS
+    pub timestamp: u64,
+    /// The part number of the file
+    pub part: u32,
+}
+
+impl JournalFileName {
+
+    /// Parse the file name, returns None if the name is not a journal log file name
+    pub fn parse(file_name: &str) -> Option<Self> {
+        if let Some((_, year, month, day, time, part)) = regex_captures!(
+            r"^Journal\.(\d{4})-(\d{2})-(\d{2})T(\d{6})\.(\d+)\.log$",
+            file_name
+        ) {
+            let timestamp = format!("{year}{month}{day}{time}").parse().ok()?;
+            return Some(Self { timestamp, part: part.parse().ok()? });
+        }
+
+        if let Some((_, timestamp, part)) = regex_captures!(
+            r"^Journal\.(\d{12})\.(\d+)\.log$",
+            file_name
+        ) {
+            let timestamp = format!("20{timestamp}").parse().ok()?;
+            return Some(Self { timestamp, part: part.parse().ok()? });
+        }
+
+        None
+    }
+
+    /// Parse the file name of the path, returns None if the path is not a journal log file
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        path.as_ref().file_name()
+            .and_then(|name| name.to_str())
+            .and_then(Self::parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_journal_file_name() {
+        assert_eq!(JournalFileName::parse("Journal.2025-01-24T172047.01.log"), Some(JournalFileName { timestamp: 20250124172047, part: 1 }));
+        assert_eq!(JournalFileName::parse("Journal.250124172047.02.log"), Some(JournalFileName { timestamp: 20250124172047, part: 2 }));
+        assert_eq!(JournalFileName::parse("Status.json"), None);
+        assert_eq!(JournalFileName::parse("Journal.2025-01-24T172047.01.log.bak"), None);
+    }
+
+    #[test]
+    fn test_journal_file_name_ordering() {
+        let mut names = vec![
+            "Journal.2025-01-24T172047.02.log",
+            "Journal.2025-01-25T090000.01.log",
+            "Journal.2025-01-24T172047.01.log",
+            "Journal.241231235959.01.log",
+        ];
+        names.sort_by_key(|name| JournalFileName::parse(name));
+
+        assert_eq!(names, vec![
+            "Journal.241231235959.01.log",
+            "Journal.2025-01-24T172047.01.log",
+            "Journal.2025-01-24T172047.02.log",
+            "Journal.2025-01-25T090000.01.log",
+        ]);
+    }
+}
@@ -1,95 +1,399 @@
+use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Seek, SeekFrom};
-use std::path::PathBuf;
-use std::sync::{Arc, RwLock};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::Duration;
 use crate::processor::NotifierProcessor;
-use lazy_regex::regex_is_match;
 use notify::EventKind;
 use notify_debouncer_full::DebounceEventResult;
 use tokio::sync::mpsc::Sender;
 use tracing::{debug, error, info, trace, warn};
+use chrono::Utc;
 use elite_dangerous_journal_model::events::{EliteDangerousEvent, JournalEvent};
+use elite_dangerous_journal_model::events::envelope::{CommanderSession, EventEnvelope, EventSource};
+use elite_dangerous_journal_model::events::status::status_change::StatusChange;
+use elite_dangerous_journal_model::events::status::status_file::StatusFile;
 use crate::config::JournalWatcherConfig;
+use crate::error::WatcherError;
+use crate::processor::companion_file::CompanionFile;
+use crate::processor::journal_file_name::JournalFileName;
+use crate::processor::tail_reader::read_complete_lines;
 
 
 /// The journal file processor is used as a processor passed to the [start](crate::elite_journal_watcher::start) function
 /// This processes the [DebounceEventResult](DebounceEventResult) to produce a [EliteDangerousEvent](EliteDangerousEvent) for the data being process
+/// which is published wrapped in an [EventEnvelope](EventEnvelope) that records where it came from
 ///
 ///
 pub struct JournalFileProcessor {
 
-    /// The [MSPC Channel Sender](Sender) to publish the EliteDangerousEvents](EliteDangerousEvent) to
-    event_tx: Sender<EliteDangerousEvent>,
+    /// The [MSPC Channel Sender](Sender) to publish the [EventEnvelopes](EventEnvelope) to
+    event_tx: Sender<EventEnvelope>,
+    /// The [MSPC Channel Sender](Sender) to publish recoverable [WatcherErrors](WatcherError) to
+    error_tx: Sender<WatcherError>,
     /// The timeout in (milliseconds) to wait for the sender to become available
     sender_timeout: Duration,
+    /// The contents of each companion file when it was last emitted, used to avoid emitting duplicate events
+    companion_file_cache: Mutex<HashMap<PathBuf, String>>,
+    /// The last status snapshot, used to derive the [StatusChange](StatusChange) events
+    previous_status: Mutex<Option<StatusFile>>,
+    /// The sequence number of the next event to be sent, this is saved with the file positions
+    sequence: AtomicU64,
+    /// The commander session from the last ```Commander```/```LoadGame``` event
+    session: Mutex<Option<CommanderSession>>,
 }
 
 
 impl NotifierProcessor for JournalFileProcessor {
-    async fn process(&self, event_list: DebounceEventResult, config: Arc<RwLock<JournalWatcherConfig>>) -> Result<(), String> {
-        for debounced_event in event_list.expect("Failed to get event list") {
+    async fn process(&self, event_list: DebounceEventResult, config: Arc<RwLock<JournalWatcherConfig>>) -> Result<(), WatcherError> {
+        let mut journal_files: Vec<(JournalFileName, PathBuf)> = Vec::new();
+        let mut companion_files: Vec<(CompanionFile, PathBuf)> = Vec::new();
+
+        let event_list = match event_list {
+            Ok(event_list) => event_list,
+            Err(errors) => {
+                for e in errors {
+                    self.report_error(WatcherError::Notify(e)).await;
+                }
+                return Ok(())
+            }
+        };
+
+        for debounced_event in event_list {
             let event = debounced_event.event;
             trace!("Processing event: {:?}", event);
 
             match event.kind {
-                EventKind::Modify(_) => {
+                EventKind::Create(_) | EventKind::Modify(_) => {
                     for path in event.paths {
-                        let filename = path.file_name().unwrap().to_str().unwrap();
-
-                        if regex_is_match!(
-                            r"^Journal.*\.log$",
-                            filename
-                        ) {
-                            debug!("Detected Journal file modified: {:?}", path);
-                            self.process_log_file(path, Arc::clone(&config)).await;
+                        let Some(filename) = path.file_name().and_then(|name| name.to_str()) else {
+                            trace!("Skipping path without a file name: {:?}", path);
+                            continue;
+                        };
+
+                        if let Some(journal_file_name) = JournalFileName::parse(filename) {
+                            debug!("Detected Journal file created/modified: {:?}", path);
+                            if !journal_files.iter().any(|(_, p)| p == &path) {
+                                journal_files.push((journal_file_name, path));
+                            }
+                        } else if let Some(companion_file) = CompanionFile::from_file_name(filename) {
+                            debug!("Detected companion file created/modified: {:?}", path);
+                            if !companion_files.iter().any(|(_, p)| p == &path) {
+                                companion_files.push((companion_file, path));
+                            }
                         }
                     }
                 }
                 _ => {}
             }
         }
+
+        // Process the journal files oldest first so that events are emitted in order across a rotation
+        journal_files.sort();
+        for (journal_file_name, path) in journal_files {
+            if let Err(e) = self.process_journal_file(journal_file_name, path, Arc::clone(&config)).await {
+                self.report_error(e).await;
+            }
+        }
+
+        for (companion_file, path) in companion_files {
+            self.process_companion_file(path, companion_file).await;
+        }
         Ok(())
     }
+
+    async fn backfill(&self, journal_dir: &Path, config_lock: Arc<RwLock<JournalWatcherConfig>>) -> Result<(), WatcherError> {
+        let entries = std::fs::read_dir(journal_dir)
+            .map_err(|source| WatcherError::Io { path: journal_dir.to_path_buf(), source })?;
+
+        let paths: Vec<PathBuf> = entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()).collect();
+
+        let mut journal_files: Vec<(JournalFileName, PathBuf)> = paths.iter()
+            .filter_map(|path| JournalFileName::from_path(path).map(|name| (name, path.clone())))
+            .collect();
+        journal_files.sort();
+
+        info!("Backfilling {} journal files from {:?}", journal_files.len(), journal_dir);
+
+        let latest_file = journal_files.last().map(|(_, path)| path.clone());
+        for (_, path) in journal_files {
+            let finished = config_lock.read()
+                .map_err(|e| WatcherError::Config(format!("Failed to read config: {}", e)))?
+                .data.finished_files.contains(&path);
+            if finished {
+                trace!("Journal file {:?} is finished, skipping", path);
+                continue;
+            }
+
+            debug!("Backfilling journal file: {:?}", path);
+            if let Err(e) = self.process_log_file(path.clone(), Arc::clone(&config_lock), true).await {
+                self.report_error(e).await;
+            }
+
+            // Only the latest journal file can still be written to by the game
+            if Some(&path) != latest_file.as_ref() {
+                let mut config = config_lock.write()
+                    .map_err(|e| WatcherError::Config(format!("Failed to update config: {}", e)))?;
+                config.data.finished_files.insert(path);
+                config.save()?;
+            }
+        }
+
+        // The companion files are only rewritten when their contents change, so the current contents are read once
+        // otherwise consumers would not see them until the game next rewrites each file
+        for path in paths {
+            let companion_file = path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(CompanionFile::from_file_name);
+            if let Some(companion_file) = companion_file {
+                debug!("Reading companion file: {:?}", path);
+                self.process_companion_file(path, companion_file).await;
+            }
+        }
+
+        self.send_event(EliteDangerousEvent::BackfillComplete, None).await;
+        Ok(())
+    }
+
+    /// Publish the error to the error channel so subscribers can decide how to handle it, it is only logged here if
+    /// the channel is unavailable
+    async fn report_error(&self, watcher_error: WatcherError) {
+        debug!("Reporting error: {}", watcher_error);
+        if let Err(e) = self.error_tx.send_timeout(watcher_error, self.sender_timeout).await {
+            error!("Failed to send error to channel: {}", e);
+        }
+    }
 }
 
 impl JournalFileProcessor {
 
-    pub fn new(event_tx: Sender<EliteDangerousEvent>, sender_timeout: u64) -> Self {
+    pub fn new(event_tx: Sender<EventEnvelope>, error_tx: Sender<WatcherError>, sender_timeout: u64) -> Self {
         Self {
             event_tx,
+            error_tx,
             sender_timeout: Duration::from_millis(sender_timeout),
+            companion_file_cache: Mutex::new(HashMap::new()),
+            previous_status: Mutex::new(None),
+            sequence: AtomicU64::new(0),
+            session: Mutex::new(None),
+        }
+    }
+
+    /// Wrap the event in an envelope and publish it, journal events also update the commander session
+    async fn send_event(&self, ed_event: EliteDangerousEvent, source: Option<EventSource>) {
+        let session = match self.session.lock() {
+            Ok(mut session) => {
+                if let EliteDangerousEvent::JournalEvent(event) | EliteDangerousEvent::BackfilledJournalEvent(event) = &ed_event {
+                    CommanderSession::track(&mut session, event);
+                }
+                session.clone()
+            }
+            Err(e) => {
+                error!("Failed to lock commander session: {}", e);
+                None
+            }
+        };
+
+        let envelope = EventEnvelope {
+            event: ed_event,
+            source,
+            received: Utc::now(),
+            sequence: self.sequence.fetch_add(1, Ordering::Relaxed),
+            session,
+        };
+
+        match self.event_tx.send_timeout(envelope, self.sender_timeout).await {
+            Ok(_) => { trace!("Event sent to channel"); }
+            Err(e) => {
+                self.report_error(WatcherError::Channel(format!("Failed to send event to channel: {}", e))).await;
+            }
+        }
+    }
+
+    /// Companion files are rewritten in full by the game, so the whole file is read and parsed each time. If the
+    /// contents are unchanged since the last emitted event then no event is sent
+    async fn process_companion_file(&self, file_path: PathBuf, companion_file: CompanionFile) {
+
+        let contents = match std::fs::read_to_string(&file_path) {
+            Ok(contents) => contents,
+            Err(source) => {
+                self.report_error(WatcherError::Io { path: file_path, source }).await;
+                return
+            }
+        };
+
+        let contents = contents.trim();
+        if contents.is_empty() {
+            trace!("Skipping empty companion file: {:?}", file_path);
+            return
+        }
+
+        match self.companion_file_cache.lock() {
+            Ok(cache) => {
+                if cache.get(&file_path).is_some_and(|previous| previous == contents) {
+                    trace!("Companion file {:?} unchanged, skipping", file_path);
+                    return
+                }
+            }
+            Err(e) => {
+                error!("Failed to lock companion file cache: {}", e);
+                return
+            }
+        }
+
+        // The game may still be part way through rewriting the file, a later modify event will pick up the full contents
+        let ed_event = match companion_file.parse(contents) {
+            Ok(ed_event) => ed_event,
+            Err(e) => {
+                debug!("Failed to parse companion file {:?}: {}", file_path, e);
+                return
+            }
+        };
+
+        trace!("Companion file event: {:?}", ed_event);
+
+        if let Ok(mut cache) = self.companion_file_cache.lock() {
+            cache.insert(file_path.clone(), contents.to_string());
+        }
+
+        let source = EventSource { path: file_path, part: None, line_number: None, byte_offset: None };
+
+        let status_changes = match &ed_event {
+            EliteDangerousEvent::StatusFile(status) => self.diff_status(status),
+            _ => Vec::new(),
+        };
+
+        self.send_event(ed_event, Some(source)).await;
+
+        for status_change in status_changes {
+            debug!("Status change: {:?}", status_change);
+            self.send_event(EliteDangerousEvent::StatusChange(status_change), None).await;
+        }
+    }
+
+    /// Compare the status against the previous snapshot (if any) and store it as the new previous snapshot
+    fn diff_status(&self, status: &StatusFile) -> Vec<StatusChange> {
+        match self.previous_status.lock() {
+            Ok(mut previous_status) => {
+                let changes = match previous_status.as_ref() {
+                    None => Vec::new(),
+                    Some(previous) => StatusChange::diff(previous, status),
+                };
+                *previous_status = Some(status.clone());
+                changes
+            }
+            Err(e) => {
+                error!("Failed to lock previous status: {}", e);
+                Vec::new()
+            }
         }
     }
 
-    async fn process_log_file(&self, file_path: PathBuf, config_lock: Arc<RwLock<JournalWatcherConfig>>) {
+    /// Process a journal file that has been created or modified. Any older journal files that have not yet been
+    /// finished are read to the end and marked as finished first, as the game will not write to them again
+    async fn process_journal_file(&self, journal_file_name: JournalFileName, file_path: PathBuf, config_lock: Arc<RwLock<JournalWatcherConfig>>) -> Result<(), WatcherError> {
+
+        let mut older_files: Vec<(JournalFileName, PathBuf)>;
+        {
+            let config = config_lock.read().map_err(|e| WatcherError::Config(format!("Failed to read config: {}", e)))?;
+            if config.data.finished_files.contains(&file_path) {
+                trace!("Journal file {:?} is finished, skipping", file_path);
+                return Ok(())
+            }
+
+            older_files = config.data.file_positions.keys()
+                .filter(|path| !config.data.finished_files.contains(*path))
+                .filter_map(|path| JournalFileName::from_path(path).map(|name| (name, path.clone())))
+                .filter(|(name, _)| *name < journal_file_name)
+                .collect();
+        }
+
+        older_files.sort();
+        for (_, older_file) in older_files {
+            debug!("Newer journal file {:?} detected, finishing {:?}", file_path, older_file);
+            // The older file may have been removed, it is still marked as finished so it is not retried
+            if let Err(e) = self.process_log_file(older_file.clone(), Arc::clone(&config_lock), false).await {
+                self.report_error(e).await;
+            }
+
+            let mut config = config_lock.write().map_err(|e| WatcherError::Config(format!("Failed to update config: {}", e)))?;
+            config.data.finished_files.insert(older_file);
+            config.save()?;
+        }
+
+        self.process_log_file(file_path, config_lock, false).await
+    }
+
+    /// Read the new complete lines of the journal file from the saved position, if backfill is true the events are
+    /// emitted as [BackfilledJournalEvents](EliteDangerousEvent::BackfilledJournalEvent)
+    /// The commander session is only kept in memory, so when a journal file is resumed part way through (e.g. after a
+    /// restart) the lines already read are scanned for the ```Commander```/```LoadGame``` events to rebuild it
+    fn restore_session(&self, reader: &mut BufReader<File>, file_path: &Path, file_pos: u64) {
+        let mut session = match self.session.lock() {
+            Ok(session) => session,
+            Err(e) => {
+                error!("Failed to lock commander session: {}", e);
+                return
+            }
+        };
+        if session.is_some() {
+            return
+        }
+
+        let tailed_lines = match read_complete_lines(reader, 0) {
+            Ok(tailed_lines) => tailed_lines,
+            Err(e) => {
+                warn!("Unable to rebuild the commander session from {:?}: {}", file_path, e);
+                return
+            }
+        };
+        for tailed_line in tailed_lines.lines.iter().take_while(|tailed_line| tailed_line.offset < file_pos) {
+            if let Ok(event) = serde_json::from_str::<JournalEvent>(&tailed_line.text) {
+                CommanderSession::track(&mut session, &event);
+            }
+        }
+        debug!("Rebuilt commander session from {:?}: {:?}", file_path, session);
+    }
+
+    async fn process_log_file(&self, file_path: PathBuf, config_lock: Arc<RwLock<JournalWatcherConfig>>, backfill: bool) -> Result<(), WatcherError> {
 
         let mut file_pos: u64;
+        let mut line_number: u64;
         match config_lock.read() {
             Ok(config) => { 
                 match config.data.file_positions.get(&file_path) {
                     None => { file_pos = 0; }
                     Some(pos) => { file_pos = *pos;}
                 }
-                
+                line_number = config.data.file_line_numbers.get(&file_path).copied().unwrap_or(0);
+                // After a restart the sequence carries on from the saved value rather than starting again at 0
+                self.sequence.fetch_max(config.data.next_sequence, Ordering::Relaxed);
             }
             Err(e) => {
-                error!("Failed to read config: {}", e);
-                return
+                return Err(WatcherError::Config(format!("Failed to read config: {}", e)))
             }
         }
 
         debug!("Processing log file: {:?} from position {file_pos}", file_path);
         
-        let mut f = File::open(&file_path).expect("Failed to open journal file");
-        
-        f.seek(SeekFrom::Start(file_pos)).expect("Failed to seek to file position");
-        
-        file_pos = f.metadata().expect("Failed to get file metadata").len();
-        
-        let reader = BufReader::new(f);
-        for line in reader.lines() {
-            let line = line.expect("Failed to read line");
+        let f = File::open(&file_path).map_err(|source| WatcherError::Io { path: file_path.clone(), source })?;
+        let mut reader = BufReader::new(f);
+
+        if file_pos > 0 {
+            self.restore_session(&mut reader, &file_path, file_pos);
+        }
+
+        // Only complete lines are consumed, a line the game is still writing is picked up on the next modify
+        let tailed_lines = read_complete_lines(&mut reader, file_pos)
+            .map_err(|source| WatcherError::Io { path: file_path.clone(), source })?;
+        file_pos = tailed_lines.position;
+        let part = JournalFileName::from_path(&file_path).map(|name| name.part);
+
+        for tailed_line in tailed_lines.lines {
+            line_number += 1;
+            let line = tailed_line.text;
             if line.is_empty() {
                 trace!("Skipping empty line");
                 continue;
@@ -100,22 +404,26 @@ impl JournalFileProcessor {
                 Ok(event) => {
                     info!("Event: {:?}", event);
                     
-                    match event {
-                        JournalEvent::Unknown => { warn!("Unknown event: {}", line);}
-                        _ => {}
+                    if let JournalEvent::Unknown(unknown) = &event {
+                        warn!("Unknown event {}: {}", unknown.event, line);
                     }
                     
-                    let ed_event = EliteDangerousEvent::JournalEvent(event);
+                    let ed_event = if backfill {
+                        EliteDangerousEvent::BackfilledJournalEvent(event)
+                    } else {
+                        EliteDangerousEvent::JournalEvent(event)
+                    };
 
-                    match self.event_tx.send_timeout(ed_event, self.sender_timeout).await {
-                        Ok(_) => { trace!("Event sent to channel"); }
-                        Err(e) => { 
-                            error!("Failed to send event to channel: {}", e);
-                        }
-                    }
+                    let source = EventSource {
+                        path: file_path.clone(),
+                        part,
+                        line_number: Some(line_number),
+                        byte_offset: Some(tailed_line.offset),
+                    };
+                    self.send_event(ed_event, Some(source)).await;
                 }
-                Err(e) => {
-                    error!("Failed to parse event: {}", e);
+                Err(source) => {
+                    self.report_error(WatcherError::Parse { content: line, source }).await;
                 }
             }
         }
@@ -123,13 +431,78 @@ impl JournalFileProcessor {
         match config_lock.write() {
             Ok(mut config) => {
                 debug!("Updating config for position for file: {:?}...", file_path);
+                config.data.file_line_numbers.insert(file_path.clone(), line_number);
                 config.data.file_positions.insert(file_path, file_pos);
-                config.save();
+                config.data.next_sequence = self.sequence.load(Ordering::Relaxed);
+                config.save()?;
                 debug!("...updated config");
+                Ok(())
             }
-            Err(_) => {
-                error!("Failed to update config");
+            Err(e) => {
+                Err(WatcherError::Config(format!("Failed to update config: {}", e)))
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_backfill_reads_existing_companion_files() {
+        let journal_dir = std::env::temp_dir().join(format!("journal_watcher_backfill_{}", std::process::id()));
+        std::fs::create_dir_all(&journal_dir).unwrap();
+        std::fs::write(journal_dir.join("Status.json"), r#"{ "timestamp":"2025-01-04T19:31:02Z", "event":"Status", "Flags":16842765, "Pips":[4,8,0], "FireGroup":0, "GuiFocus":0, "Fuel":{ "FuelMain":32.000000, "FuelReservoir":0.630000 }, "Cargo":0.000000, "LegalState":"Clean", "Balance":53038302 }"#).unwrap();
+        std::fs::write(journal_dir.join("Cargo.json"), r#"{ "timestamp":"2025-01-04T19:27:41Z", "event":"Cargo", "Vessel":"Ship", "Count":1, "Inventory":[ { "Name":"drones", "Name_Localised":"Limpet", "Count":1, "Stolen":0 } ] }"#).unwrap();
+
+        let (event_tx, mut event_rx) = tokio::sync::mpsc::channel(16);
+        let (error_tx, _error_rx) = tokio::sync::mpsc::channel(16);
+        let processor = JournalFileProcessor::new(event_tx, error_tx, 1000);
+        let config = Arc::new(RwLock::new(JournalWatcherConfig::new(&journal_dir).unwrap()));
+
+        processor.backfill(&journal_dir, config).await.expect("Failed to backfill");
+        std::fs::remove_dir_all(&journal_dir).unwrap();
+
+        let mut received = Vec::new();
+        while let Ok(envelope) = event_rx.try_recv() {
+            received.push(envelope.event);
+        }
+        assert_eq!(received.len(), 3);
+        assert!(received.iter().any(|event| matches!(event, EliteDangerousEvent::StatusFile(_))));
+        assert!(received.iter().any(|event| matches!(event, EliteDangerousEvent::CargoFile(_))));
+        assert!(matches!(received.last(), Some(EliteDangerousEvent::BackfillComplete)));
+    }
+
+    #[tokio::test]
+    async fn test_resumed_journal_restores_session_and_sequence() {
+        let journal_dir = std::env::temp_dir().join(format!("journal_watcher_resume_{}", std::process::id()));
+        std::fs::create_dir_all(&journal_dir).unwrap();
+        let commander = r#"{ "timestamp":"2025-01-04T19:27:09Z", "event":"Commander", "FID":"F00000000", "Name":"ANON" }"#;
+        let music = r#"{ "timestamp":"2025-01-04T19:27:32Z", "event":"Music", "MusicTrack":"NoTrack" }"#;
+        let journal_file = journal_dir.join("Journal.2025-01-04T192700.01.log");
+        std::fs::write(&journal_file, format!("{}\n{}\n", commander, music)).unwrap();
+
+        // The state saved before the restart, the commander line has already been read
+        let mut config = JournalWatcherConfig::new(&journal_dir).unwrap();
+        config.data.file_positions.insert(journal_file.clone(), commander.len() as u64 + 1);
+        config.data.file_line_numbers.insert(journal_file.clone(), 1);
+        config.data.next_sequence = 42;
+        let config = Arc::new(RwLock::new(config));
+
+        let (event_tx, mut event_rx) = tokio::sync::mpsc::channel(16);
+        let (error_tx, _error_rx) = tokio::sync::mpsc::channel(16);
+        let processor = JournalFileProcessor::new(event_tx, error_tx, 1000);
+
+        processor.backfill(&journal_dir, Arc::clone(&config)).await.expect("Failed to backfill");
+        let saved_sequence = config.read().unwrap().data.next_sequence;
+        std::fs::remove_dir_all(&journal_dir).unwrap();
+
+        let envelope = event_rx.try_recv().expect("Expected the music event");
+        assert!(matches!(envelope.event, EliteDangerousEvent::BackfilledJournalEvent(JournalEvent::Music(_))));
+        assert_eq!(envelope.sequence, 42);
+        assert_eq!(envelope.source.and_then(|source| source.line_number), Some(2));
+        assert_eq!(envelope.session.map(|session| session.commander.name), Some("ANON".to_string()));
+        assert_eq!(saved_sequence, 43);
+    }
+}
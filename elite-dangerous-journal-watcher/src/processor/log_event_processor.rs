@@ -2,12 +2,13 @@ use std::sync::{Arc, RwLock};
 use notify_debouncer_full::DebounceEventResult;
 use tracing::{error, info};
 use crate::config::JournalWatcherConfig;
+use crate::error::WatcherError;
 use crate::processor::NotifierProcessor;
 
 pub struct LogEventProcessor {}
 
 impl NotifierProcessor for LogEventProcessor {
-    async fn process(&self, event: DebounceEventResult, _ : Arc<RwLock<JournalWatcherConfig>>) -> Result<(), String> {
+    async fn process(&self, event: DebounceEventResult, _ : Arc<RwLock<JournalWatcherConfig>>) -> Result<(), WatcherError> {
         match event {
             Ok(event) => info!("{:?}", event) ,
             Err(err) => { error!("{:?}", err);},
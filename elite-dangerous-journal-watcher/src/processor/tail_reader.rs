@@ -0,0 +1,83 @@
+use std::io::{BufRead, Seek, SeekFrom};
+
+/// A complete line read from a journal file
+#[derive(Debug, PartialEq)]
+pub struct TailedLine {
+    /// The line with the line ending removed
+    pub text: String,
+    /// The byte offset of the start of the line
+    pub offset: u64,
+}
+
+/// The complete lines read from a journal file and the offset to resume reading from
+#[derive(Debug, Default, PartialEq)]
+pub struct TailedLines {
+    /// The newline terminated lines that were read
+    pub lines: Vec<TailedLine>,
+    /// The byte offset just past the last newline terminated line, this is where the next read should start
+    pub position: u64,
+}
+
+/// Read the complete (newline terminated) lines from the reader starting at the supplied byte offset
+///
+/// The game writes each event as a single line, so a trailing line without a newline is still being written.
+/// This is not returned and the returned position is left at the start of it, so it is read in full on the
+/// next pass
+pub fn read_complete_lines<R: BufRead + Seek>(reader: &mut R, start_position: u64) -> std::io::Result<TailedLines> {
+    reader.seek(SeekFrom::Start(start_position))?;
+
+    let mut tailed_lines = TailedLines { lines: Vec::new(), position: start_position };
+    let mut buffer: Vec<u8> = Vec::new();
+
+    loop {
+        buffer.clear();
+        let bytes_read = reader.read_until(b'\n', &mut buffer)?;
+        if bytes_read == 0 || buffer.last() != Some(&b'\n') {
+            break;
+        }
+
+        let line = String::from_utf8_lossy(&buffer);
+        tailed_lines.lines.push(TailedLine {
+            text: line.trim_end_matches(['\r', '\n']).to_string(),
+            offset: tailed_lines.position,
+        });
+        tailed_lines.position += bytes_read as u64;
+    }
+
+    Ok(tailed_lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use super::*;
+
+    #[test]
+    fn test_read_complete_lines_keeps_partial_line() {
+        let mut reader = Cursor::new(b"{\"event\":\"Music\"}\r\n{\"event\":\"Shutdown\"}\n{\"event\":\"Fil".to_vec());
+
+        let tailed = read_complete_lines(&mut reader, 0).unwrap();
+
+        assert_eq!(tailed.lines, vec![
+            TailedLine { text: "{\"event\":\"Music\"}".to_string(), offset: 0 },
+            TailedLine { text: "{\"event\":\"Shutdown\"}".to_string(), offset: 19 },
+        ]);
+        assert_eq!(tailed.position, 40);
+
+        // The rest of the partial line is written
+        reader.get_mut().extend_from_slice(b"eheader\"}\n");
+        let tailed = read_complete_lines(&mut reader, tailed.position).unwrap();
+
+        assert_eq!(tailed.lines, vec![TailedLine { text: "{\"event\":\"Fileheader\"}".to_string(), offset: 40 }]);
+        assert_eq!(tailed.position, reader.get_ref().len() as u64);
+    }
+
+    #[test]
+    fn test_read_complete_lines_nothing_new() {
+        let mut reader = Cursor::new(b"{\"event\":\"Music\"}\n".to_vec());
+
+        let tailed = read_complete_lines(&mut reader, 18).unwrap();
+
+        assert_eq!(tailed, TailedLines { lines: vec![], position: 18 });
+    }
+}
@@ -0,0 +1,365 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::time::Duration;
+use chrono::{NaiveDateTime, Utc};
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{debug, info, trace, warn};
+use tokio::time::Instant;
+use elite_dangerous_journal_model::events::{EliteDangerousEvent, JournalEvent};
+use elite_dangerous_journal_model::events::envelope::{CommanderSession, EventEnvelope, EventSource};
+use elite_dangerous_journal_model::events::journal_event_ext::JournalEventExt;
+use crate::error::WatcherError;
+use crate::processor::journal_file_name::JournalFileName;
+
+/// How quickly the replayer emits events relative to the gaps between their timestamps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    /// Multiply the speed by the factor, i.e. 1.0 is real time and 10.0 is ten times faster
+    Factor(f64),
+    /// Ignore the timestamps and emit the events as fast as the channel accepts them
+    AsFastAsPossible,
+}
+
+impl ReplaySpeed {
+
+    /// The time to wait between two events that are gap apart
+    fn scale(&self, gap: Duration) -> Duration {
+        match self {
+            ReplaySpeed::Factor(factor) if *factor > 0.0 => gap.div_f64(*factor),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// The gap between two events that corresponds to having waited for elapsed, the inverse of [scale](Self::scale)
+    fn unscale(&self, elapsed: Duration) -> Duration {
+        match self {
+            ReplaySpeed::Factor(factor) if *factor > 0.0 => elapsed.mul_f64(*factor),
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// An event loaded from a journal file ready to be replayed
+#[derive(Debug, Clone)]
+pub struct ReplayEvent {
+    /// The timestamp of the event
+    pub timestamp: NaiveDateTime,
+    /// The event
+    pub event: JournalEvent,
+    /// Where the event was read from
+    pub source: EventSource,
+}
+
+/// Controls that can be sent to a running [JournalReplayer](JournalReplayer)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplayControl {
+    /// Stop emitting events until resumed
+    Pause,
+    /// Carry on emitting events after a pause
+    Resume,
+    /// Jump to the first event at or after the timestamp (this can be before the current event)
+    Seek(NaiveDateTime),
+    /// Change the speed of the replay
+    SetSpeed(ReplaySpeed),
+    /// Stop the replay
+    Stop,
+}
+
+/// Replays historical journal files, publishing [EliteDangerousEvents](EliteDangerousEvent) on the same channel as the
+/// [JournalFileProcessor](crate::processor::journal_file_processor::JournalFileProcessor) would. This allows plugins
+/// to be developed and demoed without the game running
+///
+/// # Non-Running Example
+/// ```ignore
+///     let (event_tx, event_rx) = tokio::sync::mpsc::channel(1024);
+///     let (control_tx, control_rx) = tokio::sync::mpsc::channel(16);
+///
+///     let replayer = JournalReplayer::new(event_tx, vec![PathBuf::from("Journal.2025-01-04T185730.01.log")], ReplaySpeed::Factor(10.0));
+///     tokio::spawn(replayer.run(control_rx));
+///
+///     control_tx.send(ReplayControl::Pause).await?;
+/// ```
+pub struct JournalReplayer {
+    /// The [MSPC Channel Sender](Sender) to publish the [EventEnvelopes](EventEnvelope) to
+    event_tx: Sender<EventEnvelope>,
+    /// The journal files to replay, these are replayed in the order of the timestamp in their names
+    files: Vec<PathBuf>,
+    /// The speed to replay at
+    speed: ReplaySpeed,
+}
+
+impl JournalReplayer {
+
+    pub fn new(event_tx: Sender<EventEnvelope>, files: Vec<PathBuf>, speed: ReplaySpeed) -> Self {
+        Self {
+            event_tx,
+            files,
+            speed,
+        }
+    }
+
+    /// Read all the events from the files, lines that cannot be parsed are skipped
+    pub fn load(&self) -> Result<Vec<ReplayEvent>, WatcherError> {
+        let mut files = self.files.clone();
+        files.sort_by_key(|path| JournalFileName::from_path(path));
+
+        let mut events = Vec::new();
+        for path in files {
+            debug!("Loading journal file for replay: {:?}", path);
+            let file = File::open(&path).map_err(|source| WatcherError::Io { path: path.clone(), source })?;
+
+            let part = JournalFileName::from_path(&path).map(|name| name.part);
+            let mut reader = BufReader::new(file);
+            let mut line = String::new();
+            let mut line_number: u64 = 0;
+            let mut byte_offset: u64 = 0;
+            loop {
+                line.clear();
+                let bytes_read = reader.read_line(&mut line).map_err(|source| WatcherError::Io { path: path.clone(), source })?;
+                if bytes_read == 0 {
+                    break;
+                }
+                let offset = byte_offset;
+                byte_offset += bytes_read as u64;
+                line_number += 1;
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                match serde_json::from_str::<JournalEvent>(&line) {
+                    Ok(event) => events.push(ReplayEvent {
+                        timestamp: event.timestamp(),
+                        event,
+                        source: EventSource { path: path.clone(), part, line_number: Some(line_number), byte_offset: Some(offset) },
+                    }),
+                    Err(e) => warn!("Skipping line that failed to parse: {}", e),
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Replay the events, the control_rx is used to pause, resume, seek, change the speed of or stop the replay. If
+    /// the control channel is closed the replay carries on to the end
+    pub async fn run(mut self, mut control_rx: Receiver<ReplayControl>) -> Result<(), WatcherError> {
+        let events = self.load()?;
+        info!("Replaying {} events", events.len());
+
+        let mut index = 0;
+        let mut previous_timestamp: Option<NaiveDateTime> = None;
+        // How much of the gap before the current event has already been waited, so a control received part way
+        // through the wait (or a pause) only leaves the remainder to wait rather than starting the wait again
+        let mut waited = Duration::ZERO;
+        let mut paused = false;
+        let mut controls_open = true;
+        let mut sequence: u64 = 0;
+        let mut session: Option<CommanderSession> = None;
+
+        while index < events.len() {
+
+            // Block while paused, only a control can change the state
+            if paused {
+                match control_rx.recv().await {
+                    Some(control) => {
+                        if self.apply_control(control, &events, &mut index, &mut previous_timestamp, &mut paused) {
+                            return Ok(())
+                        }
+                    }
+                    None => {
+                        controls_open = false;
+                        paused = false;
+                    }
+                }
+                continue;
+            }
+
+            let replay_event = &events[index];
+            let gap = previous_timestamp
+                .and_then(|previous| (replay_event.timestamp - previous).to_std().ok())
+                .unwrap_or(Duration::ZERO);
+            let started = Instant::now();
+            let deadline = started + self.speed.scale(gap.saturating_sub(waited));
+
+            if controls_open {
+                tokio::select! {
+                    // Controls take priority over emitting the next event
+                    biased;
+                    control = control_rx.recv() => {
+                        // Measured with the speed in use before the control is applied
+                        waited += self.speed.unscale(started.elapsed());
+                        match control {
+                            Some(control) => {
+                                if self.apply_control(control, &events, &mut index, &mut previous_timestamp, &mut paused) {
+                                    return Ok(())
+                                }
+                            }
+                            None => { controls_open = false; }
+                        }
+                        // Re-evaluate the state before emitting the next event
+                        continue;
+                    }
+                    _ = tokio::time::sleep_until(deadline) => {}
+                }
+            } else if deadline > started {
+                tokio::time::sleep_until(deadline).await;
+            }
+
+            trace!("Replaying event: {:?}", replay_event.event);
+            if previous_timestamp.is_none() {
+                // At the start or after a seek, so the session comes from the events that have been skipped
+                session = None;
+                events[..index].iter().for_each(|skipped| CommanderSession::track(&mut session, &skipped.event));
+            }
+            CommanderSession::track(&mut session, &replay_event.event);
+            let envelope = EventEnvelope {
+                event: EliteDangerousEvent::JournalEvent(replay_event.event.clone()),
+                source: Some(replay_event.source.clone()),
+                received: Utc::now(),
+                sequence,
+                session: session.clone(),
+            };
+            self.event_tx.send(envelope).await
+                .map_err(|e| WatcherError::Channel(format!("Failed to send replayed event to channel: {}", e)))?;
+
+            sequence += 1;
+            waited = Duration::ZERO;
+            previous_timestamp = Some(replay_event.timestamp);
+            index += 1;
+        }
+
+        info!("Replay complete");
+        Ok(())
+    }
+
+    /// Apply the control to the replay state, returns true if the replay should stop
+    fn apply_control(&mut self, control: ReplayControl, events: &[ReplayEvent], index: &mut usize, previous_timestamp: &mut Option<NaiveDateTime>, paused: &mut bool) -> bool {
+        debug!("Replay control: {:?}", control);
+        match control {
+            ReplayControl::Pause => { *paused = true; }
+            ReplayControl::Resume => { *paused = false; }
+            ReplayControl::Seek(timestamp) => {
+                *index = events.partition_point(|replay_event| replay_event.timestamp < timestamp);
+                // Emit the first event after the seek straight away
+                *previous_timestamp = None;
+            }
+            ReplayControl::SetSpeed(speed) => { self.speed = speed; }
+            ReplayControl::Stop => { return true; }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn example_file() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../elite-dangerous-journal-model/examples/Journal.2025-01-04T185730.01.log")
+    }
+
+    /// Write a journal with three events ten seconds apart, the name is used to keep the files of each test apart
+    fn ten_second_journal(name: &str) -> PathBuf {
+        let journal_dir = std::env::temp_dir().join(format!("journal_replayer_{}_{}", name, std::process::id()));
+        std::fs::create_dir_all(&journal_dir).unwrap();
+        let journal_file = journal_dir.join("Journal.2025-01-04T190000.01.log");
+        std::fs::write(&journal_file, concat!(
+            "{ \"timestamp\":\"2025-01-04T19:00:00Z\", \"event\":\"Music\", \"MusicTrack\":\"NoTrack\" }\n",
+            "{ \"timestamp\":\"2025-01-04T19:00:10Z\", \"event\":\"Music\", \"MusicTrack\":\"Exploration\" }\n",
+            "{ \"timestamp\":\"2025-01-04T19:00:20Z\", \"event\":\"Music\", \"MusicTrack\":\"Supercruise\" }\n",
+        )).unwrap();
+        journal_file
+    }
+
+    /// Start replaying the journal, checking first that all of its events load
+    fn start_replay(journal_file: PathBuf, speed: ReplaySpeed) -> (Receiver<EventEnvelope>, Sender<ReplayControl>) {
+        let (event_tx, event_rx) = tokio::sync::mpsc::channel(16);
+        let (control_tx, control_rx) = tokio::sync::mpsc::channel(4);
+
+        let replayer = JournalReplayer::new(event_tx, vec![journal_file], speed);
+        let events = replayer.load().expect("Failed to load journal");
+        assert_eq!(events.len(), 3);
+        tokio::spawn(replayer.run(control_rx));
+        (event_rx, control_tx)
+    }
+
+    #[test]
+    fn test_replay_speed_scale() {
+        assert_eq!(ReplaySpeed::Factor(1.0).scale(Duration::from_secs(10)), Duration::from_secs(10));
+        assert_eq!(ReplaySpeed::Factor(10.0).scale(Duration::from_secs(10)), Duration::from_secs(1));
+        assert_eq!(ReplaySpeed::AsFastAsPossible.scale(Duration::from_secs(10)), Duration::ZERO);
+        assert_eq!(ReplaySpeed::Factor(10.0).unscale(Duration::from_secs(1)), Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_replay_seek() {
+        let (event_tx, mut event_rx) = tokio::sync::mpsc::channel(8192);
+        let (control_tx, control_rx) = tokio::sync::mpsc::channel(1);
+
+        let replayer = JournalReplayer::new(event_tx, vec![example_file()], ReplaySpeed::AsFastAsPossible);
+        let events = replayer.load().expect("Failed to load example journal");
+        let seek_to = NaiveDate::from_ymd_opt(2025, 1, 4).unwrap().and_hms_opt(19, 30, 0).unwrap();
+        let expected = events.iter().filter(|replay_event| replay_event.timestamp >= seek_to).count();
+        assert!(expected > 0 && expected < events.len());
+
+        // Sent before the replay starts so it is applied before the first event
+        control_tx.send(ReplayControl::Seek(seek_to)).await.unwrap();
+        drop(control_tx);
+        replayer.run(control_rx).await.expect("Failed to replay");
+
+        let mut received = Vec::new();
+        while let Ok(envelope) = event_rx.try_recv() {
+            received.push(envelope);
+        }
+        assert_eq!(received.len(), expected);
+        assert_eq!(received.last().map(|envelope| envelope.sequence), Some(expected as u64 - 1));
+        let source = received[0].source.as_ref().expect("Expected a source");
+        assert_eq!(source.part, Some(1));
+        assert!(source.line_number.is_some_and(|line_number| line_number > 1));
+        assert!(received[0].session.is_some());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_replay_pause_resume() {
+        let journal_file = ten_second_journal("pause");
+        let (mut event_rx, control_tx) = start_replay(journal_file.clone(), ReplaySpeed::Factor(1.0));
+
+        let first = event_rx.recv().await.expect("Expected the first event");
+        assert_eq!(first.sequence, 0);
+        std::fs::remove_dir_all(journal_file.parent().unwrap()).unwrap();
+
+        // Nothing is emitted while paused, even well past the gap to the next event
+        control_tx.send(ReplayControl::Pause).await.unwrap();
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        assert!(event_rx.try_recv().is_err());
+
+        // No time passed before the pause so the whole gap is still waited after resuming
+        control_tx.send(ReplayControl::Resume).await.unwrap();
+        let resumed = Instant::now();
+        let second = event_rx.recv().await.expect("Expected the second event");
+        assert_eq!(second.sequence, 1);
+        assert!(resumed.elapsed() >= Duration::from_secs(10) && resumed.elapsed() < Duration::from_secs(11), "{:?}", resumed.elapsed());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_replay_set_speed() {
+        let journal_file = ten_second_journal("speed");
+        let (mut event_rx, control_tx) = start_replay(journal_file.clone(), ReplaySpeed::Factor(1.0));
+
+        event_rx.recv().await.expect("Expected the first event");
+        std::fs::remove_dir_all(journal_file.parent().unwrap()).unwrap();
+
+        // At real time the gap of ten seconds is waited in full
+        let started = Instant::now();
+        event_rx.recv().await.expect("Expected the second event");
+        assert!(started.elapsed() >= Duration::from_secs(10) && started.elapsed() < Duration::from_secs(11), "{:?}", started.elapsed());
+
+        // At ten times the speed the same gap only takes a second
+        control_tx.send(ReplayControl::SetSpeed(ReplaySpeed::Factor(10.0))).await.unwrap();
+        let started = Instant::now();
+        event_rx.recv().await.expect("Expected the third event");
+        assert!(started.elapsed() >= Duration::from_secs(1) && started.elapsed() < Duration::from_secs(2), "{:?}", started.elapsed());
+    }
+}
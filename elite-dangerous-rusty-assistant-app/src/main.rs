@@ -14,7 +14,7 @@ use tokio::fs::create_dir_all;
 use tokio::signal;
 use tokio::sync::mpsc::channel;
 use tokio::task::JoinSet;
-use tracing::{debug, error, info, trace};
+use tracing::{debug, error, info, trace, warn};
 
 #[tokio::main]
 async fn main() -> Result<(), String> {
@@ -76,12 +76,23 @@ async fn main() -> Result<(), String> {
     debug!("Staring journal watcher");
 
     let (event_tx, mut event_rx) = channel(1024);
-    let processor = Arc::new(JournalFileProcessor::new(event_tx, cli_args.sender_timeout));
+    let (error_tx, mut error_rx) = channel(1024);
+    let processor = Arc::new(JournalFileProcessor::new(event_tx, error_tx, cli_args.sender_timeout));
 
     let (terminate_tx, terminate_rx) = futures::channel::oneshot::channel::<()>();
 
     task_set.spawn(async move {
-        elite_journal_watcher::start(config_dir, journal_dir, processor, terminate_rx).await;
+        if let Err(e) = elite_journal_watcher::start(config_dir, journal_dir, processor, terminate_rx).await {
+            error!("Journal watcher stopped: {}", e);
+        }
+    });
+
+    task_set.spawn(async move {
+        // The watcher carries on after a recoverable error, so they are logged here rather than stopping the app.
+        // The channel is always drained so the watcher never blocks waiting for space to report an error
+        while let Some(e) = error_rx.recv().await {
+            warn!("Journal watcher error: {}", e);
+        }
     });
 
     info!("Journal watcher started");
@@ -101,15 +112,17 @@ async fn main() -> Result<(), String> {
     });
 
     task_set.spawn(async move {
-        while let Some(event) = event_rx.recv().await {
-            info!("{:?}", event);
+        while let Some(envelope) = event_rx.recv().await {
+            info!("{:?}", envelope);
 
-            let event_ref = Arc::new(event);
+            let envelope_ref = Arc::new(envelope);
             for plugin in plugins.iter() {
-                plugin
-                    .process_event(event_ref.clone())
-                    .await
-                    .expect("Failed to process event");
+                if let Err(e) = plugin.process_event(envelope_ref.clone()).await {
+                    error!(
+                        "Failed to process event {} from {:?}: {}",
+                        envelope_ref.sequence, envelope_ref.source, e
+                    );
+                }
             }
         }
     });
@@ -1,9 +1,9 @@
 use std::future::Future;
 use std::sync::Arc;
-use elite_dangerous_journal_model::events::EliteDangerousEvent;
+use elite_dangerous_journal_model::events::envelope::EventEnvelope;
 
 pub mod pirate_massacre_plugin;
 
 pub trait EliteDangerousEventProcessor {
-    fn process_event(&self, event: Arc<EliteDangerousEvent>) -> impl Future<Output= Result<(), String>> + Send;
+    fn process_event(&self, event: Arc<EventEnvelope>) -> impl Future<Output= Result<(), String>> + Send;
 }
\ No newline at end of file
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use elite_dangerous_journal_model::events::station_services::missions::mission_accepted::MissionAcceptedEvent;
 use elite_dangerous_journal_model::events::station_services::missions::mission_completed::MissionCompletedEvent;
 use elite_dangerous_journal_model::events::EliteDangerousEvent;
+use elite_dangerous_journal_model::events::envelope::EventEnvelope;
 use elite_dangerous_journal_model::events::JournalEvent::{
     MissionAbandoned, MissionAccepted, MissionCompleted, MissionFailed, MissionRedirected,
 };
@@ -126,10 +127,11 @@ impl PirateMassacrePlugin {
 impl EliteDangerousEventProcessor for PirateMassacrePlugin {
     async fn process_event(
         &self,
-        event: Arc<EliteDangerousEvent>,
+        event: Arc<EventEnvelope>,
     ) -> std::result::Result<(), String> {
-        match event.as_ref() {
-            EliteDangerousEvent::JournalEvent(je) => match je {
+        match &event.event {
+            // Missions accepted before edra was started are still open so history is processed the same as live events
+            EliteDangerousEvent::JournalEvent(je) | EliteDangerousEvent::BackfilledJournalEvent(je) => match je {
                 MissionAbandoned(ma) => {
                     trace!("Processing mission abandoned: {:?}", ma);
                     Ok(())
@@ -165,6 +167,10 @@ impl EliteDangerousEventProcessor for PirateMassacrePlugin {
                     Ok(())
                 }
             },
+            _ => {
+                trace!("Not interested in {:?}", event.event);
+                Ok(())
+            }
         }
     }
 }